@@ -3,6 +3,8 @@
 extern crate serenity_utils;
 extern crate test;
 
+use serenity_utils::{Mention, Mentionable, MessageBuilder};
+use std::fmt::Write;
 use test::{Bencher, black_box};
 
 #[bench]
@@ -11,3 +13,53 @@ fn bench_parse_emoji(b: &mut Bencher) {
         serenity_utils::parse_emoji("<:smugAnimeFace:302516740095606785>");
     });
 }
+
+/// A chain of 50 mentions of users, roles, and channels.
+fn mentions() -> Vec<Mention> {
+    (0..50u64)
+        .map(|n| match n % 3 {
+            0 => Mention::User(114941315417899012 + n),
+            1 => Mention::Role(302516740095606785 + n),
+            _ => Mention::Channel(81384788765712384 + n),
+        })
+        .collect()
+}
+
+#[bench]
+#[allow(deprecated)]
+fn bench_mention_chain_string(b: &mut Bencher) {
+    let mentions = mentions();
+
+    b.iter(|| {
+        let mut content = String::with_capacity(2000);
+
+        for mention in &mentions {
+            content.push_str(&mention.mention());
+            content.push(' ');
+        }
+
+        black_box(content)
+    });
+}
+
+#[bench]
+fn bench_mention_chain_display(b: &mut Bencher) {
+    let mentions = mentions();
+
+    b.iter(|| {
+        let mut content = String::with_capacity(2000);
+
+        for mention in &mentions {
+            let _ = write!(content, "{} ", mention.mention_display());
+        }
+
+        black_box(content)
+    });
+}
+
+#[bench]
+fn bench_mention_chain_builder(b: &mut Bencher) {
+    let mentions = mentions();
+
+    b.iter(|| black_box(MessageBuilder::with_capacity(2000).mention_join(&mentions, " ").build()));
+}
@@ -0,0 +1,145 @@
+use std::fmt::Write as FmtWrite;
+
+/// One of the eight colours supported in `ansi` code blocks, usable as either
+/// a foreground or a background colour.
+///
+/// Discord's client renders these with its own palette, so the exact shades
+/// differ from those of a terminal.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum AnsiColour {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+}
+
+impl AnsiColour {
+    fn offset(&self) -> u8 {
+        use self::AnsiColour::*;
+
+        match *self {
+            Black => 0,
+            Red => 1,
+            Green => 2,
+            Yellow => 3,
+            Blue => 4,
+            Magenta => 5,
+            Cyan => 6,
+            White => 7,
+        }
+    }
+}
+
+/// A style applied to a segment of text in an [`AnsiBuilder`].
+///
+/// [`AnsiBuilder`]: struct.AnsiBuilder.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum AnsiStyle {
+    Bold,
+    Underline,
+    Foreground(AnsiColour),
+    Background(AnsiColour),
+}
+
+impl AnsiStyle {
+    /// The SGR code of the style.
+    pub fn code(&self) -> u8 {
+        match *self {
+            AnsiStyle::Bold => 1,
+            AnsiStyle::Underline => 4,
+            AnsiStyle::Foreground(colour) => 30 + colour.offset(),
+            AnsiStyle::Background(colour) => 40 + colour.offset(),
+        }
+    }
+}
+
+/// A builder for text coloured with ANSI escape sequences, which Discord
+/// renders inside of `ansi` code blocks.
+///
+/// Each styled segment is followed by a reset sequence, so that styles never
+/// leak into the text after it.
+///
+/// The text is usually pushed via [`MessageBuilder::push_ansi`], which wraps
+/// it in the code block.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity_utils::{AnsiBuilder, AnsiColour, AnsiStyle};
+///
+/// let text = AnsiBuilder::new()
+///     .push("Status: ")
+///     .push_styled("online", &[AnsiStyle::Bold, AnsiStyle::Foreground(AnsiColour::Green)])
+///     .build();
+///
+/// assert_eq!(text, "Status: \u{1b}[1;32monline\u{1b}[0m");
+/// ```
+///
+/// [`MessageBuilder::push_ansi`]: struct.MessageBuilder.html#method.push_ansi
+#[derive(Clone, Debug, Default)]
+pub struct AnsiBuilder(String);
+
+impl AnsiBuilder {
+    /// Creates a new, empty builder.
+    pub fn new() -> AnsiBuilder { AnsiBuilder::default() }
+
+    /// Pushes text without any styling.
+    pub fn push(mut self, text: &str) -> Self {
+        self.0.push_str(text);
+
+        self
+    }
+
+    /// Pushes text with the given styles applied, followed by a reset.
+    ///
+    /// Nothing is pushed if the text is empty, and the text is pushed as-is if
+    /// there are no styles.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::{AnsiBuilder, AnsiColour, AnsiStyle};
+    ///
+    /// let text = AnsiBuilder::new()
+    ///     .push_styled("error", &[AnsiStyle::Foreground(AnsiColour::Red)])
+    ///     .push(" occurred")
+    ///     .build();
+    ///
+    /// assert_eq!(text, "\u{1b}[31merror\u{1b}[0m occurred");
+    /// ```
+    pub fn push_styled(mut self, text: &str, styles: &[AnsiStyle]) -> Self {
+        if text.is_empty() {
+            return self;
+        }
+
+        if styles.is_empty() {
+            return self.push(text);
+        }
+
+        self.0.push_str("\u{1b}[");
+
+        for (idx, style) in styles.iter().enumerate() {
+            if idx > 0 {
+                self.0.push(';');
+            }
+
+            let _ = write!(self.0, "{}", style.code());
+        }
+
+        self.0.push('m');
+        self.0.push_str(text);
+        self.0.push_str("\u{1b}[0m");
+
+        self
+    }
+
+    /// Returns the text built so far.
+    pub fn as_str(&self) -> &str { &self.0 }
+
+    /// Pulls the inner value out of the builder.
+    pub fn build(self) -> String { self.0 }
+}
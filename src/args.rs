@@ -0,0 +1,656 @@
+use std::borrow::Cow;
+use std::marker::PhantomData;
+use std::result::Result as StdResult;
+use std::str::FromStr;
+use super::error::{ArgError, MultipleError};
+
+/// A delimiter between arguments, as used by [`Args`].
+///
+/// [`Args`]: struct.Args.html
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Delimiter {
+    /// A single character, such as `' '`.
+    Single(char),
+    /// A string of any number of characters, such as `", "`.
+    Multiple(String),
+}
+
+impl Delimiter {
+    /// Returns the length in bytes of this delimiter if the content starts
+    /// with it.
+    fn matches(&self, content: &str) -> Option<usize> {
+        match *self {
+            Delimiter::Single(c) if content.starts_with(c) => Some(c.len_utf8()),
+            Delimiter::Multiple(ref s) if !s.is_empty() && content.starts_with(&**s) => {
+                Some(s.len())
+            },
+            _ => None,
+        }
+    }
+}
+
+impl From<char> for Delimiter {
+    fn from(c: char) -> Delimiter { Delimiter::Single(c) }
+}
+
+impl<'a> From<&'a str> for Delimiter {
+    fn from(s: &'a str) -> Delimiter { Delimiter::Multiple(s.to_string()) }
+}
+
+impl From<String> for Delimiter {
+    fn from(s: String) -> Delimiter { Delimiter::Multiple(s) }
+}
+
+/// The position of an argument within the message, in bytes.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct Token {
+    start: usize,
+    end: usize,
+    /// The position of the content, without any surrounding quotes.
+    content: (usize, usize),
+    quoted: bool,
+}
+
+impl Token {
+    fn new(start: usize, end: usize) -> Token {
+        Token {
+            start,
+            end,
+            content: (start, end),
+            quoted: false,
+        }
+    }
+}
+
+fn is_opening_quote(c: char) -> bool { c == '"' || c == '\u{201C}' }
+
+fn is_closing_quote(c: char) -> bool { c == '"' || c == '\u{201D}' }
+
+/// Finds the closing quote of a quoted argument, returning the positions of the
+/// quote and of the character after it.
+fn closing_quote(content: &str, from: usize) -> Option<(usize, usize)> {
+    let mut chars = content[from..].char_indices();
+
+    while let Some((index, c)) = chars.next() {
+        if c == '\\' && content[from + index + 1..].starts_with(is_closing_quote) {
+            chars.next();
+        } else if is_closing_quote(c) {
+            return Some((from + index, from + index + c.len_utf8()));
+        }
+    }
+
+    None
+}
+
+/// A parser for the arguments of a command, splitting message content by a set
+/// of delimiters.
+///
+/// Arguments are read one at a time, starting from the first: [`current`]
+/// returns the argument being pointed to, and [`advance`] moves on to the
+/// next. Arguments borrow from the original content rather than being copied
+/// out of it.
+///
+/// By default, consecutive delimiters are treated as one, and delimiters at
+/// the start or end of the content are ignored. Use [`strict`] to instead
+/// split on every delimiter, keeping the empty arguments between them.
+///
+/// An argument starting with a double quote, either `"` or the `“` inserted
+/// by mobile keyboards, continues up to the closing `"` or `”`, even past
+/// delimiters. Quotes can be escaped within it as `\"`. If there is no closing
+/// quote, the argument continues to the end of the message. [`current`]
+/// returns quoted arguments as they are, while [`current_quoted`] removes the
+/// quotes and escapes.
+///
+/// # Examples
+///
+/// Read the arguments of `!ban @user 7 spamming`, after the command name has
+/// been removed:
+///
+/// ```rust
+/// use serenity_utils::Args;
+///
+/// let mut args = Args::new("<@12345>  7 spamming a lot", &[' '.into()]);
+///
+/// assert_eq!(args.current(), Some("<@12345>"));
+/// assert_eq!(args.remaining(), 5);
+///
+/// args.advance().advance();
+///
+/// assert_eq!(args.rest(), "spamming a lot");
+/// ```
+///
+/// [`advance`]: #method.advance
+/// [`current`]: #method.current
+/// [`current_quoted`]: #method.current_quoted
+/// [`strict`]: #method.strict
+#[derive(Clone, Debug)]
+pub struct Args<'a> {
+    message: &'a str,
+    offset: usize,
+    tokens: Vec<Token>,
+}
+
+impl<'a> Args<'a> {
+    /// Creates a new parser over the message, splitting by any of the given
+    /// delimiters.
+    ///
+    /// Consecutive delimiters are treated as one. Where more than one
+    /// delimiter matches at the same position, the first given is used.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::Args;
+    ///
+    /// let args = Args::new("a, b,c  ,, d", &[' '.into(), ','.into()]);
+    ///
+    /// assert_eq!(args.len(), 4);
+    /// ```
+    pub fn new(message: &'a str, delimiters: &[Delimiter]) -> Args<'a> {
+        Args::split(message, delimiters, false)
+    }
+
+    /// Creates a new parser over the message, splitting by every occurrence of
+    /// the given delimiters.
+    ///
+    /// Unlike with [`new`], consecutive delimiters are not treated as one, so
+    /// there is an empty argument between each pair of them, as well as before
+    /// a leading delimiter and after a trailing one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::Args;
+    ///
+    /// let mut args = Args::strict("a,,b,", &[','.into()]);
+    ///
+    /// assert_eq!(args.len(), 4);
+    /// assert_eq!(args.advance().current(), Some(""));
+    /// ```
+    ///
+    /// [`new`]: #method.new
+    pub fn strict(message: &'a str, delimiters: &[Delimiter]) -> Args<'a> {
+        Args::split(message, delimiters, true)
+    }
+
+    fn split(message: &'a str, delimiters: &[Delimiter], strict: bool) -> Args<'a> {
+        let mut tokens = Vec::new();
+        let mut start = 0;
+        let mut index = 0;
+        // Whether a quoted argument has just ended, so that a delimiter
+        // directly after it doesn't start an empty argument in strict mode.
+        let mut after_quote = false;
+
+        while let Some(c) = message[index..].chars().next() {
+            if index == start && is_opening_quote(c) {
+                let open = index + c.len_utf8();
+                let (content_end, end) = closing_quote(message, open)
+                    .unwrap_or((message.len(), message.len()));
+
+                tokens.push(Token {
+                    start,
+                    end,
+                    content: (open, content_end),
+                    quoted: true,
+                });
+
+                index = end;
+                start = end;
+                after_quote = true;
+
+                continue;
+            }
+
+            let rest = &message[index..];
+
+            match delimiters.iter().filter_map(|delimiter| delimiter.matches(rest)).next() {
+                Some(length) => {
+                    if index > start || (strict && !after_quote) {
+                        tokens.push(Token::new(start, index));
+                    }
+
+                    index += length;
+                    start = index;
+                    after_quote = false;
+                },
+                None => {
+                    index += c.len_utf8();
+                    after_quote = false;
+                },
+            }
+        }
+
+        if start < message.len() || (strict && !message.is_empty() && !after_quote) {
+            tokens.push(Token::new(start, message.len()));
+        }
+
+        Args {
+            message,
+            offset: 0,
+            tokens,
+        }
+    }
+
+    /// Returns the argument being pointed to, without advancing past it.
+    ///
+    /// Returns `None` if all of the arguments have been advanced past.
+    pub fn current(&self) -> Option<&'a str> {
+        self.tokens.get(self.offset).map(|token| &self.message[token.start..token.end])
+    }
+
+    /// Returns the argument being pointed to with any surrounding quotes
+    /// removed, without advancing past it.
+    ///
+    /// Escaped quotes within a quoted argument are unescaped, in which case
+    /// the argument is copied. Arguments that aren't quoted are returned as
+    /// with [`current`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::Args;
+    ///
+    /// let mut args = Args::new(r#""hello there" “say \"hi\"” plain"#, &[' '.into()]);
+    ///
+    /// assert_eq!(args.current(), Some(r#""hello there""#));
+    /// assert_eq!(args.current_quoted().unwrap(), "hello there");
+    /// assert_eq!(args.advance().current_quoted().unwrap(), r#"say "hi""#);
+    /// assert_eq!(args.advance().current_quoted().unwrap(), "plain");
+    /// ```
+    ///
+    /// [`current`]: #method.current
+    pub fn current_quoted(&self) -> Option<Cow<'a, str>> {
+        self.tokens.get(self.offset).map(|token| self.unquote(token))
+    }
+
+    fn unquote(&self, token: &Token) -> Cow<'a, str> {
+        let content = &self.message[token.content.0..token.content.1];
+
+        if !token.quoted || !content.contains('\\') {
+            return Cow::Borrowed(content);
+        }
+
+        let mut unescaped = String::with_capacity(content.len());
+        let mut chars = content.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match chars.peek() {
+                Some(&next) if c == '\\' && is_closing_quote(next) => {},
+                _ => unescaped.push(c),
+            }
+        }
+
+        Cow::Owned(unescaped)
+    }
+
+    /// Parses the argument being pointed to, advancing past it only if it is
+    /// parsed successfully.
+    ///
+    /// The argument is parsed as it is, including any quotes. Use
+    /// [`single_quoted`] to remove them first.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArgError::Eos`] if all of the arguments have been advanced
+    /// past, and [`ArgError::Parse`] with the parsing error if the argument
+    /// couldn't be parsed.
+    ///
+    /// # Examples
+    ///
+    /// Read the arguments of `!ban 12345 7 spamming`:
+    ///
+    /// ```rust
+    /// use serenity_utils::{ArgError, Args};
+    ///
+    /// let mut args = Args::new("12345 7 spamming", &[' '.into()]);
+    ///
+    /// assert_eq!(args.single::<u64>(), Ok(12345));
+    /// assert_eq!(args.single::<u32>(), Ok(7));
+    /// assert!(args.single::<u32>().is_err());
+    /// assert_eq!(args.single::<String>(), Ok("spamming".to_string()));
+    /// assert_eq!(args.single::<String>(), Err(ArgError::Eos));
+    /// ```
+    ///
+    /// [`ArgError::Eos`]: enum.ArgError.html#variant.Eos
+    /// [`ArgError::Parse`]: enum.ArgError.html#variant.Parse
+    /// [`single_quoted`]: #method.single_quoted
+    pub fn single<T: FromStr>(&mut self) -> StdResult<T, ArgError<T::Err>> {
+        let parsed = self.current().ok_or(ArgError::Eos)?.parse().map_err(ArgError::Parse)?;
+        self.advance();
+
+        Ok(parsed)
+    }
+
+    /// Parses the argument being pointed to with any surrounding quotes
+    /// removed, advancing past it only if it is parsed successfully.
+    ///
+    /// This is like [`single`], but removes quotes as with
+    /// [`current_quoted`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`single`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::Args;
+    ///
+    /// let mut args = Args::new(r#""Cool People" "7""#, &[' '.into()]);
+    ///
+    /// assert_eq!(args.single_quoted::<String>(), Ok("Cool People".to_string()));
+    /// assert_eq!(args.single_quoted::<u8>(), Ok(7));
+    /// ```
+    ///
+    /// [`current_quoted`]: #method.current_quoted
+    /// [`single`]: #method.single
+    pub fn single_quoted<T: FromStr>(&mut self) -> StdResult<T, ArgError<T::Err>> {
+        let parsed = self.current_quoted().ok_or(ArgError::Eos)?.parse().map_err(ArgError::Parse)?;
+        self.advance();
+
+        Ok(parsed)
+    }
+
+    /// Returns an iterator parsing each of the arguments that haven't been
+    /// advanced past, advancing past each as it is parsed.
+    ///
+    /// Unlike with [`single`], arguments that can't be parsed are advanced past
+    /// too, after their error is returned. The arguments are parsed as they
+    /// are, unless quotes are removed with [`ArgsIter::quoted`].
+    ///
+    /// # Examples
+    ///
+    /// Sum the arguments of `!add 1 2 x 3`, ignoring those that aren't
+    /// numbers:
+    ///
+    /// ```rust
+    /// use serenity_utils::Args;
+    ///
+    /// let mut args = Args::new("1 2 x 3", &[' '.into()]);
+    /// let sum = args.iter::<u32>().filter_map(Result::ok).sum::<u32>();
+    ///
+    /// assert_eq!(sum, 6);
+    /// assert_eq!(args.remaining(), 0);
+    /// ```
+    ///
+    /// [`ArgsIter::quoted`]: struct.ArgsIter.html#method.quoted
+    /// [`single`]: #method.single
+    pub fn iter<'b, T: FromStr>(&'b mut self) -> ArgsIter<'b, 'a, T> {
+        ArgsIter {
+            args: self,
+            quoted: false,
+            marker: PhantomData,
+        }
+    }
+
+    /// Parses all of the arguments that haven't been advanced past, advancing
+    /// past them only if they're all parsed successfully.
+    ///
+    /// The arguments are parsed as they are, including any quotes. Use
+    /// [`multiple_quoted`] to remove them first.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`MultipleError`] with the index of the first argument that
+    /// couldn't be parsed, and the parsing error. The index counts all of the
+    /// arguments, including those already advanced past.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::Args;
+    ///
+    /// let mut args = Args::new("roll 1 2 3", &[' '.into()]);
+    ///
+    /// assert!(args.multiple::<u8>().is_err());
+    /// assert_eq!(args.single::<String>(), Ok("roll".to_string()));
+    /// assert_eq!(args.multiple::<u8>(), Ok(vec![1, 2, 3]));
+    /// ```
+    ///
+    /// Find which argument was bad:
+    ///
+    /// ```rust
+    /// use serenity_utils::Args;
+    ///
+    /// let mut args = Args::new("roll 1 two 3", &[' '.into()]);
+    /// args.advance();
+    ///
+    /// let err = args.multiple::<u8>().unwrap_err();
+    ///
+    /// assert_eq!(err.index, 2);
+    /// assert_eq!(args.current(), Some("1"));
+    /// ```
+    ///
+    /// [`MultipleError`]: struct.MultipleError.html
+    /// [`multiple_quoted`]: #method.multiple_quoted
+    pub fn multiple<T: FromStr>(&mut self) -> StdResult<Vec<T>, MultipleError<T::Err>> {
+        self.parse_remaining(false)
+    }
+
+    /// Parses all of the arguments that haven't been advanced past with any
+    /// surrounding quotes removed, advancing past them only if they're all
+    /// parsed successfully.
+    ///
+    /// This is like [`multiple`], but removes quotes as with
+    /// [`current_quoted`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`multiple`].
+    ///
+    /// [`current_quoted`]: #method.current_quoted
+    /// [`multiple`]: #method.multiple
+    pub fn multiple_quoted<T: FromStr>(&mut self) -> StdResult<Vec<T>, MultipleError<T::Err>> {
+        self.parse_remaining(true)
+    }
+
+    fn parse_remaining<T>(&mut self, quoted: bool) -> StdResult<Vec<T>, MultipleError<T::Err>>
+        where T: FromStr {
+        let offset = self.offset;
+        let parsed = self.iter()
+            .quoted_if(quoted)
+            .enumerate()
+            .map(|(index, parsed)| {
+                parsed.map_err(|error| MultipleError { index: offset + index, error })
+            })
+            .collect::<StdResult<Vec<_>, _>>();
+
+        if parsed.is_err() {
+            self.offset = offset;
+        }
+
+        parsed
+    }
+
+    /// Finds the first argument that hasn't been advanced past and can be
+    /// parsed, removing it from the arguments.
+    ///
+    /// This is useful for flags that can be given in any position. The order
+    /// of the other arguments is kept, so they are read as if the removed
+    /// argument was never there. The argument is still included in the
+    /// content returned by [`rest`] and [`message`], though.
+    ///
+    /// The arguments are parsed as they are, including any quotes. Returns
+    /// `None` if none of them can be parsed.
+    ///
+    /// # Examples
+    ///
+    /// Read the arguments of `!purge 50 --silent` and `!purge --silent 50`:
+    ///
+    /// ```rust
+    /// use serenity_utils::Args;
+    /// use std::str::FromStr;
+    ///
+    /// struct Silent;
+    ///
+    /// impl FromStr for Silent {
+    ///     type Err = ();
+    ///
+    ///     fn from_str(s: &str) -> Result<Silent, ()> {
+    ///         if s == "--silent" { Ok(Silent) } else { Err(()) }
+    ///     }
+    /// }
+    ///
+    /// for content in &["50 --silent", "--silent 50"] {
+    ///     let mut args = Args::new(content, &[' '.into()]);
+    ///
+    ///     assert!(args.find::<Silent>().is_some());
+    ///     assert_eq!(args.single::<u32>(), Ok(50));
+    ///     assert_eq!(args.remaining(), 0);
+    /// }
+    /// ```
+    ///
+    /// [`message`]: #method.message
+    /// [`rest`]: #method.rest
+    pub fn find<T: FromStr>(&mut self) -> Option<T> {
+        let (index, parsed) = self.find_index()?;
+        self.tokens.remove(index);
+
+        Some(parsed)
+    }
+
+    /// Finds the first argument that hasn't been advanced past and can be
+    /// parsed, without removing it or advancing.
+    ///
+    /// This is like [`find`], but leaves the arguments untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::Args;
+    ///
+    /// let args = Args::new("a b 3 c", &[' '.into()]);
+    ///
+    /// assert_eq!(args.find_n::<u8>(), Some(3));
+    /// assert_eq!(args.len(), 4);
+    /// assert_eq!(args.current(), Some("a"));
+    /// ```
+    ///
+    /// [`find`]: #method.find
+    pub fn find_n<T: FromStr>(&self) -> Option<T> {
+        self.find_index().map(|(_, parsed)| parsed)
+    }
+
+    fn find_index<T: FromStr>(&self) -> Option<(usize, T)> {
+        self.tokens.iter()
+            .enumerate()
+            .skip(self.offset)
+            .filter_map(|(index, token)| {
+                self.message[token.start..token.end].parse().ok().map(|parsed| (index, parsed))
+            })
+            .next()
+    }
+
+    /// Advances to the next argument.
+    ///
+    /// Does nothing if all of the arguments have already been advanced past.
+    pub fn advance(&mut self) -> &mut Self {
+        if self.offset < self.tokens.len() {
+            self.offset += 1;
+        }
+
+        self
+    }
+
+    /// Moves back to the first argument.
+    pub fn rewind(&mut self) -> &mut Self {
+        self.offset = 0;
+
+        self
+    }
+
+    /// Returns the number of arguments that haven't been advanced past,
+    /// including the current one.
+    pub fn remaining(&self) -> usize { self.tokens.len() - self.offset }
+
+    /// Returns the content from the current argument to the end of the
+    /// message, untouched.
+    ///
+    /// The delimiters before the current argument are never included, nor is
+    /// the closing quote of a quoted argument that has been advanced past.
+    /// Returns an empty string if all of the arguments have been advanced
+    /// past.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::Args;
+    ///
+    /// let mut args = Args::new("role add  Cool  People ", &[' '.into()]);
+    /// args.advance().advance();
+    ///
+    /// assert_eq!(args.rest(), "Cool  People ");
+    /// ```
+    pub fn rest(&self) -> &'a str {
+        match self.tokens.get(self.offset) {
+            Some(token) => &self.message[token.start..],
+            None => "",
+        }
+    }
+
+    /// Returns the total number of arguments, including those that have been
+    /// advanced past.
+    pub fn len(&self) -> usize { self.tokens.len() }
+
+    /// Returns whether there are no arguments at all.
+    pub fn is_empty(&self) -> bool { self.tokens.is_empty() }
+
+    /// Returns the original message.
+    pub fn message(&self) -> &'a str { self.message }
+}
+
+/// An iterator parsing the remaining arguments of an [`Args`], as returned by
+/// [`Args::iter`].
+///
+/// [`Args`]: struct.Args.html
+/// [`Args::iter`]: struct.Args.html#method.iter
+#[derive(Debug)]
+pub struct ArgsIter<'a, 'b: 'a, T> {
+    args: &'a mut Args<'b>,
+    marker: PhantomData<T>,
+    quoted: bool,
+}
+
+impl<'a, 'b, T> ArgsIter<'a, 'b, T> {
+    /// Removes any surrounding quotes from the arguments before parsing them,
+    /// as with [`Args::current_quoted`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::Args;
+    ///
+    /// let mut args = Args::new(r#""Cool People" Bots"#, &[' '.into()]);
+    /// let roles = args.iter::<String>().quoted().collect::<Result<Vec<_>, _>>();
+    ///
+    /// assert_eq!(roles.unwrap(), ["Cool People", "Bots"]);
+    /// ```
+    ///
+    /// [`Args::current_quoted`]: struct.Args.html#method.current_quoted
+    pub fn quoted(self) -> Self { self.quoted_if(true) }
+
+    fn quoted_if(mut self, quoted: bool) -> Self {
+        self.quoted = quoted;
+
+        self
+    }
+}
+
+impl<'a, 'b, T: FromStr> Iterator for ArgsIter<'a, 'b, T> {
+    type Item = StdResult<T, T::Err>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let parsed = if self.quoted {
+            self.args.current_quoted()?.parse()
+        } else {
+            self.args.current()?.parse()
+        };
+        self.args.advance();
+
+        Some(parsed)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.args.remaining(), Some(self.args.remaining()))
+    }
+}
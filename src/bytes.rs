@@ -0,0 +1,92 @@
+use std::fmt::Write as FmtWrite;
+
+/// How a number of bytes is written, as by
+/// [`MessageBuilder::push_bytes_styled`].
+///
+/// The default style uses binary units with one decimal place, such as
+/// `3.4 MiB`.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity_utils::{ByteStyle, MessageBuilder};
+///
+/// let style = ByteStyle {
+///     binary: false,
+///     decimals: 2,
+/// };
+///
+/// let content = MessageBuilder::new().push_bytes_styled(3_565_158, &style).build();
+///
+/// assert_eq!(content, "3.57 MB");
+/// ```
+///
+/// [`MessageBuilder::push_bytes_styled`]: struct.MessageBuilder.html#method.push_bytes_styled
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ByteStyle {
+    /// Whether to use binary units, which are powers of 1024 such as `KiB`,
+    /// rather than decimal units, which are powers of 1000 such as `KB`.
+    pub binary: bool,
+    /// The number of decimal places to write, up to 18. Sizes under a
+    /// kilobyte are always written as a whole number of bytes.
+    pub decimals: usize,
+}
+
+impl ByteStyle {
+    /// Creates the default style, the same as `ByteStyle::default`.
+    pub fn new() -> ByteStyle { ByteStyle::default() }
+}
+
+impl Default for ByteStyle {
+    fn default() -> ByteStyle {
+        ByteStyle {
+            binary: true,
+            decimals: 1,
+        }
+    }
+}
+
+const BINARY_UNITS: &[&str] = &["KiB", "MiB", "GiB", "TiB", "PiB", "EiB"];
+const DECIMAL_UNITS: &[&str] = &["KB", "MB", "GB", "TB", "PB", "EB"];
+
+/// Writes a number of bytes in the largest unit it is at least one of,
+/// rounded to the given number of decimal places.
+pub fn write_bytes(buf: &mut String, bytes: u64, style: &ByteStyle) {
+    let (base, units) = if style.binary {
+        (1024, BINARY_UNITS)
+    } else {
+        (1000, DECIMAL_UNITS)
+    };
+
+    if bytes < base {
+        let _ = write!(buf, "{} B", bytes);
+
+        return;
+    }
+
+    let decimals = style.decimals.min(18);
+    let scale = 10u128.pow(decimals as u32);
+    let mut unit = u128::from(base);
+    let mut idx = 0;
+
+    let scaled = loop {
+        // Round half up, moving on to the next unit if that rounds up to a
+        // whole one of it, so that 1023.99 KiB is written as 1.0 MiB.
+        let scaled = (u128::from(bytes) * scale + unit / 2) / unit;
+
+        if idx + 1 == units.len() || scaled < u128::from(base) * scale {
+            break scaled;
+        }
+
+        unit *= u128::from(base);
+        idx += 1;
+    };
+
+    let _ = write!(buf, "{}", scaled / scale);
+
+    if decimals > 0 {
+        let _ = write!(buf, ".{:0width$}", scaled % scale, width = decimals);
+    }
+
+    let _ = write!(buf, " {}", units[idx]);
+}
@@ -1,15 +1,45 @@
 // Disable this lint to avoid it wanting to change `0xABCDEF` to `0xAB_CDEF`.
 #![allow(unreadable_literal)]
 
+use std::result::Result as StdResult;
+use std::str::FromStr;
+use super::error::ParseColourError;
+
 macro_rules! colour {
-    ($(#[$attr:meta] $name:ident, $val:expr;)*) => {
+    ($(#[$attr:meta] $name:ident, $constant:ident, $val:tt;)*) => {
         impl Colour {
+            $(
+                #[$attr]
+                pub const $constant: Colour = Colour($val);
+            )*
+
             $(
                 #[$attr]
                 pub fn $name() -> Colour {
-                    Colour::new($val)
+                    Colour::$constant
                 }
             )*
+
+            /// Returns the name of the associated constant with the same value
+            /// as this Colour, if there is one.
+            ///
+            /// This is useful when logging colours.
+            ///
+            /// # Examples
+            ///
+            /// ```rust
+            /// use serenity_utils::Colour;
+            ///
+            /// assert_eq!(Colour::new(0x7289DA).name(), Some("BLURPLE"));
+            /// assert_eq!(Colour::DARK_RED.name(), Some("DARK_RED"));
+            /// assert_eq!(Colour::new(0x123456).name(), None);
+            /// ```
+            pub fn name(&self) -> Option<&'static str> {
+                match self.0 {
+                    $($val => Some(stringify!($constant)),)*
+                    _ => None,
+                }
+            }
         }
     }
 }
@@ -21,9 +51,9 @@ macro_rules! colour {
 /// serenity models, as the API works with an integer value instead of an RGB
 /// value.
 ///
-/// Instances can be created by using the struct's associated functions. These
-/// produce presets equivalent to those found in the official client's colour
-/// picker.
+/// Instances can be created by using the struct's associated constants or
+/// functions. These produce presets equivalent to those found in the official
+/// client's colour picker.
 ///
 /// # Examples
 ///
@@ -120,6 +150,169 @@ impl Colour {
         Colour(uint)
     }
 
+    /// Parses a Colour from a hex colour code, such as `#7289DA`.
+    ///
+    /// The code can be prefixed with `#` or `0x`, or have no prefix at all,
+    /// and its digits can be in either case. Codes with fewer than six digits
+    /// are treated as having leading zeros.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseColourError`] if there are no digits, a character
+    /// isn't a hex digit, or the value is over `0xFFFFFF`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::{Colour, ParseColourError};
+    ///
+    /// assert_eq!(Colour::hex("#7289DA"), Ok(Colour::blurple()));
+    /// assert_eq!(Colour::hex("7289da"), Ok(Colour::blurple()));
+    /// assert_eq!(Colour::hex("0x7289DA"), Ok(Colour::blurple()));
+    ///
+    /// assert_eq!(Colour::hex("#72G9DA"), Err(ParseColourError::InvalidCharacter('G')));
+    /// assert_eq!(Colour::hex("#1000000"), Err(ParseColourError::TooLarge));
+    /// ```
+    ///
+    /// [`ParseColourError`]: enum.ParseColourError.html
+    pub fn hex(code: &str) -> StdResult<Colour, ParseColourError> {
+        let digits = code
+            .strip_prefix('#')
+            .or_else(|| code.strip_prefix("0x"))
+            .or_else(|| code.strip_prefix("0X"))
+            .unwrap_or(code);
+
+        if digits.is_empty() {
+            return Err(ParseColourError::Empty);
+        }
+
+        if let Some(c) = digits.chars().find(|c| !c.is_ascii_hexdigit()) {
+            return Err(ParseColourError::InvalidCharacter(c));
+        }
+
+        match u32::from_str_radix(digits, 16) {
+            Ok(value) if value <= 0xFFFFFF => Ok(Colour(value)),
+            _ => Err(ParseColourError::TooLarge),
+        }
+    }
+
+    /// Generates a new Colour from an HSL value.
+    ///
+    /// The hue is in degrees, and wraps around at 360. The saturation and
+    /// lightness are fractions between `0.0` and `1.0`, and are clamped to
+    /// those bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::Colour;
+    ///
+    /// assert_eq!(Colour::from_hsl(0.0, 1.0, 0.5), Colour::from_rgb(255, 0, 0));
+    /// assert_eq!(Colour::from_hsl(480.0, 1.0, 0.25), Colour::from_rgb(0, 128, 0));
+    /// assert_eq!(Colour::from_hsl(200.0, 0.0, 1.0), Colour::WHITE);
+    /// ```
+    pub fn from_hsl(h: f32, s: f32, l: f32) -> Colour {
+        let h = ((h % 360.0) + 360.0) % 360.0 / 60.0;
+        let s = s.clamp(0.0, 1.0);
+        let l = l.clamp(0.0, 1.0);
+
+        let chroma = (1.0 - (2.0 * l - 1.0).abs()) * s;
+        let x = chroma * (1.0 - (h % 2.0 - 1.0).abs());
+        let (r, g, b) = match h as u8 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+        let m = l - chroma / 2.0;
+        let channel = |value: f32| ((value + m).clamp(0.0, 1.0) * 255.0).round() as u8;
+
+        Colour::from_rgb(channel(r), channel(g), channel(b))
+    }
+
+    /// Returns the hue, saturation, and lightness of this Colour.
+    ///
+    /// The hue is in degrees, from `0.0` up to but not including `360.0`. The
+    /// saturation and lightness are fractions between `0.0` and `1.0`. Greys
+    /// have no hue, and so are given a hue and saturation of `0.0`.
+    ///
+    /// The conversion is the inverse of [`from_hsl`], and a round trip
+    /// through both is off by at most one for each RGB component.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::Colour;
+    ///
+    /// assert_eq!(Colour::from_rgb(0, 0, 255).to_hsl(), (240.0, 1.0, 0.5));
+    /// assert_eq!(Colour::BLACK.to_hsl(), (0.0, 0.0, 0.0));
+    ///
+    /// let (h, s, l) = Colour::BLURPLE.to_hsl();
+    ///
+    /// assert_eq!(Colour::from_hsl(h, s, l), Colour::BLURPLE);
+    /// ```
+    ///
+    /// [`from_hsl`]: #method.from_hsl
+    pub fn to_hsl(&self) -> (f32, f32, f32) {
+        let (r, g, b) = self.tuple();
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let l = (f32::from(max) + f32::from(min)) / 510.0;
+
+        if max == min {
+            return (0.0, 0.0, l);
+        }
+
+        let delta = f32::from(max - min) / 255.0;
+        let s = delta / (1.0 - (2.0 * l - 1.0).abs());
+        let (r, g, b) = (f32::from(r) / 255.0, f32::from(g) / 255.0, f32::from(b) / 255.0);
+        let h = if max == self.r() {
+            (g - b) / delta
+        } else if max == self.g() {
+            (b - r) / delta + 2.0
+        } else {
+            (r - g) / delta + 4.0
+        };
+
+        ((h * 60.0 + 360.0) % 360.0, s.clamp(0.0, 1.0), l)
+    }
+
+    /// Returns a lighter version of this Colour, by adding to its HSL
+    /// lightness.
+    ///
+    /// The lightness is clamped at `1.0`, which is white.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::Colour;
+    ///
+    /// assert_eq!(Colour::from_rgb(0, 0, 255).lighten(0.25), Colour::from_rgb(128, 128, 255));
+    /// assert_eq!(Colour::BLURPLE.lighten(2.0), Colour::WHITE);
+    /// ```
+    pub fn lighten(&self, amount: f32) -> Colour {
+        let (h, s, l) = self.to_hsl();
+
+        Colour::from_hsl(h, s, l + amount)
+    }
+
+    /// Returns a darker version of this Colour, by subtracting from its HSL
+    /// lightness.
+    ///
+    /// The lightness is clamped at `0.0`, which is black.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::Colour;
+    ///
+    /// assert_eq!(Colour::from_rgb(0, 0, 255).darken(0.25), Colour::from_rgb(0, 0, 128));
+    /// assert_eq!(Colour::BLURPLE.darken(2.0), Colour::BLACK);
+    /// ```
+    pub fn darken(&self, amount: f32) -> Colour { self.lighten(-amount) }
+
     /// Returns the red RGB component of this Colour.
     ///
     /// # Examples
@@ -218,68 +411,95 @@ impl From<u64> for Colour {
     fn from(value: u64) -> Colour { Colour(value as u32) }
 }
 
+impl From<Colour> for u32 {
+    /// Retrieves the inner value of a Colour, for use with functions that
+    /// accept a raw integer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::Colour;
+    ///
+    /// assert_eq!(u32::from(Colour::from_rgb(114, 137, 218)), 0x7289DA);
+    /// ```
+    fn from(colour: Colour) -> u32 { colour.0 }
+}
+
+impl FromStr for Colour {
+    type Err = ParseColourError;
+
+    /// Parses a Colour from a hex colour code, as with [`Colour::hex`].
+    ///
+    /// [`Colour::hex`]: #method.hex
+    fn from_str(code: &str) -> StdResult<Colour, ParseColourError> { Colour::hex(code) }
+}
+
 impl From<(u8, u8, u8)> for Colour {
     /// Constructs a Colour from rgb.
     fn from((r, g, b): (u8, u8, u8)) -> Self { Colour::from_rgb(r, g, b) }
 }
 
 colour! {
-    /// Creates a new `Colour`, setting its RGB value to `(111, 198, 226)`.
-    blitz_blue, 0x6FC6E2;
-    /// Creates a new `Colour`, setting its RGB value to `(52, 152, 219)`.
-    blue, 0x3498DB;
-    /// Creates a new `Colour`, setting its RGB value to `(114, 137, 218)`.
-    blurple, 0x7289DA;
-    /// Creates a new `Colour`, setting its RGB value to `(32, 102, 148)`.
-    dark_blue, 0x206694;
-    /// Creates a new `Colour`, setting its RGB value to `(194, 124, 14)`.
-    dark_gold, 0xC27C0E;
-    /// Creates a new `Colour`, setting its RGB value to `(31, 139, 76)`.
-    dark_green, 0x1F8B4C;
-    /// Creates a new `Colour`, setting its RGB value to `(96, 125, 139)`.
-    dark_grey, 0x607D8B;
-    /// Creates a new `Colour`, setting its RGB value to `(173, 20, 87)`.
-    dark_magenta, 0xAD1457;
-    /// Creates a new `Colour`, setting its RGB value to `(168, 67, 0)`.
-    dark_orange, 0xA84300;
-    /// Creates a new `Colour`, setting its RGB value to `(113, 54, 138)`.
-    dark_purple, 0x71368A;
-    /// Creates a new `Colour`, setting its RGB value to `(153, 45, 34)`.
-    dark_red, 0x992D22;
-    /// Creates a new `Colour`, setting its RGB value to `(17, 128, 106)`.
-    dark_teal, 0x11806A;
-    /// Creates a new `Colour`, setting its RGB value to `(84, 110, 122)`.
-    darker_grey, 0x546E7A;
-    /// Creates a new `Colour`, setting its RGB value to `(250, 177, 237)`.
-    fabled_pink, 0xFAB1ED;
-    /// Creates a new `Colour`, setting its RGB value to `(136, 130, 196)`.`
-    faded_purple, 0x8882C4;
-    /// Creates a new `Colour`, setting its RGB value to `(17, 202, 128)`.
-    fooyoo, 0x11CA80;
-    /// Creates a new `Colour`, setting its RGB value to `(241, 196, 15)`.
-    gold, 0xF1C40F;
-    /// Creates a new `Colour`, setting its RGB value to `(186, 218, 85)`.
-    kerbal, 0xBADA55;
-    /// Creates a new `Colour`, setting its RGB value to `(151, 156, 159)`.
-    light_grey, 0x979C9F;
-    /// Creates a new `Colour`, setting its RGB value to `(149, 165, 166)`.
-    lighter_grey, 0x95A5A6;
-    /// Creates a new `Colour`, setting its RGB value to `(233, 30, 99)`.
-    magenta, 0xE91E63;
-    /// Creates a new `Colour`, setting its RGB value to `(230, 131, 151)`.
-    meibe_pink, 0xE68397;
-    /// Creates a new `Colour`, setting its RGB value to `(230, 126, 34)`.
-    orange, 0xE67E22;
-    /// Creates a new `Colour`, setting its RGB value to `(155, 89, 182)`.
-    purple, 0x9B59B6;
-    /// Creates a new `Colour`, setting its RGB value to `(231, 76, 60)`.
-    red, 0xE74C3C;
-    /// Creates a new `Colour`, setting its RGB value to `(117, 150, 255)`.
-    rohrkatze_blue, 0x7596FF;
-    /// Creates a new `Colour`, setting its RGB value to `(246, 219, 216)`.
-    rosewater, 0xF6DBD8;
-    /// Creates a new `Colour`, setting its RGB value to `(26, 188, 156)`.
-    teal, 0x1ABC9C;
+    /// A `Colour` with the RGB value `(0, 0, 0)`.
+    black, BLACK, 0x000000;
+    /// A `Colour` with the RGB value `(111, 198, 226)`.
+    blitz_blue, BLITZ_BLUE, 0x6FC6E2;
+    /// A `Colour` with the RGB value `(52, 152, 219)`.
+    blue, BLUE, 0x3498DB;
+    /// A `Colour` with the RGB value `(114, 137, 218)`.
+    blurple, BLURPLE, 0x7289DA;
+    /// A `Colour` with the RGB value `(32, 102, 148)`.
+    dark_blue, DARK_BLUE, 0x206694;
+    /// A `Colour` with the RGB value `(194, 124, 14)`.
+    dark_gold, DARK_GOLD, 0xC27C0E;
+    /// A `Colour` with the RGB value `(31, 139, 76)`.
+    dark_green, DARK_GREEN, 0x1F8B4C;
+    /// A `Colour` with the RGB value `(96, 125, 139)`.
+    dark_grey, DARK_GREY, 0x607D8B;
+    /// A `Colour` with the RGB value `(173, 20, 87)`.
+    dark_magenta, DARK_MAGENTA, 0xAD1457;
+    /// A `Colour` with the RGB value `(168, 67, 0)`.
+    dark_orange, DARK_ORANGE, 0xA84300;
+    /// A `Colour` with the RGB value `(113, 54, 138)`.
+    dark_purple, DARK_PURPLE, 0x71368A;
+    /// A `Colour` with the RGB value `(153, 45, 34)`.
+    dark_red, DARK_RED, 0x992D22;
+    /// A `Colour` with the RGB value `(17, 128, 106)`.
+    dark_teal, DARK_TEAL, 0x11806A;
+    /// A `Colour` with the RGB value `(84, 110, 122)`.
+    darker_grey, DARKER_GREY, 0x546E7A;
+    /// A `Colour` with the RGB value `(250, 177, 237)`.
+    fabled_pink, FABLED_PINK, 0xFAB1ED;
+    /// A `Colour` with the RGB value `(136, 130, 196)`.
+    faded_purple, FADED_PURPLE, 0x8882C4;
+    /// A `Colour` with the RGB value `(17, 202, 128)`.
+    fooyoo, FOOYOO, 0x11CA80;
+    /// A `Colour` with the RGB value `(241, 196, 15)`.
+    gold, GOLD, 0xF1C40F;
+    /// A `Colour` with the RGB value `(186, 218, 85)`.
+    kerbal, KERBAL, 0xBADA55;
+    /// A `Colour` with the RGB value `(151, 156, 159)`.
+    light_grey, LIGHT_GREY, 0x979C9F;
+    /// A `Colour` with the RGB value `(149, 165, 166)`.
+    lighter_grey, LIGHTER_GREY, 0x95A5A6;
+    /// A `Colour` with the RGB value `(233, 30, 99)`.
+    magenta, MAGENTA, 0xE91E63;
+    /// A `Colour` with the RGB value `(230, 131, 151)`.
+    meibe_pink, MEIBE_PINK, 0xE68397;
+    /// A `Colour` with the RGB value `(230, 126, 34)`.
+    orange, ORANGE, 0xE67E22;
+    /// A `Colour` with the RGB value `(155, 89, 182)`.
+    purple, PURPLE, 0x9B59B6;
+    /// A `Colour` with the RGB value `(231, 76, 60)`.
+    red, RED, 0xE74C3C;
+    /// A `Colour` with the RGB value `(117, 150, 255)`.
+    rohrkatze_blue, ROHRKATZE_BLUE, 0x7596FF;
+    /// A `Colour` with the RGB value `(246, 219, 216)`.
+    rosewater, ROSEWATER, 0xF6DBD8;
+    /// A `Colour` with the RGB value `(26, 188, 156)`.
+    teal, TEAL, 0x1ABC9C;
+    /// A `Colour` with the RGB value `(255, 255, 255)`.
+    white, WHITE, 0xFFFFFF;
 }
 
 impl Default for Colour {
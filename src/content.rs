@@ -0,0 +1,586 @@
+//! Parsing message content into a stream of tokens, such as mentions, code,
+//! and styled text.
+//!
+//! This is for working with the structure of content that has already been
+//! written, such as a message received from Discord, rather than with
+//! patterns that miss cases like mentions within code blocks.
+//!
+//! # Examples
+//!
+//! Find the users mentioned outside of code:
+//!
+//! ```rust
+//! use serenity_utils::content::{self, TokenKind};
+//!
+//! let tokens = content::parse("**Hi <@114941315417899012>!** `<@81384788765712384>`");
+//!
+//! let mut users = vec![];
+//! let mut pending = tokens.iter().collect::<Vec<_>>();
+//!
+//! while let Some(token) = pending.pop() {
+//!     match token.kind {
+//!         TokenKind::UserMention { id, .. } => users.push(id),
+//!         TokenKind::Styled { ref children, .. } => pending.extend(children),
+//!         _ => {},
+//!     }
+//! }
+//!
+//! assert_eq!(users, [114941315417899012]);
+//! ```
+//!
+//! Tokens can be written back out as content with [`render`], such as after
+//! changing them with [`map_tokens`].
+//!
+//! [`map_tokens`]: fn.map_tokens.html
+//! [`render`]: fn.render.html
+
+use std::fmt::Write as FmtWrite;
+use std::mem;
+use std::ops::Range;
+use super::markdown::{closing_run, code_at, flanking, marker};
+use super::{parse_mention, EmojiIdentifier, Mention, TimestampStyle};
+
+/// A token of message content, as parsed by [`parse`].
+///
+/// [`parse`]: fn.parse.html
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Token {
+    /// What the token is.
+    pub kind: TokenKind,
+    /// The byte range of the content which the token was parsed from.
+    pub range: Range<usize>,
+}
+
+/// The kinds of [`Token`].
+///
+/// [`Token`]: struct.Token.html
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum TokenKind {
+    /// Text with no special meaning, exactly as written, including any
+    /// escaping backslashes and any markers which weren't closed.
+    Text(String),
+    /// A mention of a user, in the form of `<@id>` or `<@!id>`.
+    UserMention {
+        /// The Id of the user.
+        id: u64,
+        /// Whether the mention is in the older form of `<@!id>`, once used
+        /// to show the user's nickname, which Discord now shows the same way.
+        nickname: bool,
+    },
+    /// A mention of a channel, in the form of `<#id>`.
+    ChannelMention(u64),
+    /// A mention of a role, in the form of `<@&id>`.
+    RoleMention(u64),
+    /// A custom emoji, in the form of `<:name:id>` or `<a:name:id>`.
+    CustomEmoji(EmojiIdentifier),
+    /// A timestamp, in the form of `<t:timestamp>` or `<t:timestamp:style>`.
+    Timestamp {
+        /// The timestamp, in seconds since the Unix epoch.
+        timestamp: i64,
+        /// The style of the timestamp, if one was given.
+        style: Option<TimestampStyle>,
+    },
+    /// A mention of a slash command, in the form of `</name:id>`.
+    CommandMention {
+        /// The Id of the command.
+        id: u64,
+        /// The name of the command, including any subcommand group and
+        /// subcommand, separated by spaces.
+        name: String,
+    },
+    /// An inline code span.
+    CodeSpan {
+        /// The code, exactly as written between the backticks.
+        code: String,
+        /// The number of backticks on each side of the code, either `1` or
+        /// `2`.
+        backticks: usize,
+    },
+    /// A code block, fenced by three backticks.
+    CodeBlock {
+        /// The language given on the block's first line, if any.
+        lang: Option<String>,
+        /// The code in the block, after the language's line if there is one,
+        /// up to the closing fence.
+        body: String,
+    },
+    /// Text in a markdown style, such as bold.
+    Styled {
+        /// The style of the text.
+        style: Style,
+        /// The tokens within the style's markers.
+        children: Vec<Token>,
+    },
+}
+
+/// The markdown style of a [`TokenKind::Styled`] token.
+///
+/// Italic text can be written with either `*` or `_`, and these are kept
+/// apart so that the content can be written again as it was.
+///
+/// [`TokenKind::Styled`]: enum.TokenKind.html#variant.Styled
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Style {
+    /// Bold text, written with `**`.
+    Bold,
+    /// Italic text, written with `*`.
+    Italic,
+    /// Italic text, written with `_`.
+    ItalicUnderscore,
+    /// Underlined text, written with `__`.
+    Underline,
+    /// Struck-through text, written with `~~`.
+    Strikethrough,
+    /// A spoiler, written with `||`.
+    Spoiler,
+}
+
+impl Style {
+    /// The marker written on either side of text in the style.
+    pub fn marker(&self) -> &'static str {
+        match *self {
+            Style::Bold => "**",
+            Style::Italic => "*",
+            Style::ItalicUnderscore => "_",
+            Style::Underline => "__",
+            Style::Strikethrough => "~~",
+            Style::Spoiler => "||",
+        }
+    }
+
+    fn from_marker(marker: &str) -> Option<Style> {
+        Some(match marker {
+            "**" => Style::Bold,
+            "*" => Style::Italic,
+            "_" => Style::ItalicUnderscore,
+            "__" => Style::Underline,
+            "~~" => Style::Strikethrough,
+            "||" => Style::Spoiler,
+            _ => return None,
+        })
+    }
+}
+
+/// A style which has been opened but not yet closed.
+struct Open {
+    style: Style,
+    start: usize,
+    children: Vec<Token>,
+}
+
+/// Parses message content into tokens, in a single pass from start to end.
+///
+/// Mentions, custom emoji, timestamps, code spans, code blocks, and styled
+/// text are recognised in the same way as by Discord; everything else,
+/// including anything malformed, such as a mention with an invalid Id or a
+/// marker which is never closed, is text. Nothing within code is parsed, and
+/// a character escaped with a backslash is text along with the backslash.
+///
+/// Adjacent text is always joined into a single token, and the ranges of the
+/// tokens cover the whole content, in order.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity_utils::content::{self, Style, Token, TokenKind};
+///
+/// let tokens = content::parse("hi **<@114941315417899012>**");
+///
+/// assert_eq!(tokens, [
+///     Token {
+///         kind: TokenKind::Text("hi ".to_string()),
+///         range: 0..3,
+///     },
+///     Token {
+///         kind: TokenKind::Styled {
+///             style: Style::Bold,
+///             children: vec![Token {
+///                 kind: TokenKind::UserMention {
+///                     id: 114941315417899012,
+///                     nickname: false,
+///                 },
+///                 range: 5..26,
+///             }],
+///         },
+///         range: 3..28,
+///     },
+/// ]);
+/// ```
+pub fn parse(content: &str) -> Vec<Token> { scan(content).0 }
+
+/// Finds the markers which open formatting that is never closed, as parsed
+/// by [`parse`], returning the byte offset and marker of each, in the order
+/// they appear.
+///
+/// A code span or block which is never closed is returned as the run of
+/// backticks that would have opened it, up to three. A marker which can't
+/// open formatting where it is, such as a `*` between spaces, isn't
+/// returned, as it is text either way. Markers within a style which is
+/// closed before them, such as the `~~` in `**a ~~b**`, are returned too.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity_utils::content;
+///
+/// assert!(content::unclosed_markers("***a*** and 2 * 3").is_empty());
+/// assert_eq!(content::unclosed_markers("**a ||b `c"), [(0, "**"), (4, "||"), (8, "`")]);
+/// ```
+///
+/// [`parse`]: fn.parse.html
+pub fn unclosed_markers(content: &str) -> Vec<(usize, &'static str)> {
+    let mut unclosed = scan(content).1;
+    unclosed.sort();
+
+    unclosed
+}
+
+/// Parses content into tokens, along with the markers which were never
+/// closed.
+fn scan(content: &str) -> (Vec<Token>, Vec<(usize, &'static str)>) {
+    let mut tokens = vec![];
+    let mut unclosed = vec![];
+    let mut open: Vec<Open> = vec![];
+    let mut idx = 0;
+
+    while let Some(c) = content[idx..].chars().next() {
+        let rest = &content[idx..];
+        let prev = content[..idx].chars().next_back();
+
+        let (token, len) = if c == '`' {
+            match code(rest) {
+                Some((kind, len)) => (Some(kind), len),
+                None => {
+                    let run = rest.len() - rest.trim_start_matches('`').len();
+                    unclosed.push((idx, &"```"[..run.min(3)]));
+
+                    (None, run)
+                },
+            }
+        } else if c == '\\' {
+            match rest[1..].chars().next() {
+                Some(escaped) if escaped.is_ascii_punctuation() => (None, 1 + escaped.len_utf8()),
+                _ => (None, 1),
+            }
+        } else if c == '<' {
+            match tag(rest) {
+                Some((kind, len)) => (Some(kind), len),
+                None => (None, 1),
+            }
+        } else {
+            let innermost = open.last().map(|open| open.style.marker());
+
+            if let Some(marker) = marker(rest, prev, innermost) {
+                let (closes, opens) = flanking(marker, prev, rest[marker.len()..].chars().next());
+
+                // A style is only closed if there is something within it.
+                let closed = if closes {
+                    open.iter()
+                        .rposition(|open| open.style.marker() == marker)
+                        .filter(|&position| {
+                            position + 1 < open.len() || !open[position].children.is_empty()
+                        })
+                } else {
+                    None
+                };
+
+                if let Some(position) = closed {
+                    while open.len() > position + 1 {
+                        unwind(content, &mut open, &mut tokens, &mut unclosed);
+                    }
+
+                    let style = open.pop().expect("closed style is open");
+                    let token = Token {
+                        kind: TokenKind::Styled {
+                            style: style.style,
+                            children: style.children,
+                        },
+                        range: style.start..idx + marker.len(),
+                    };
+
+                    push(current(&mut open, &mut tokens), content, token);
+                    idx += marker.len();
+
+                    continue;
+                }
+
+                if opens {
+                    if let Some(style) = Style::from_marker(marker) {
+                        open.push(Open {
+                            style,
+                            start: idx,
+                            children: vec![],
+                        });
+                        idx += marker.len();
+
+                        continue;
+                    }
+                }
+
+                (None, marker.len())
+            } else {
+                (None, c.len_utf8())
+            }
+        };
+
+        let token = Token {
+            kind: token.unwrap_or_else(|| TokenKind::Text(rest[..len].to_string())),
+            range: idx..idx + len,
+        };
+
+        push(current(&mut open, &mut tokens), content, token);
+        idx += len;
+    }
+
+    while !open.is_empty() {
+        unwind(content, &mut open, &mut tokens, &mut unclosed);
+    }
+
+    (tokens, unclosed)
+}
+
+/// Writes tokens out as message content, the inverse of [`parse`].
+///
+/// Rendering the tokens parsed from content gives exactly the same content.
+///
+/// A code span is written with its number of backticks, unless its code
+/// contains a run of that many backticks, which would close it early; in
+/// that case, it's written with whichever of one or two backticks doesn't
+/// appear as a run in the code.
+///
+/// The ranges of the tokens are ignored, so that tokens can be changed or
+/// created without working them out.
+///
+/// # Examples
+///
+/// Hide every spoiler's text:
+///
+/// ```rust
+/// use serenity_utils::content::{self, Style, TokenKind};
+///
+/// let mut tokens = content::parse("The answer is ||42||, in **bold**.");
+///
+/// for token in &mut tokens {
+///     if let TokenKind::Styled { style: Style::Spoiler, ref mut children } = token.kind {
+///         children.clear();
+///     }
+/// }
+///
+/// assert_eq!(content::render(&tokens), "The answer is ||||, in **bold**.");
+/// ```
+///
+/// [`parse`]: fn.parse.html
+pub fn render(tokens: &[Token]) -> String {
+    let mut content = String::new();
+    render_into(&mut content, tokens);
+
+    content
+}
+
+fn render_into(content: &mut String, tokens: &[Token]) {
+    for token in tokens {
+        let _ = match token.kind {
+            TokenKind::Text(ref text) => {
+                content.push_str(text);
+
+                Ok(())
+            },
+            TokenKind::UserMention { id, nickname: true } => write!(content, "<@!{}>", id),
+            TokenKind::UserMention { id, nickname: false } => write!(content, "<@{}>", id),
+            TokenKind::ChannelMention(id) => write!(content, "<#{}>", id),
+            TokenKind::RoleMention(id) => write!(content, "<@&{}>", id),
+            TokenKind::CustomEmoji(ref emoji) => write!(content, "{}", emoji),
+            TokenKind::Timestamp { timestamp, style: Some(style) } => {
+                write!(content, "<t:{}:{}>", timestamp, style)
+            },
+            TokenKind::Timestamp { timestamp, style: None } => write!(content, "<t:{}>", timestamp),
+            TokenKind::CommandMention { id, ref name } => write!(content, "</{}:{}>", name, id),
+            TokenKind::CodeSpan { ref code, backticks } => {
+                let fence = code_fence(code, backticks);
+
+                write!(content, "{}{}{}", fence, code, fence)
+            },
+            TokenKind::CodeBlock { ref lang, ref body } => {
+                content.push_str("```");
+
+                if let Some(ref lang) = *lang {
+                    content.push_str(lang);
+                    content.push('\n');
+                }
+
+                write!(content, "{}```", body)
+            },
+            TokenKind::Styled { style, ref children } => {
+                content.push_str(style.marker());
+                render_into(content, children);
+                content.push_str(style.marker());
+
+                Ok(())
+            },
+        };
+    }
+}
+
+/// Chooses the backticks to fence a code span with, preferring the given
+/// number if no run of that many backticks appears in the code.
+fn code_fence(code: &str, backticks: usize) -> &'static str {
+    let fits = |backticks: usize| backticks > 0 && closing_run(code, backticks).is_none();
+
+    match backticks {
+        1 | 2 if fits(backticks) => &"``"[..backticks],
+        _ if fits(1) => "`",
+        _ => "``",
+    }
+}
+
+/// Parses content, passes each token through a function, and renders the
+/// tokens it returns, dropping any for which it returns `None`.
+///
+/// The tokens within a style are passed through the function before the
+/// style itself. See [`render`] for how the tokens are written out.
+///
+/// # Examples
+///
+/// Drop every mention, and write custom emoji as their names:
+///
+/// ```rust
+/// use serenity_utils::content::{self, Token, TokenKind};
+///
+/// let content = "**Hi <@114941315417899012>** <:wave:302516740095606785>";
+///
+/// let cleaned = content::map_tokens(content, |token| match token.kind {
+///     TokenKind::UserMention { .. } | TokenKind::RoleMention(_) => None,
+///     TokenKind::CustomEmoji(ref emoji) => Some(Token {
+///         kind: TokenKind::Text(format!(":{}:", emoji.name)),
+///         range: token.range.clone(),
+///     }),
+///     _ => Some(token),
+/// });
+///
+/// assert_eq!(cleaned, "**Hi ** :wave:");
+/// ```
+///
+/// [`render`]: fn.render.html
+pub fn map_tokens<F>(content: &str, mut f: F) -> String
+    where F: FnMut(Token) -> Option<Token> {
+    render(&map_all(parse(content), &mut f))
+}
+
+fn map_all<F>(tokens: Vec<Token>, f: &mut F) -> Vec<Token>
+    where F: FnMut(Token) -> Option<Token> {
+    tokens
+        .into_iter()
+        .filter_map(|mut token| {
+            if let TokenKind::Styled { ref mut children, .. } = token.kind {
+                *children = map_all(mem::take(children), f);
+            }
+
+            f(token)
+        })
+        .collect()
+}
+
+/// The tokens of the innermost open style, or the top-level tokens if there
+/// is none.
+fn current<'a>(open: &'a mut [Open], tokens: &'a mut Vec<Token>) -> &'a mut Vec<Token> {
+    match open.last_mut() {
+        Some(open) => &mut open.children,
+        None => tokens,
+    }
+}
+
+/// Pushes a token, joining it to the previous one if both are text.
+fn push(tokens: &mut Vec<Token>, content: &str, token: Token) {
+    if let TokenKind::Text(_) = token.kind {
+        if let Some(&mut Token { kind: TokenKind::Text(ref mut text), ref mut range }) =
+            tokens.last_mut() {
+            if range.end == token.range.start {
+                range.end = token.range.end;
+                text.push_str(&content[token.range]);
+
+                return;
+            }
+        }
+    }
+
+    tokens.push(token);
+}
+
+/// Gives up on the innermost open style, which was never closed, making its
+/// marker text and moving its tokens out to the style around it.
+fn unwind(content: &str, open: &mut Vec<Open>, tokens: &mut Vec<Token>,
+          unclosed: &mut Vec<(usize, &'static str)>) {
+    let style = match open.pop() {
+        Some(style) => style,
+        None => return,
+    };
+
+    let marker = style.style.marker();
+    unclosed.push((style.start, marker));
+    let parent = current(open, tokens);
+
+    push(parent, content, Token {
+        kind: TokenKind::Text(marker.to_string()),
+        range: style.start..style.start + marker.len(),
+    });
+
+    for child in style.children {
+        push(parent, content, child);
+    }
+}
+
+/// Parses a code span or block at the start of the content, returning it and
+/// its length.
+fn code(content: &str) -> Option<(TokenKind, usize)> {
+    let (fence, len) = code_at(content)?;
+    let inner = &content[fence..len - fence];
+
+    if fence < 3 {
+        let kind = TokenKind::CodeSpan {
+            code: inner.to_string(),
+            backticks: fence,
+        };
+
+        return Some((kind, len));
+    }
+
+    let (lang, body) = match inner.find('\n') {
+        Some(newline) if newline > 0 && !inner[..newline].contains(char::is_whitespace) => {
+            (Some(inner[..newline].to_string()), &inner[newline + 1..])
+        },
+        _ => (None, inner),
+    };
+
+    let kind = TokenKind::CodeBlock {
+        lang,
+        body: body.to_string(),
+    };
+
+    Some((kind, len))
+}
+
+/// Parses a tag, such as a mention, at the start of the content, returning it
+/// and its length.
+fn tag(content: &str) -> Option<(TokenKind, usize)> {
+    let end = content.find('>')? + 1;
+    let tag = &content[..end];
+
+    if tag.len() > 128 {
+        return None;
+    }
+
+    let kind = match parse_mention(tag)? {
+        Mention::Channel(id) => TokenKind::ChannelMention(id),
+        Mention::Command { id, name } => TokenKind::CommandMention { id, name },
+        Mention::Emoji(emoji) => TokenKind::CustomEmoji(emoji),
+        Mention::Role(id) => TokenKind::RoleMention(id),
+        Mention::Timestamp { style, timestamp } => TokenKind::Timestamp { timestamp, style },
+        Mention::User(id) => TokenKind::UserMention {
+            id,
+            nickname: tag.starts_with("<@!"),
+        },
+        Mention::Navigation(_) => return None,
+    };
+
+    Some((kind, end))
+}
@@ -0,0 +1,361 @@
+use std::collections::HashMap;
+use super::{is_mass_mention, parse_mention, Mention};
+use super::markdown::code_at;
+
+/// Options for how [`content_safe`] makes content safe, including the names
+/// used to replace mentions of users, roles, and channels with plain text.
+///
+/// By default, every kind of mention is cleaned, as that is the safe choice.
+/// Users are shown by their nickname if they have one, without their
+/// discriminator.
+///
+/// # Examples
+///
+/// Allow channel mentions, which don't ping anyone:
+///
+/// ```rust
+/// use serenity_utils::{content_safe, ContentSafeOptions};
+///
+/// let options = ContentSafeOptions::new()
+///     .clean_channel(false)
+///     .user(114941315417899012, "acdenisSK")
+///     .role(136107769680887808, "Moderators");
+///
+/// assert_eq!(
+///     content_safe("<@114941315417899012>, see <#81384788765712384>", &options),
+///     "@acdenisSK, see <#81384788765712384>",
+/// );
+/// ```
+///
+/// [`content_safe`]: fn.content_safe.html
+#[derive(Clone, Debug)]
+pub struct ContentSafeOptions {
+    channels: HashMap<u64, String>,
+    clean_channel: bool,
+    clean_everyone: bool,
+    clean_here: bool,
+    clean_role: bool,
+    clean_user: bool,
+    roles: HashMap<u64, String>,
+    show_discriminator: bool,
+    show_nickname: bool,
+    users: HashMap<u64, ResolvedUser>,
+}
+
+impl ContentSafeOptions {
+    /// Creates a new set of options, without any names, which cleans every
+    /// kind of mention.
+    pub fn new() -> ContentSafeOptions { ContentSafeOptions::default() }
+
+    /// Sets the name of the channel with the given Id, replacing its mentions
+    /// with `#name`.
+    pub fn channel(mut self, id: u64, name: &str) -> Self {
+        self.channels.insert(id, name.to_string());
+
+        self
+    }
+
+    /// Sets the name of the role with the given Id, replacing its mentions
+    /// with `@name`.
+    pub fn role(mut self, id: u64, name: &str) -> Self {
+        self.roles.insert(id, name.to_string());
+
+        self
+    }
+
+    /// Sets the names of the user with the given Id, replacing their mentions
+    /// with `@name`.
+    ///
+    /// This can be given just the user's name, or a [`ResolvedUser`] with
+    /// their other names too.
+    ///
+    /// [`ResolvedUser`]: struct.ResolvedUser.html
+    pub fn user<U: Into<ResolvedUser>>(mut self, id: u64, user: U) -> Self {
+        self.users.insert(id, user.into());
+
+        self
+    }
+
+    /// Sets whether to replace channel mentions. Defaults to `true`.
+    pub fn clean_channel(mut self, clean: bool) -> Self {
+        self.clean_channel = clean;
+
+        self
+    }
+
+    /// Sets whether to break up `@everyone` mentions. Defaults to `true`.
+    pub fn clean_everyone(mut self, clean: bool) -> Self {
+        self.clean_everyone = clean;
+
+        self
+    }
+
+    /// Sets whether to break up `@here` mentions. Defaults to `true`.
+    pub fn clean_here(mut self, clean: bool) -> Self {
+        self.clean_here = clean;
+
+        self
+    }
+
+    /// Sets whether to replace role mentions. Defaults to `true`.
+    pub fn clean_role(mut self, clean: bool) -> Self {
+        self.clean_role = clean;
+
+        self
+    }
+
+    /// Sets whether to replace user mentions. Defaults to `true`.
+    pub fn clean_user(mut self, clean: bool) -> Self {
+        self.clean_user = clean;
+
+        self
+    }
+
+    /// Sets whether to show users' discriminators after their names, such as
+    /// `@Zeyla#5479`, for users that have one. Defaults to `false`.
+    pub fn show_discriminator(mut self, show: bool) -> Self {
+        self.show_discriminator = show;
+
+        self
+    }
+
+    /// Sets whether to show users by their nickname, if they have one, rather
+    /// than their global name. Defaults to `true`.
+    pub fn show_nickname(mut self, show: bool) -> Self {
+        self.show_nickname = show;
+
+        self
+    }
+
+    fn user_name(&self, user: &ResolvedUser) -> String {
+        let name = match (user.nickname.as_ref(), user.global_name.as_ref()) {
+            (Some(nickname), _) if self.show_nickname => nickname,
+            (_, Some(global_name)) => global_name,
+            _ => &user.name,
+        };
+
+        match user.discriminator {
+            Some(discriminator) if self.show_discriminator && discriminator != 0 => {
+                format!("{}#{:04}", name, discriminator)
+            },
+            _ => name.to_string(),
+        }
+    }
+}
+
+impl Default for ContentSafeOptions {
+    fn default() -> ContentSafeOptions {
+        ContentSafeOptions {
+            channels: HashMap::new(),
+            clean_channel: true,
+            clean_everyone: true,
+            clean_here: true,
+            clean_role: true,
+            clean_user: true,
+            roles: HashMap::new(),
+            show_discriminator: false,
+            show_nickname: true,
+            users: HashMap::new(),
+        }
+    }
+}
+
+/// The names of a user, as used by [`content_safe`] to replace mentions of
+/// them.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity_utils::{content_safe, ContentSafeOptions, ResolvedUser};
+///
+/// let user = ResolvedUser {
+///     discriminator: Some(5479),
+///     global_name: Some("Zey".to_string()),
+///     nickname: Some("zey [away]".to_string()),
+///     ..ResolvedUser::from("Zeyla")
+/// };
+/// let options = ContentSafeOptions::new().user(77, user);
+///
+/// assert_eq!(content_safe("<@77>", &options), "@zey [away]");
+///
+/// let options = options.show_nickname(false).show_discriminator(true);
+///
+/// assert_eq!(content_safe("<@77>", &options), "@Zey#5479");
+/// ```
+///
+/// [`content_safe`]: fn.content_safe.html
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ResolvedUser {
+    /// The user's discriminator, if they have one. Users which have migrated
+    /// to unique usernames have a discriminator of `0`, which isn't shown.
+    pub discriminator: Option<u16>,
+    /// The user's global display name, if they have set one.
+    pub global_name: Option<String>,
+    /// The user's username.
+    pub name: String,
+    /// The user's nickname in the guild, if they have one.
+    pub nickname: Option<String>,
+}
+
+impl<'a> From<&'a str> for ResolvedUser {
+    fn from(name: &'a str) -> ResolvedUser {
+        ResolvedUser {
+            name: name.to_string(),
+            ..ResolvedUser::default()
+        }
+    }
+}
+
+/// Makes content safe to send again, such as when echoing a user's message,
+/// so that it doesn't ping anyone.
+///
+/// This makes a single pass over the content:
+///
+/// - mentions of users, roles, and channels are replaced with their names,
+///   as set in the options, in the form of `@name` or `#name`. Mentions of
+///   unknown users, roles, and channels are replaced with `@invalid-user`,
+///   `@deleted-role`, and `#deleted-channel`;
+/// - `@everyone` and `@here` are broken up with a zero-width space.
+///
+/// Each of these can be turned off in the options. Names are always made
+/// safe, so that a role named `everyone` doesn't form a mass mention.
+/// Content inside of inline code and code blocks is left untouched, as
+/// Discord displays it as written.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity_utils::{content_safe, ContentSafeOptions};
+///
+/// let options = ContentSafeOptions::new().user(114941315417899012, "acdenisSK");
+/// let content = "<@!114941315417899012> and <@1>, look at <#2> @everyone `<@1>`";
+///
+/// assert_eq!(
+///     content_safe(content, &options),
+///     "@acdenisSK and @invalid-user, look at #deleted-channel @\u{200B}everyone `<@1>`",
+/// );
+/// ```
+pub fn content_safe(content: &str, options: &ContentSafeOptions) -> String {
+    let mut safe = String::with_capacity(content.len());
+    let mut rest = content;
+
+    while let Some(c) = rest.chars().next() {
+        // An escaped backtick doesn't open code, and so anything after it
+        // is still cleaned. Escaped `<` and `@` are cleaned as usual, in case
+        // a client shows them differently.
+        if c == '\\' {
+            let escaped = rest[1..].chars().next().filter(|&c| {
+                c.is_ascii_punctuation() && c != '<' && c != '@'
+            });
+            let len = 1 + escaped.map_or(0, char::len_utf8);
+            safe.push_str(&rest[..len]);
+            rest = &rest[len..];
+
+            continue;
+        }
+
+        if c == '`' {
+            let len = code_len(rest);
+            safe.push_str(&rest[..len]);
+            rest = &rest[len..];
+
+            continue;
+        }
+
+        if c == '<' {
+            if let Some((len, prefix, name)) = mention_replacement(rest, options) {
+                safe.push(prefix);
+                defuse_mentions(&mut safe, &name, prefix == '@');
+                rest = &rest[len..];
+
+                continue;
+            }
+        }
+
+        safe.push(c);
+
+        if c == '@' && is_cleaned_mass_mention(&rest[1..], options) {
+            safe.push('\u{200B}');
+        }
+
+        rest = &rest[c.len_utf8()..];
+    }
+
+    safe
+}
+
+/// Pushes text, breaking up mass mentions and mention tags within it with
+/// zero-width spaces.
+///
+/// If `after_at` is true, the text follows an `@`, and so is checked for
+/// forming a mass mention with it.
+fn defuse_mentions(buf: &mut String, text: &str, after_at: bool) {
+    if after_at && is_mass_mention(text) {
+        buf.push('\u{200B}');
+    }
+
+    for (idx, c) in text.char_indices() {
+        buf.push(c);
+
+        let rest = &text[idx + 1..];
+        let tag = c == '<' && (rest.starts_with('@') || rest.starts_with('#'));
+
+        if tag || (c == '@' && is_mass_mention(rest)) {
+            buf.push('\u{200B}');
+        }
+    }
+}
+
+/// Determines whether the content following an `@` forms a mass mention
+/// which the options clean.
+fn is_cleaned_mass_mention(rest: &str, options: &ContentSafeOptions) -> bool {
+    (options.clean_everyone && starts_with_name(rest, "everyone"))
+        || (options.clean_here && starts_with_name(rest, "here"))
+}
+
+fn starts_with_name(rest: &str, name: &str) -> bool {
+    match rest.get(..name.len()) {
+        Some(prefix) => prefix.eq_ignore_ascii_case(name),
+        None => false,
+    }
+}
+
+/// Determines the length of the code span or block at the start of the
+/// content, including its backticks.
+///
+/// If the backticks aren't closed, only their length is returned.
+fn code_len(content: &str) -> usize {
+    match code_at(content) {
+        Some((_, len)) => len,
+        None => content.len() - content.trim_start_matches('`').len(),
+    }
+}
+
+/// If the content starts with a mention which the options clean, returns its
+/// length along with the prefix and name to replace it with.
+fn mention_replacement(content: &str, options: &ContentSafeOptions)
+    -> Option<(usize, char, String)> {
+    let end = content.find('>')?;
+    let name = |names: &HashMap<u64, String>, id, fallback: &str| match names.get(&id) {
+        Some(name) => name.clone(),
+        None => fallback.to_string(),
+    };
+
+    let (prefix, name) = match parse_mention(&content[..=end])? {
+        Mention::Channel(id) if options.clean_channel => {
+            ('#', name(&options.channels, id, "deleted-channel"))
+        },
+        Mention::Role(id) if options.clean_role => ('@', name(&options.roles, id, "deleted-role")),
+        Mention::User(id) if options.clean_user => {
+            let name = match options.users.get(&id) {
+                Some(user) => options.user_name(user),
+                None => "invalid-user".to_string(),
+            };
+
+            ('@', name)
+        },
+        _ => return None,
+    };
+
+    Some((end + 1, prefix, name))
+}
@@ -0,0 +1,90 @@
+use std::fmt::Write as FmtWrite;
+use std::time::Duration;
+
+/// How a duration is written, as by [`MessageBuilder::push_duration_styled`].
+///
+/// The default style writes every unit compactly, such as `1d 1h 1m 1s`.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity_utils::{DurationStyle, MessageBuilder};
+/// use std::time::Duration;
+///
+/// let style = DurationStyle {
+///     long: true,
+///     max_components: 2,
+/// };
+///
+/// let content = MessageBuilder::new()
+///     .push_duration_styled(Duration::from_secs(90_061), &style)
+///     .build();
+///
+/// assert_eq!(content, "1 day, 1 hour");
+/// ```
+///
+/// [`MessageBuilder::push_duration_styled`]: struct.MessageBuilder.html#method.push_duration_styled
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct DurationStyle {
+    /// Whether to write units in full, such as `3 minutes, 10 seconds`,
+    /// rather than compactly, such as `3m 10s`.
+    pub long: bool,
+    /// The most units to write, starting from the largest. At least one is
+    /// always written.
+    pub max_components: usize,
+}
+
+impl DurationStyle {
+    /// Creates the default style, the same as `DurationStyle::default`.
+    pub fn new() -> DurationStyle { DurationStyle::default() }
+}
+
+impl Default for DurationStyle {
+    fn default() -> DurationStyle {
+        DurationStyle {
+            long: false,
+            max_components: UNITS.len(),
+        }
+    }
+}
+
+/// Each unit's length in seconds, compact suffix, and long name.
+const UNITS: &[(u64, &str, &str)] = &[
+    (86_400, "d", "day"),
+    (3_600, "h", "hour"),
+    (60, "m", "minute"),
+    (1, "s", "second"),
+];
+
+/// Writes a duration in the given style, dropping any part of a second.
+pub fn write_duration(buf: &mut String, duration: Duration, style: &DurationStyle) {
+    let mut secs = duration.as_secs();
+    let mut written = 0;
+    let max = style.max_components.max(1);
+
+    for &(length, suffix, name) in UNITS {
+        let count = secs / length;
+        secs %= length;
+
+        // Zero units are skipped, unless the whole duration is zero.
+        if count == 0 && !(length == 1 && written == 0) {
+            continue;
+        }
+
+        if written > 0 {
+            buf.push_str(if style.long { ", " } else { " " });
+        }
+
+        let _ = if style.long {
+            write!(buf, "{} {}{}", count, name, if count == 1 { "" } else { "s" })
+        } else {
+            write!(buf, "{}{}", count, suffix)
+        };
+
+        written += 1;
+
+        if written == max {
+            break;
+        }
+    }
+}
@@ -0,0 +1,287 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// An emoji, as used in message content and reactions: either a guild's
+/// custom emoji or a unicode emoji.
+///
+/// The `Display` implementation formats custom emoji as their tag, in the form
+/// of `<:name:id>`, or `<a:name:id>` if animated. Unicode emoji are formatted
+/// as-is.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity_utils::ReactionType;
+///
+/// let custom = ReactionType::Custom {
+///     animated: true,
+///     id: 302516740095606785,
+///     name: "smugAnimeFace".to_string(),
+/// };
+///
+/// assert_eq!(custom.to_string(), "<a:smugAnimeFace:302516740095606785>");
+/// assert_eq!(ReactionType::from("✅").to_string(), "✅");
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum ReactionType {
+    /// A custom emoji belonging to a guild.
+    Custom {
+        /// Whether the emoji is animated.
+        animated: bool,
+        /// The Id of the emoji.
+        id: u64,
+        /// The name of the emoji.
+        name: String,
+    },
+    /// A unicode emoji, such as `"✅"`.
+    Unicode(String),
+}
+
+impl Display for ReactionType {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match *self {
+            ReactionType::Custom { animated, id, ref name } => {
+                let prefix = if animated { "a" } else { "" };
+
+                write!(f, "<{}:{}:{}>", prefix, name, id)
+            },
+            ReactionType::Unicode(ref unicode) => f.write_str(unicode),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ReactionType {
+    fn from(unicode: &'a str) -> ReactionType { ReactionType::Unicode(unicode.to_string()) }
+}
+
+impl From<String> for ReactionType {
+    fn from(unicode: String) -> ReactionType { ReactionType::Unicode(unicode) }
+}
+
+impl From<char> for ReactionType {
+    fn from(unicode: char) -> ReactionType { ReactionType::Unicode(unicode.to_string()) }
+}
+
+/// The parts of a custom emoji, as parsed from its tag by [`parse_emoji`].
+///
+/// The `Display` implementation formats the emoji as its tag again.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity_utils::{parse_emoji, ReactionType};
+///
+/// let emoji = parse_emoji("<a:blobcat:271629243716468736>").unwrap();
+///
+/// assert!(emoji.animated);
+/// assert_eq!(emoji.name, "blobcat");
+/// assert_eq!(emoji.id, 271629243716468736);
+/// assert_eq!(emoji.to_string(), "<a:blobcat:271629243716468736>");
+///
+/// // React with the emoji.
+/// let reaction = ReactionType::from(emoji);
+/// ```
+///
+/// [`parse_emoji`]: fn.parse_emoji.html
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct EmojiIdentifier {
+    /// Whether the emoji is animated.
+    pub animated: bool,
+    /// The Id of the emoji.
+    pub id: u64,
+    /// The name of the emoji.
+    pub name: String,
+}
+
+impl Display for EmojiIdentifier {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        let prefix = if self.animated { "a" } else { "" };
+
+        write!(f, "<{}:{}:{}>", prefix, self.name, self.id)
+    }
+}
+
+impl From<EmojiIdentifier> for ReactionType {
+    fn from(emoji: EmojiIdentifier) -> ReactionType {
+        ReactionType::Custom {
+            animated: emoji.animated,
+            id: emoji.id,
+            name: emoji.name,
+        }
+    }
+}
+
+/// Looks up a unicode emoji by its shortcode, the name used to write it in the
+/// official client, such as `white_check_mark` for ✅.
+///
+/// The name can optionally be surrounded by colons, as it is in the client.
+/// Only a set of commonly used emoji are included, and skin tone suffixes such
+/// as `:skin-tone-3:` aren't supported.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity_utils::emoji_shortcode;
+///
+/// assert_eq!(emoji_shortcode("white_check_mark"), Some("✅"));
+/// assert_eq!(emoji_shortcode(":tada:"), Some("🎉"));
+/// assert_eq!(emoji_shortcode("white_check_mrak"), None);
+/// ```
+pub fn emoji_shortcode(name: &str) -> Option<&'static str> {
+    let name = if name.len() > 2 && name.starts_with(':') && name.ends_with(':') {
+        &name[1..name.len() - 1]
+    } else {
+        name
+    };
+
+    SHORTCODES.binary_search_by_key(&name, |&(shortcode, _)| shortcode)
+        .ok()
+        .map(|index| SHORTCODES[index].1)
+}
+
+/// Shortcodes and their emoji, sorted by shortcode.
+const SHORTCODES: &[(&str, &str)] = &[
+    ("+1", "👍"),
+    ("-1", "👎"),
+    ("100", "💯"),
+    ("alarm_clock", "⏰"),
+    ("angry", "😠"),
+    ("apple", "🍎"),
+    ("arrow_backward", "◀\u{FE0F}"),
+    ("arrow_down", "⬇\u{FE0F}"),
+    ("arrow_forward", "▶\u{FE0F}"),
+    ("arrow_left", "⬅\u{FE0F}"),
+    ("arrow_right", "➡\u{FE0F}"),
+    ("arrow_up", "⬆\u{FE0F}"),
+    ("arrows_counterclockwise", "🔄"),
+    ("ballot_box_with_check", "☑\u{FE0F}"),
+    ("bangbang", "‼\u{FE0F}"),
+    ("bell", "🔔"),
+    ("black_heart", "🖤"),
+    ("blue_heart", "💙"),
+    ("blush", "😊"),
+    ("book", "📖"),
+    ("books", "📚"),
+    ("boom", "💥"),
+    ("brain", "🧠"),
+    ("broken_heart", "💔"),
+    ("bulb", "💡"),
+    ("cake", "🍰"),
+    ("calendar", "📆"),
+    ("camera", "📷"),
+    ("cat", "🐱"),
+    ("clap", "👏"),
+    ("cloud", "☁\u{FE0F}"),
+    ("coffee", "☕"),
+    ("construction", "🚧"),
+    ("cool", "🆒"),
+    ("crown", "👑"),
+    ("cry", "😢"),
+    ("dog", "🐶"),
+    ("eight", "8\u{FE0F}\u{20E3}"),
+    ("exclamation", "❗"),
+    ("expressionless", "😑"),
+    ("eyes", "👀"),
+    ("fire", "🔥"),
+    ("five", "5\u{FE0F}\u{20E3}"),
+    ("flushed", "😳"),
+    ("four", "4\u{FE0F}\u{20E3}"),
+    ("frowning", "😦"),
+    ("ghost", "👻"),
+    ("gift", "🎁"),
+    ("green_heart", "💚"),
+    ("grimacing", "😬"),
+    ("grin", "😁"),
+    ("grinning", "😀"),
+    ("hammer", "🔨"),
+    ("heart", "❤\u{FE0F}"),
+    ("heart_eyes", "😍"),
+    ("heavy_check_mark", "✔\u{FE0F}"),
+    ("heavy_minus_sign", "➖"),
+    ("heavy_plus_sign", "➕"),
+    ("hourglass", "⌛"),
+    ("hugging", "🤗"),
+    ("information_source", "ℹ\u{FE0F}"),
+    ("joy", "😂"),
+    ("key", "🔑"),
+    ("keycap_ten", "🔟"),
+    ("kiss", "💋"),
+    ("laughing", "😆"),
+    ("link", "🔗"),
+    ("lock", "🔒"),
+    ("mag", "🔍"),
+    ("memo", "📝"),
+    ("money_mouth", "🤑"),
+    ("moneybag", "💰"),
+    ("muscle", "💪"),
+    ("nerd", "🤓"),
+    ("neutral_face", "😐"),
+    ("nine", "9\u{FE0F}\u{20E3}"),
+    ("no_entry", "⛔"),
+    ("no_entry_sign", "🚫"),
+    ("ok", "🆗"),
+    ("ok_hand", "👌"),
+    ("one", "1\u{FE0F}\u{20E3}"),
+    ("orange_heart", "🧡"),
+    ("partying_face", "🥳"),
+    ("pause_button", "⏸\u{FE0F}"),
+    ("pensive", "😔"),
+    ("point_down", "👇"),
+    ("point_left", "👈"),
+    ("point_right", "👉"),
+    ("point_up", "☝\u{FE0F}"),
+    ("point_up_2", "👆"),
+    ("poop", "💩"),
+    ("pray", "🙏"),
+    ("purple_heart", "💜"),
+    ("pushpin", "📌"),
+    ("question", "❓"),
+    ("rage", "😡"),
+    ("raised_hands", "🙌"),
+    ("repeat", "🔁"),
+    ("robot", "🤖"),
+    ("rocket", "🚀"),
+    ("rofl", "🤣"),
+    ("scream", "😱"),
+    ("see_no_evil", "🙈"),
+    ("seven", "7\u{FE0F}\u{20E3}"),
+    ("shrug", "🤷"),
+    ("six", "6\u{FE0F}\u{20E3}"),
+    ("skull", "💀"),
+    ("sleeping", "😴"),
+    ("slight_frown", "🙁"),
+    ("slight_smile", "🙂"),
+    ("smile", "😄"),
+    ("smiley", "😃"),
+    ("smirk", "😏"),
+    ("sob", "😭"),
+    ("sparkles", "✨"),
+    ("star", "⭐"),
+    ("star2", "🌟"),
+    ("stop_button", "⏹\u{FE0F}"),
+    ("stuck_out_tongue", "😛"),
+    ("sunglasses", "😎"),
+    ("sweat_smile", "😅"),
+    ("tada", "🎉"),
+    ("thinking", "🤔"),
+    ("three", "3\u{FE0F}\u{20E3}"),
+    ("thumbsdown", "👎"),
+    ("thumbsup", "👍"),
+    ("tools", "🛠\u{FE0F}"),
+    ("track_next", "⏭\u{FE0F}"),
+    ("track_previous", "⏮\u{FE0F}"),
+    ("trophy", "🏆"),
+    ("two", "2\u{FE0F}\u{20E3}"),
+    ("unamused", "😒"),
+    ("upside_down", "🙃"),
+    ("warning", "⚠\u{FE0F}"),
+    ("wave", "👋"),
+    ("white_check_mark", "✅"),
+    ("white_heart", "🤍"),
+    ("wink", "😉"),
+    ("wrench", "🔧"),
+    ("x", "❌"),
+    ("yellow_heart", "💛"),
+    ("zap", "⚡"),
+    ("zero", "0\u{FE0F}\u{20E3}"),
+    ("zzz", "💤"),
+];
@@ -7,12 +7,36 @@ pub type Result<T> = StdResult<T, Error>;
 
 #[derive(Debug)]
 pub enum Error {
+    ContentFull(ContentFull),
     Io(IoError),
+    MessageTooLong(MessageTooLong),
+    ParseColour(ParseColourError),
+    ParseId(ParseIdError),
+    StaleCheckpoint(StaleCheckpoint),
+    Token(TokenError),
+    UnknownEmoji(UnknownEmoji),
+    /// An image isn't in a format Discord accepts, as returned by
+    /// [`read_image`].
+    ///
+    /// [`read_image`]: fn.read_image.html
+    UnsupportedImage,
 }
 
 impl Display for Error {
     fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        f.write_str(self.description())
+        use self::Error::*;
+
+        match *self {
+            ContentFull(ref inner) => inner.fmt(f),
+            Io(ref inner) => inner.fmt(f),
+            MessageTooLong(ref inner) => inner.fmt(f),
+            ParseColour(ref inner) => inner.fmt(f),
+            ParseId(ref inner) => inner.fmt(f),
+            StaleCheckpoint(ref inner) => inner.fmt(f),
+            Token(ref inner) => inner.fmt(f),
+            UnknownEmoji(ref inner) => inner.fmt(f),
+            UnsupportedImage => f.write_str("Image isn't a PNG, JPEG, GIF, or WebP"),
+        }
     }
 }
 
@@ -21,13 +45,411 @@ impl StdError for Error {
         use self::Error::*;
 
         match *self {
+            ContentFull(ref inner) => inner.description(),
             Io(ref inner) => inner.description(),
+            MessageTooLong(ref inner) => inner.description(),
+            ParseColour(ref inner) => inner.description(),
+            ParseId(ref inner) => inner.description(),
+            StaleCheckpoint(ref inner) => inner.description(),
+            Token(ref inner) => inner.description(),
+            UnknownEmoji(ref inner) => inner.description(),
+            UnsupportedImage => "Unsupported image format",
         }
     }
 }
 
+impl From<ContentFull> for Error {
+    fn from(err: ContentFull) -> Self {
+        Error::ContentFull(err)
+    }
+}
+
 impl From<IoError> for Error {
     fn from(err: IoError) -> Self {
         Error::Io(err)
     }
 }
+
+impl From<MessageTooLong> for Error {
+    fn from(err: MessageTooLong) -> Self {
+        Error::MessageTooLong(err)
+    }
+}
+
+impl From<ParseColourError> for Error {
+    fn from(err: ParseColourError) -> Self {
+        Error::ParseColour(err)
+    }
+}
+
+impl From<ParseIdError> for Error {
+    fn from(err: ParseIdError) -> Self {
+        Error::ParseId(err)
+    }
+}
+
+impl From<StaleCheckpoint> for Error {
+    fn from(err: StaleCheckpoint) -> Self {
+        Error::StaleCheckpoint(err)
+    }
+}
+
+impl From<TokenError> for Error {
+    fn from(err: TokenError) -> Self {
+        Error::Token(err)
+    }
+}
+
+impl From<UnknownEmoji> for Error {
+    fn from(err: UnknownEmoji) -> Self {
+        Error::UnknownEmoji(err)
+    }
+}
+
+/// An error returned when message content is longer than Discord allows.
+///
+/// Lengths are counted as by [`MessageBuilder::len`].
+///
+/// [`MessageBuilder::len`]: struct.MessageBuilder.html#method.len
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct MessageTooLong {
+    /// The length of the content.
+    pub length: usize,
+    /// The maximum length allowed.
+    pub limit: usize,
+}
+
+impl Display for MessageTooLong {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "Message is {} characters long, over the limit of {}", self.length, self.limit)
+    }
+}
+
+impl StdError for MessageTooLong {
+    fn description(&self) -> &str {
+        "Message too long"
+    }
+}
+
+/// An error returned when pushing content to a builder would take it over
+/// Discord's limit, as by [`MessageBuilder::push_checked`].
+///
+/// Lengths are counted as by [`MessageBuilder::len`].
+///
+/// [`MessageBuilder::len`]: struct.MessageBuilder.html#method.len
+/// [`MessageBuilder::push_checked`]: struct.MessageBuilder.html#method.push_checked
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ContentFull {
+    /// The length of the content which would have been pushed.
+    pub needed: usize,
+    /// The length which could still have been pushed.
+    pub remaining: usize,
+}
+
+impl Display for ContentFull {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "Content needs {} characters, but only {} remain", self.needed, self.remaining)
+    }
+}
+
+impl StdError for ContentFull {
+    fn description(&self) -> &str {
+        "Content full"
+    }
+}
+
+/// An error returned when rolling a builder back to a checkpoint which no
+/// longer matches its content, as by [`MessageBuilder::rollback_to`].
+///
+/// [`MessageBuilder::rollback_to`]: struct.MessageBuilder.html#method.rollback_to
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct StaleCheckpoint;
+
+impl Display for StaleCheckpoint {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.write_str("Checkpoint is stale, as the content it was taken at has since changed")
+    }
+}
+
+impl StdError for StaleCheckpoint {
+    fn description(&self) -> &str {
+        "Stale checkpoint"
+    }
+}
+
+/// The kind of formatting left open, as found by
+/// [`MessageBuilder::check_markdown`].
+///
+/// [`MessageBuilder::check_markdown`]: struct.MessageBuilder.html#method.check_markdown
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum MarkdownKind {
+    /// Bold text, opened with `**`.
+    Bold,
+    /// Italic text, opened with `*` or `_`.
+    Italic,
+    /// Underlined text, opened with `__`.
+    Underline,
+    /// Struck-through text, opened with `~~`.
+    Strikethrough,
+    /// A spoiler, opened with `||`.
+    Spoiler,
+    /// An inline code span, opened with one or two backticks.
+    CodeSpan,
+    /// A code block, opened with three backticks.
+    CodeBlock,
+}
+
+impl MarkdownKind {
+    fn name(&self) -> &'static str {
+        match *self {
+            MarkdownKind::Bold => "bold text",
+            MarkdownKind::Italic => "italic text",
+            MarkdownKind::Underline => "underlined text",
+            MarkdownKind::Strikethrough => "struck-through text",
+            MarkdownKind::Spoiler => "spoiler",
+            MarkdownKind::CodeSpan => "code span",
+            MarkdownKind::CodeBlock => "code block",
+        }
+    }
+}
+
+/// Formatting which is opened but never closed, as returned by
+/// [`MessageBuilder::check_markdown`].
+///
+/// [`MessageBuilder::check_markdown`]: struct.MessageBuilder.html#method.check_markdown
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct MarkdownIssue {
+    /// The kind of formatting left open.
+    pub kind: MarkdownKind,
+    /// The byte offset of the marker which opens the formatting.
+    pub offset: usize,
+}
+
+impl Display for MarkdownIssue {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "Unclosed {} at byte {}", self.kind.name(), self.offset)
+    }
+}
+
+impl StdError for MarkdownIssue {
+    fn description(&self) -> &str {
+        "Unclosed markdown"
+    }
+}
+
+/// An error returned when a hex colour code can't be parsed, such as by
+/// [`Colour::hex`].
+///
+/// [`Colour::hex`]: struct.Colour.html#method.hex
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ParseColourError {
+    /// There are no digits after the prefix.
+    Empty,
+    /// A character isn't a hex digit.
+    InvalidCharacter(char),
+    /// The value is over `0xFFFFFF`, and so isn't an RGB colour.
+    TooLarge,
+}
+
+impl Display for ParseColourError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match *self {
+            ParseColourError::InvalidCharacter(c) => write!(f, "Invalid hex digit {:?}", c),
+            _ => f.write_str(self.description()),
+        }
+    }
+}
+
+impl StdError for ParseColourError {
+    fn description(&self) -> &str {
+        use self::ParseColourError::*;
+
+        match *self {
+            Empty => "Colour code has no digits",
+            InvalidCharacter(_) => "Invalid hex digit",
+            TooLarge => "Colour code is over 0xFFFFFF",
+        }
+    }
+}
+
+/// An error returned when a string is neither an Id nor a mention of the
+/// expected kind, such as by [`MessageBuilder::try_user`].
+///
+/// [`MessageBuilder::try_user`]: struct.MessageBuilder.html#method.try_user
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseIdError {
+    /// The string that was given.
+    pub input: String,
+}
+
+impl Display for ParseIdError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "Invalid Id or mention {:?}", self.input)
+    }
+}
+
+impl StdError for ParseIdError {
+    fn description(&self) -> &str {
+        "Invalid Id or mention"
+    }
+}
+
+/// One of the three dot-separated parts of a bot token.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum TokenPart {
+    /// The first part, the bot's user Id encoded in base64.
+    UserId,
+    /// The second part, the time the token was generated.
+    Timestamp,
+    /// The third part, the HMAC of the rest of the token.
+    Hmac,
+}
+
+impl TokenPart {
+    fn name(&self) -> &'static str {
+        match *self {
+            TokenPart::UserId => "user Id",
+            TokenPart::Timestamp => "timestamp",
+            TokenPart::Hmac => "HMAC",
+        }
+    }
+}
+
+/// An error returned when a bot token is malformed, such as by
+/// [`validate_token`].
+///
+/// The token itself is never included, so errors are safe to log.
+///
+/// [`validate_token`]: fn.validate_token.html
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TokenError {
+    /// The token is empty.
+    Empty,
+    /// The token contains whitespace, often from being copied with a trailing
+    /// newline.
+    Whitespace,
+    /// The token doesn't have three dot-separated parts. Contains the number of
+    /// parts found.
+    PartCount(usize),
+    /// A part contains a character outside of the base64 alphabet.
+    InvalidCharacter(TokenPart),
+    /// A part is too short or too long to be genuine.
+    InvalidLength(TokenPart),
+}
+
+impl Display for TokenError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match *self {
+            TokenError::PartCount(count) => {
+                write!(f, "Token has {} dot-separated parts instead of 3", count)
+            },
+            TokenError::InvalidCharacter(part) => {
+                write!(f, "Token {} part has an invalid character", part.name())
+            },
+            TokenError::InvalidLength(part) => {
+                write!(f, "Token {} part has an invalid length", part.name())
+            },
+            _ => f.write_str(self.description()),
+        }
+    }
+}
+
+impl StdError for TokenError {
+    fn description(&self) -> &str {
+        use self::TokenError::*;
+
+        match *self {
+            Empty => "Token is empty",
+            Whitespace => "Token contains whitespace",
+            PartCount(_) => "Token doesn't have 3 parts",
+            InvalidCharacter(_) => "Token has an invalid character",
+            InvalidLength(_) => "Token has a part with an invalid length",
+        }
+    }
+}
+
+/// An error returned when an argument can't be read, such as by
+/// [`Args::single`].
+///
+/// [`Args::single`]: struct.Args.html#method.single
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ArgError<E> {
+    /// All of the arguments have already been read. Short for "end of
+    /// string".
+    Eos,
+    /// The argument couldn't be parsed. Contains the error from parsing it.
+    Parse(E),
+}
+
+impl<E: Display> Display for ArgError<E> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match *self {
+            ArgError::Eos => f.write_str("No arguments left"),
+            ArgError::Parse(ref inner) => write!(f, "Failed to parse argument: {}", inner),
+        }
+    }
+}
+
+impl<E: StdError + 'static> StdError for ArgError<E> {
+    fn description(&self) -> &str {
+        match *self {
+            ArgError::Eos => "No arguments left",
+            ArgError::Parse(_) => "Failed to parse argument",
+        }
+    }
+
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match *self {
+            ArgError::Eos => None,
+            ArgError::Parse(ref inner) => Some(inner),
+        }
+    }
+}
+
+/// An error returned when one of several arguments can't be parsed, such as by
+/// [`Args::multiple`].
+///
+/// [`Args::multiple`]: struct.Args.html#method.multiple
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct MultipleError<E> {
+    /// The index of the argument, counting from the first argument.
+    pub index: usize,
+    /// The error from parsing the argument.
+    pub error: E,
+}
+
+impl<E: Display> Display for MultipleError<E> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "Failed to parse argument {}: {}", self.index, self.error)
+    }
+}
+
+impl<E: StdError + 'static> StdError for MultipleError<E> {
+    fn description(&self) -> &str {
+        "Failed to parse argument"
+    }
+
+    fn source(&self) -> Option<&(dyn StdError + 'static)> { Some(&self.error) }
+}
+
+/// An error returned when an emoji shortcode isn't known, such as by
+/// [`MessageBuilder::push_emoji_shortcode`].
+///
+/// [`MessageBuilder::push_emoji_shortcode`]: struct.MessageBuilder.html#method.push_emoji_shortcode
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UnknownEmoji {
+    /// The shortcode that was given.
+    pub name: String,
+}
+
+impl Display for UnknownEmoji {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "Unknown emoji shortcode {:?}", self.name)
+    }
+}
+
+impl StdError for UnknownEmoji {
+    fn description(&self) -> &str {
+        "Unknown emoji shortcode"
+    }
+}
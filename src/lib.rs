@@ -4,11 +4,84 @@
 extern crate base64;
 extern crate serenity_common;
 
+#[cfg(feature = "chrono")]
+extern crate chrono;
+#[cfg(feature = "serde")]
+#[macro_use]
+extern crate serde;
+
+#[macro_use]
+mod macros;
+
+pub mod content;
+
+mod ansi;
+mod args;
+mod bytes;
 mod colour;
+mod content_safe;
+mod duration;
+mod emoji;
 mod error;
+mod markdown;
+mod mention;
+mod message_builder;
+mod progress;
+mod snowflake;
+mod split;
+mod status;
+mod table;
+mod timestamp;
 
+pub use self::ansi::{AnsiBuilder, AnsiColour, AnsiStyle};
+pub use self::args::{Args, ArgsIter, Delimiter};
+pub use self::bytes::ByteStyle;
 pub use self::colour::Colour;
-pub use self::error::{Error, Result};
+pub use self::content_safe::{content_safe, ContentSafeOptions, ResolvedUser};
+pub use self::duration::DurationStyle;
+pub use self::emoji::{emoji_shortcode, EmojiIdentifier, ReactionType};
+pub use self::error::{
+    ArgError,
+    ContentFull,
+    Error,
+    MarkdownIssue,
+    MarkdownKind,
+    MessageTooLong,
+    MultipleError,
+    ParseColourError,
+    ParseIdError,
+    Result,
+    StaleCheckpoint,
+    TokenError,
+    TokenPart,
+    UnknownEmoji,
+};
+pub use self::markdown::{remove_markdown, remove_markdown_with_urls};
+pub use self::mention::{GuildNavigation, Mention, MentionDisplay, Mentionable};
+pub use self::message_builder::{
+    Checkpoint,
+    Content,
+    ContentModifier,
+    MassMentions,
+    MessageBuilder,
+    Segment,
+    SegmentKind,
+    Styles,
+    MESSAGE_CODE_LIMIT,
+};
+pub use self::progress::ProgressStyle;
+pub use self::snowflake::{
+    decompose_snowflake,
+    generate_snowflake,
+    SnowflakeExt,
+    SnowflakeGenerator,
+    SnowflakeParts,
+    DISCORD_EPOCH,
+};
+pub use self::split::split_message;
+pub use self::status::StatusIcons;
+pub use self::table::{Alignment, TableBuilder};
+pub use self::timestamp::{find_timestamp_tags, IntoTimestamp, TimestampStyle, TimestampTags};
 
 // Note: Here for BC purposes.
 #[cfg(feature = "builder")]
@@ -21,6 +94,7 @@ use std::fs::File;
 use std::hash::Hash;
 use std::io::Read;
 use std::path::Path;
+use std::result::Result as StdResult;
 
 #[cfg(feature = "cache")]
 use cache::Cache;
@@ -28,6 +102,35 @@ use cache::Cache;
 use CACHE;
 
 /// Converts a HashMap into a final `serde_json::Map` representation.
+///
+/// This is useful for building the body of a request to an endpoint that the
+/// library doesn't wrap yet. To build a map from pairs without collecting
+/// them into a `HashMap` first, use [`json_map`].
+///
+/// # Examples
+///
+/// Build the body of a request to create a message:
+///
+/// ```rust
+/// extern crate serde_json;
+/// extern crate serenity_utils;
+///
+/// use serde_json::Value;
+/// use serenity_utils::hashmap_to_json_map;
+/// use std::collections::HashMap;
+///
+/// # fn main() {
+/// let mut map = HashMap::new();
+/// map.insert("content", Value::from("Hello!"));
+/// map.insert("tts", Value::from(false));
+///
+/// let body = Value::from(hashmap_to_json_map(map));
+///
+/// assert_eq!(body["content"], Value::from("Hello!"));
+/// # }
+/// ```
+///
+/// [`json_map`]: fn.json_map.html
 pub fn hashmap_to_json_map<T>(map: HashMap<T, Value>) -> Map<String, Value>
     where T: Eq + Hash + ToString {
     let mut json_map = Map::new();
@@ -39,6 +142,47 @@ pub fn hashmap_to_json_map<T>(map: HashMap<T, Value>) -> Map<String, Value>
     json_map
 }
 
+/// Builds a `serde_json::Map` from key-value pairs, converting each key and
+/// value.
+///
+/// This is a more general form of [`hashmap_to_json_map`], allowing a request
+/// body to be built inline from tuples. If a key appears more than once, the
+/// last value for it is kept.
+///
+/// # Examples
+///
+/// Build the body of a request to create a message with an embed:
+///
+/// ```rust
+/// extern crate serde_json;
+/// extern crate serenity_utils;
+///
+/// use serde_json::Value;
+/// use serenity_utils::json_map;
+///
+/// # fn main() {
+/// let embed = json_map(vec![("title", "Status"), ("description", "All good")]);
+/// let body = Value::from(json_map(vec![
+///     ("content", Value::from("Hello!")),
+///     ("embeds", Value::from(vec![Value::from(embed)])),
+/// ]));
+///
+/// assert_eq!(body["embeds"][0]["title"], Value::from("Status"));
+/// # }
+/// ```
+///
+/// [`hashmap_to_json_map`]: fn.hashmap_to_json_map.html
+pub fn json_map<I, K, V>(pairs: I) -> Map<String, Value>
+    where I: IntoIterator<Item = (K, V)>, K: Into<String>, V: Into<Value> {
+    let mut json_map = Map::new();
+
+    for (key, value) in pairs {
+        json_map.insert(key.into(), value.into());
+    }
+
+    json_map
+}
+
 /// Determines if a name is NSFW.
 ///
 /// This checks that the name is either `"nsfw"` or, for names longer than that,
@@ -92,6 +236,162 @@ pub fn is_nsfw(name: &str) -> bool {
     }
 }
 
+/// Breaks up `@everyone` and `@here` mentions in content, so that sending it
+/// won't ping anyone.
+///
+/// A zero-width space is inserted after the `@` of each mass mention. These
+/// are broken up regardless of their case, and also when they appear in the
+/// middle of a word, as Discord will still resolve them there.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity_utils;
+///
+/// let content = serenity_utils::escape_mass_mentions("hey @everyone and@HERE");
+///
+/// assert_eq!(content, "hey @\u{200B}everyone and@\u{200B}HERE");
+/// ```
+pub fn escape_mass_mentions(content: &str) -> String {
+    let mut escaped = String::with_capacity(content.len());
+
+    for (idx, c) in content.char_indices() {
+        escaped.push(c);
+
+        if c == '@' && is_mass_mention(&content[idx + 1..]) {
+            escaped.push('\u{200B}');
+        }
+    }
+
+    escaped
+}
+
+/// Determines whether the content following an `@` forms a mass mention.
+fn is_mass_mention(rest: &str) -> bool {
+    let starts_with = |name: &str| match rest.get(..name.len()) {
+        Some(prefix) => prefix.eq_ignore_ascii_case(name),
+        None => false,
+    };
+
+    starts_with("everyone") || starts_with("here")
+}
+
+/// Removes invisible characters from content, which can be used to spoof
+/// content or evade filters.
+///
+/// This removes zero-width characters (such as zero-width spaces and
+/// joiners), byte order marks, and direction marks, embeddings, overrides, and
+/// isolates, which can make text display differently from how it is stored.
+///
+/// Zero-width joiners between two emoji are kept, as they join emoji such as
+/// 👨‍👩‍👧 into one. Combining characters, variation selectors, and other
+/// parts of emoji are left alone too.
+///
+/// **Note**: This also removes the zero-width spaces used by other functions
+/// to break up mentions, such as [`escape_mass_mentions`], so content should be
+/// normalized before it is escaped, not after.
+///
+/// # Examples
+///
+/// Reveal the real extension of a file named with a right-to-left override:
+///
+/// ```rust
+/// use serenity_utils::normalize;
+///
+/// let name = "invoice\u{202E}fdp.exe";
+///
+/// assert_eq!(normalize(name), "invoicefdp.exe");
+/// assert_eq!(normalize("ad\u{200B}min"), "admin");
+/// assert_eq!(normalize("👨\u{200D}👩\u{200D}👧"), "👨\u{200D}👩\u{200D}👧");
+/// ```
+///
+/// [`escape_mass_mentions`]: fn.escape_mass_mentions.html
+pub fn normalize(content: &str) -> String {
+    let mut normalized = String::with_capacity(content.len());
+    normalize_into(&mut normalized, content);
+
+    normalized
+}
+
+/// Pushes content to a buffer with invisible characters removed, as with
+/// [`normalize`].
+///
+/// [`normalize`]: fn.normalize.html
+fn normalize_into(normalized: &mut String, content: &str) {
+    let mut chars = content.chars().peekable();
+    // Whether the last character kept is part of an emoji.
+    let mut in_emoji = false;
+
+    while let Some(c) = chars.next() {
+        if c == '\u{200D}' {
+            let joins_emoji = match chars.peek() {
+                Some(&next) => in_emoji && is_pictographic(next),
+                None => false,
+            };
+
+            if joins_emoji {
+                normalized.push(c);
+            }
+
+            continue;
+        }
+
+        if is_invisible(c) {
+            continue;
+        }
+
+        normalized.push(c);
+        in_emoji = is_pictographic(c) || (in_emoji && c == '\u{FE0F}');
+    }
+}
+
+/// Whether a character is invisible and can be removed by [`normalize`].
+///
+/// [`normalize`]: fn.normalize.html
+fn is_invisible(c: char) -> bool {
+    matches!(c as u32,
+        0x00AD
+        | 0x180E
+        | 0x200B..=0x200F
+        | 0x202A..=0x202E
+        | 0x2060..=0x2064
+        | 0x2066..=0x2069
+        | 0xFEFF)
+}
+
+/// Whether a character is an emoji that can be joined to another by a
+/// zero-width joiner, including skin tone modifiers.
+fn is_pictographic(c: char) -> bool {
+    matches!(c as u32,
+        0x00A9
+        | 0x00AE
+        | 0x203C
+        | 0x2049
+        | 0x2122
+        | 0x2139
+        | 0x2194..=0x21AA
+        | 0x2300..=0x23FF
+        | 0x24C2
+        | 0x25AA..=0x25FE
+        | 0x2600..=0x27BF
+        | 0x2934..=0x2935
+        | 0x2B05..=0x2B55
+        | 0x3030
+        | 0x303D
+        | 0x3297
+        | 0x3299
+        | 0x1F000..=0x1FAFF)
+}
+
+/// Returns the length of content as counted by Discord, which every length
+/// check in the crate goes through so that they agree with each other.
+///
+/// Discord counts unicode code points (scalar values), not bytes or
+/// grapheme clusters. A combining accent counts separately from the letter it
+/// modifies, and an emoji built from several code points, such as a skin tone
+/// or a ZWJ sequence, counts each code point, joiners included.
+fn discord_len(content: &str) -> usize { content.chars().count() }
+
 /// Retrieves the "code" part of an invite out of a URL.
 ///
 /// # Examples
@@ -143,6 +443,11 @@ pub fn parse_invite(code: &str) -> &str {
 
 /// Retreives an Id from a user mention.
 ///
+/// Both the regular form, `<@id>`, and the nickname form, `<@!id>`, are
+/// accepted. Surrounding whitespace is ignored, but the whole of the rest of
+/// the string must be the mention. A bare Id isn't a mention, and so isn't
+/// accepted.
+///
 /// If the mention is invalid, then `None` is returned.
 ///
 /// # Examples
@@ -156,7 +461,7 @@ pub fn parse_invite(code: &str) -> &str {
 /// assert_eq!(parse_username("<@114941315417899012>"), Some(114941315417899012));
 ///
 /// // nickname mention
-/// assert_eq!(parse_username("<@!114941315417899012>"), Some(114941315417899012));
+/// assert_eq!(parse_username(" <@!114941315417899012> "), Some(114941315417899012));
 /// ```
 ///
 /// Asserting that an invalid username or nickname mention returns `None`:
@@ -166,26 +471,42 @@ pub fn parse_invite(code: &str) -> &str {
 ///
 /// assert!(parse_username("<@1149413154aa17899012").is_none());
 /// assert!(parse_username("<@!11494131541789a90b1c2").is_none());
+/// assert!(parse_username("<@114941315417899012>!").is_none());
+/// assert!(parse_username("114941315417899012").is_none());
 /// ```
 ///
 /// [`User`]: ../model/struct.User.html
 pub fn parse_username(mention: &str) -> Option<u64> {
-    if mention.len() < 4 {
-        return None;
-    }
+    parse_tagged_id(mention, "<@!").or_else(|| parse_tagged_id(mention, "<@"))
+}
 
-    if mention.starts_with("<@!") {
-        let len = mention.len() - 1;
-        mention[3..len].parse::<u64>().ok()
-    } else if mention.starts_with("<@") {
-        let len = mention.len() - 1;
-        mention[2..len].parse::<u64>().ok()
+/// Parses the Id out of a mention in the form of `{prefix}id>`, ignoring
+/// surrounding whitespace.
+fn parse_tagged_id(mention: &str, prefix: &str) -> Option<u64> {
+    let mention = mention.trim();
+
+    if mention.starts_with(prefix) && mention.ends_with('>') {
+        parse_id(&mention[prefix.len()..mention.len() - 1])
     } else {
         None
     }
 }
 
-/// Retreives an Id from a role mention.
+/// Parses an Id, which must be made up only of digits without any leading
+/// zeros, and fit in a `u64`.
+fn parse_id(id: &str) -> Option<u64> {
+    if id.starts_with('0') || !id.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    id.parse().ok()
+}
+
+/// Retreives an Id from a role mention, in the form of `<@&id>`.
+///
+/// Like [`parse_channel`], the whole string must be the mention, apart from
+/// surrounding whitespace. A user mention isn't a role mention, and so isn't
+/// accepted.
 ///
 /// If the mention is invalid, then `None` is returned.
 ///
@@ -205,23 +526,18 @@ pub fn parse_username(mention: &str) -> Option<u64> {
 /// use serenity_utils::parse_role;
 ///
 /// assert!(parse_role("<@&136107769680887808").is_none());
+/// assert!(parse_role("<@136107769680887808>").is_none());
 /// ```
 ///
 /// [`Role`]: ../model/struct.Role.html
-pub fn parse_role(mention: &str) -> Option<u64> {
-    if mention.len() < 4 {
-        return None;
-    }
-
-    if mention.starts_with("<@&") && mention.ends_with('>') {
-        let len = mention.len() - 1;
-        mention[3..len].parse::<u64>().ok()
-    } else {
-        None
-    }
-}
+/// [`parse_channel`]: fn.parse_channel.html
+pub fn parse_role(mention: &str) -> Option<u64> { parse_tagged_id(mention, "<@&") }
 
-/// Retreives an Id from a channel mention.
+/// Retreives an Id from a channel mention, in the form of `<#id>`.
+///
+/// Surrounding whitespace is ignored, but the whole of the rest of the string
+/// must be the mention; a mention within other text isn't searched for. The
+/// Id must fit in a `u64`, and can't have leading zeros.
 ///
 /// If the channel mention is invalid, then `None` is returned.
 ///
@@ -242,25 +558,276 @@ pub fn parse_role(mention: &str) -> Option<u64> {
 ///
 /// assert!(parse_channel("<#!81384788765712384>").is_none());
 /// assert!(parse_channel("<#81384788765712384").is_none());
+/// assert!(parse_channel("see <#81384788765712384>").is_none());
 /// ```
 ///
 /// [`Channel`]: ../model/enum.Channel.html
-pub fn parse_channel(mention: &str) -> Option<u64> {
-    if mention.len() < 4 {
+pub fn parse_channel(mention: &str) -> Option<u64> { parse_tagged_id(mention, "<#") }
+
+/// Retrieves the guild navigation from a navigation mention, such as
+/// `<id:customize>`.
+///
+/// Surrounding whitespace is ignored, but the whole of the rest of the string
+/// must be the mention.
+///
+/// If the mention is invalid, then `None` is returned.
+///
+/// # Examples
+///
+/// Retrieving the navigation from a valid mention:
+///
+/// ```rust
+/// use serenity_utils::{GuildNavigation, parse_navigation};
+///
+/// assert_eq!(parse_navigation("<id:browse>"), Some(GuildNavigation::Browse));
+/// assert_eq!(parse_navigation(" <id:browse> "), Some(GuildNavigation::Browse));
+/// ```
+///
+/// Asserting that an unknown navigation returns `None`:
+///
+/// ```rust
+/// use serenity_utils::parse_navigation;
+///
+/// assert!(parse_navigation("<id:settings>").is_none());
+/// ```
+pub fn parse_navigation(mention: &str) -> Option<GuildNavigation> {
+    let mention = mention.trim();
+
+    if mention.starts_with("<id:") && mention.ends_with('>') && mention.len() > 5 {
+        GuildNavigation::from_name(&mention[4..mention.len() - 1])
+    } else {
+        None
+    }
+}
+
+/// Parses any kind of mention, such as of a user, role, or channel, or a
+/// custom emoji or timestamp tag.
+///
+/// The kind of mention is decided by its prefix, in the same way as Discord.
+/// Each kind is parsed as strictly as by its own parser, such as
+/// [`parse_username`], and so the whole string must be the mention, apart
+/// from surrounding whitespace.
+///
+/// If the mention is invalid, then `None` is returned.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity_utils::{parse_mention, Mention, TimestampStyle};
+///
+/// assert_eq!(parse_mention("<@!114941315417899012>"), Some(Mention::User(114941315417899012)));
+/// assert_eq!(parse_mention("<@&136107769680887808>"), Some(Mention::Role(136107769680887808)));
+/// assert_eq!(parse_mention("<t:1700000000:R>"), Some(Mention::Timestamp {
+///     style: Some(TimestampStyle::Relative),
+///     timestamp: 1700000000,
+/// }));
+///
+/// assert!(parse_mention("@everyone").is_none());
+/// ```
+///
+/// [`parse_username`]: fn.parse_username.html
+pub fn parse_mention(mention: &str) -> Option<Mention> {
+    let mention = mention.trim();
+
+    if mention.starts_with("<@&") {
+        parse_role(mention).map(Mention::Role)
+    } else if mention.starts_with("<@") {
+        parse_username(mention).map(Mention::User)
+    } else if mention.starts_with("<#") {
+        parse_channel(mention).map(Mention::Channel)
+    } else if mention.starts_with("<:") || mention.starts_with("<a:") {
+        parse_emoji(mention).map(Mention::Emoji)
+    } else if mention.starts_with("<t:") {
+        parse_timestamp_parts(mention).map(|(timestamp, style)| Mention::Timestamp {
+            style,
+            timestamp,
+        })
+    } else if mention.starts_with("</") {
+        parse_command(mention)
+    } else if mention.starts_with("<id:") {
+        parse_navigation(mention).map(Mention::Navigation)
+    } else {
+        None
+    }
+}
+
+/// Retrieves the time and style out of a timestamp tag, in the form of
+/// `<t:timestamp>` or `<t:timestamp:style>`.
+///
+/// The timestamp is in seconds since the Unix epoch, and may be negative. A
+/// tag without a style is displayed by Discord in the default
+/// [`TimestampStyle`], and so is returned with it. Like [`parse_channel`],
+/// the whole string must be the tag, apart from surrounding whitespace.
+///
+/// If the tag is invalid, then `None` is returned.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity_utils::{parse_timestamp_tag, TimestampStyle};
+///
+/// let relative = parse_timestamp_tag("<t:1700000000:R>");
+/// assert_eq!(relative, Some((1700000000, TimestampStyle::Relative)));
+///
+/// let unstyled = parse_timestamp_tag("<t:1700000000>");
+/// assert_eq!(unstyled, Some((1700000000, TimestampStyle::ShortDateTime)));
+///
+/// assert!(parse_timestamp_tag("<t:1700000000R>").is_none());
+/// assert!(parse_timestamp_tag("<t:now:R>").is_none());
+/// assert!(parse_timestamp_tag("<t:1700000000:R:x>").is_none());
+/// ```
+///
+/// [`TimestampStyle`]: enum.TimestampStyle.html
+/// [`parse_channel`]: fn.parse_channel.html
+pub fn parse_timestamp_tag(tag: &str) -> Option<(i64, TimestampStyle)> {
+    parse_timestamp_parts(tag).map(|(timestamp, style)| (timestamp, style.unwrap_or_default()))
+}
+
+/// Parses a timestamp tag, keeping whether it had a style.
+fn parse_timestamp_parts(tag: &str) -> Option<(i64, Option<TimestampStyle>)> {
+    let tag = tag.trim();
+
+    if !tag.starts_with("<t:") || !tag.ends_with('>') {
         return None;
     }
 
-    if mention.starts_with("<#") && mention.ends_with('>') {
-        let len = mention.len() - 1;
-        mention[2..len].parse::<u64>().ok()
+    let inner = &tag[3..tag.len() - 1];
+    let (timestamp, style) = match inner.find(':') {
+        Some(idx) => {
+            let mut letters = inner[idx + 1..].chars();
+
+            match (letters.next(), letters.next()) {
+                (Some(letter), None) => (&inner[..idx], Some(TimestampStyle::from_letter(letter)?)),
+                _ => return None,
+            }
+        },
+        None => (inner, None),
+    };
+
+    let digits = timestamp.strip_prefix('-').unwrap_or(timestamp);
+
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    Some((timestamp.parse().ok()?, style))
+}
+
+/// Parses a slash command mention, in the form of `</name:id>`.
+fn parse_command(mention: &str) -> Option<Mention> {
+    if !mention.ends_with('>') {
+        return None;
+    }
+
+    let inner = &mention[2..mention.len() - 1];
+    let separator = inner.rfind(':')?;
+    let name = &inner[..separator];
+    let id = parse_id(&inner[separator + 1..])?;
+
+    // The name is made up of the command, and an optional subcommand group
+    // and subcommand, separated by single spaces.
+    let words = name.split(' ').collect::<Vec<_>>();
+    let valid = words.len() <= 3 && words.iter().all(|word| {
+        !word.is_empty() && !word.contains(|c: char| c.is_whitespace() || c == ':' || c == '>')
+    });
+
+    if valid {
+        Some(Mention::Command {
+            id,
+            name: name.to_string(),
+        })
     } else {
         None
     }
 }
 
-/// Retreives the name and Id from an emoji mention.
+/// Retrieves the guild, channel, and message Ids from a link to a message,
+/// such as
+/// `https://discord.com/channels/381880193251409931/381880193700069377/806164913558781963`.
+///
+/// Links on `discord.com` and the older `discordapp.com`, including their
+/// `ptb.` and `canary.` subdomains, are accepted. Messages in direct messages
+/// have no guild, so their links use `@me` instead of its Id; for these, the
+/// guild Id is `None`. A query string or fragment after the link is ignored.
+///
+/// If the link is invalid, or is a link to a channel rather than a message,
+/// then `None` is returned.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity_utils::parse_message_link;
+///
+/// let link = "https://discord.com/channels/381880193251409931/381880193700069377/806164913558781963";
+///
+/// assert_eq!(
+///     parse_message_link(link),
+///     Some((Some(381880193251409931), 381880193700069377, 806164913558781963)),
+/// );
+///
+/// let link = "https://canary.discord.com/channels/@me/381880193700069377/806164913558781963";
+///
+/// assert_eq!(parse_message_link(link), Some((None, 381880193700069377, 806164913558781963)));
+/// ```
+///
+/// Asserting that a link to a channel returns `None`:
+///
+/// ```rust
+/// use serenity_utils::parse_message_link;
+///
+/// let link = "https://discord.com/channels/381880193251409931/381880193700069377";
+///
+/// assert!(parse_message_link(link).is_none());
+/// ```
+pub fn parse_message_link(link: &str) -> Option<(Option<u64>, u64, u64)> {
+    const HOSTS: &[&str] = &[
+        "discord.com",
+        "ptb.discord.com",
+        "canary.discord.com",
+        "discordapp.com",
+        "ptb.discordapp.com",
+        "canary.discordapp.com",
+    ];
+
+    let link = link.trim();
+    let link = link.strip_prefix("https://").or_else(|| link.strip_prefix("http://"))?;
+
+    let end = link.find(&['?', '#'][..]).unwrap_or(link.len());
+    let mut parts = link[..end].trim_end_matches('/').split('/');
+
+    match parts.next() {
+        Some(host) if HOSTS.contains(&host) => {},
+        _ => return None,
+    }
+
+    if parts.next() != Some("channels") {
+        return None;
+    }
+
+    let guild_id = match parts.next()? {
+        "@me" => None,
+        id => Some(parse_id(id)?),
+    };
+    let channel_id = parse_id(parts.next()?)?;
+    let message_id = parse_id(parts.next()?)?;
+
+    if parts.next().is_some() {
+        return None;
+    }
+
+    Some((guild_id, channel_id, message_id))
+}
+
+/// Retreives the name and Id from an emoji mention, such as
+/// `<:blobcat:271629243716468736>`, or `<a:blobcat:271629243716468736>` if
+/// the emoji is animated.
+///
+/// The name can contain any characters, including non-ASCII ones, other than
+/// whitespace, colons, and angle brackets, and must be no longer than 32
+/// characters. Surrounding whitespace is ignored.
 ///
-/// If the emoji usage is invalid, then `None` is returned.
+/// If the emoji usage is invalid, then `None` is returned. Unicode emoji
+/// don't have a mention, and so also return `None`.
 ///
 /// # Examples
 ///
@@ -269,9 +836,11 @@ pub fn parse_channel(mention: &str) -> Option<u64> {
 /// ```rust
 /// use serenity_utils::parse_emoji;
 ///
-/// let expected = Some(("smugAnimeFace", 302516740095606785u64));
+/// let emoji = parse_emoji("<:smugAnimeFace:302516740095606785>").unwrap();
 ///
-/// assert_eq!(parse_emoji("<:smugAnimeFace:302516740095606785>"), expected);
+/// assert!(!emoji.animated);
+/// assert_eq!(emoji.name, "smugAnimeFace");
+/// assert_eq!(emoji.id, 302516740095606785);
 /// ```
 ///
 /// Asserting that an invalid emoji usage returns `None`:
@@ -280,35 +849,53 @@ pub fn parse_channel(mention: &str) -> Option<u64> {
 /// use serenity_utils::parse_emoji;
 ///
 /// assert!(parse_emoji("<:smugAnimeFace:302516740095606785").is_none());
+/// assert!(parse_emoji("👍").is_none());
 /// ```
 ///
 /// [`Emoji`]: ../model/struct.Emoji.html
-pub fn parse_emoji(mention: &str) -> Option<(&str, u64)> {
-    let len = mention.len();
+pub fn parse_emoji(mention: &str) -> Option<EmojiIdentifier> {
+    let mention = mention.trim();
 
-    if len < 6 || len > 56 {
+    let (animated, rest) = if let Some(rest) = mention.strip_prefix("<a:") {
+        (true, rest)
+    } else if let Some(rest) = mention.strip_prefix("<:") {
+        (false, rest)
+    } else {
         return None;
-    }
+    };
 
-    if mention.starts_with("<:") && mention.ends_with('>') {
-        let (name_to, id) = match mention[2..].find(':') {
-            Some(pos) => (pos + 2, &mention[pos + 3..len - 1]),
-            None => return None,
-        };
+    let rest = rest.strip_suffix('>')?;
+    let separator = rest.find(':')?;
+    let name = &rest[..separator];
+    let id = parse_id(&rest[separator + 1..])?;
 
-        match id.parse::<u64>() {
-            Ok(x) => Some((&mention[2..name_to], x)),
-            Err(_) => None,
-        }
-    } else {
-        None
+    let invalid = |c: char| c.is_whitespace() || c == ':' || c == '<' || c == '>';
+    let length = name.chars().count();
+
+    if length == 0 || length > 32 || name.contains(invalid) {
+        return None;
     }
+
+    Some(EmojiIdentifier {
+        animated,
+        id,
+        name: name.to_string(),
+    })
 }
 
-/// Reads an image from a path and encodes it into base64.
+/// Reads an image from a path and encodes it into a base64 data URI.
 ///
 /// This can be used for methods like [`EditProfile::avatar`].
 ///
+/// The format is detected from the file's contents, falling back to its
+/// extension if the contents aren't recognised. PNG, JPEG, GIF, and WebP
+/// images are supported.
+///
+/// # Errors
+///
+/// Returns [`Error::Io`] if the file can't be read, and
+/// [`Error::UnsupportedImage`] if it isn't in a supported format.
+///
 /// # Examples
 ///
 /// Reads an image located at `./cat.png` into a base64-encoded string:
@@ -316,24 +903,56 @@ pub fn parse_emoji(mention: &str) -> Option<(&str, u64)> {
 /// ```rust,no_run
 /// let image = serenity_utils::read_image("./cat.png")
 ///     .expect("Failed to read image");
+///
+/// assert!(image.starts_with("data:image/png;base64,"));
 /// ```
 ///
 /// [`EditProfile::avatar`]: ../builder/struct.EditProfile.html#method.avatar
+/// [`Error::Io`]: enum.Error.html#variant.Io
+/// [`Error::UnsupportedImage`]: enum.Error.html#variant.UnsupportedImage
 pub fn read_image<P: AsRef<Path>>(path: P) -> Result<String> {
     let path = path.as_ref();
 
     let mut v = Vec::default();
     let mut f = File::open(path)?;
-    let _ = f.read_to_end(&mut v);
+    f.read_to_end(&mut v)?;
 
-    let b64 = base64::encode(&v);
-    let ext = if path.extension() == Some(OsStr::new("png")) {
-        "png"
-    } else {
-        "jpg"
+    let format = match image_format(&v).or_else(|| image_extension(path)) {
+        Some(format) => format,
+        None => return Err(Error::UnsupportedImage),
     };
 
-    Ok(format!("data:image/{};base64,{}", ext, b64))
+    Ok(format!("data:image/{};base64,{}", format, base64::encode(&v)))
+}
+
+/// Detects the format of an image from its magic bytes, returning its MIME
+/// subtype.
+fn image_format(bytes: &[u8]) -> Option<&'static str> {
+    if bytes.starts_with(b"\x89PNG\r\n\x1A\n") {
+        Some("png")
+    } else if bytes.starts_with(b"\xFF\xD8\xFF") {
+        Some("jpeg")
+    } else if bytes.starts_with(b"GIF87a") || bytes.starts_with(b"GIF89a") {
+        Some("gif")
+    } else if bytes.len() >= 12 && &bytes[..4] == b"RIFF" && &bytes[8..12] == b"WEBP" {
+        Some("webp")
+    } else {
+        None
+    }
+}
+
+/// Detects the format of an image from its path's extension, returning its
+/// MIME subtype.
+fn image_extension(path: &Path) -> Option<&'static str> {
+    let extension = path.extension().and_then(OsStr::to_str)?.to_lowercase();
+
+    match &*extension {
+        "png" => Some("png"),
+        "jpg" | "jpeg" => Some("jpeg"),
+        "gif" => Some("gif"),
+        "webp" => Some("webp"),
+        _ => None,
+    }
 }
 
 /// Turns a string into a vector of string arguments, splitting by spaces, but
@@ -414,6 +1033,10 @@ pub fn parse_quotes(s: &str) -> Vec<String> {
 /// Calculates the Id of the shard responsible for a guild, given its Id and
 /// total number of shards used.
 ///
+/// # Panics
+///
+/// Panics if `shard_count` is `0`.
+///
 /// # Examples
 ///
 /// Retrieve the Id of the shard for a guild with Id `81384788765712384`, using
@@ -425,7 +1048,121 @@ pub fn parse_quotes(s: &str) -> Vec<String> {
 /// assert_eq!(serenity_utils::shard_id(81384788765712384, 17), 7);
 /// ```
 #[inline]
-pub fn shard_id(guild_id: u64, shard_count: u64) -> u64 { (guild_id >> 22) % shard_count }
+pub fn shard_id(guild_id: u64, shard_count: u64) -> u64 {
+    assert!(shard_count != 0, "shard_count must be at least 1");
+
+    (guild_id >> 22) % shard_count
+}
+
+/// Groups guild Ids by the shard responsible for each, as calculated by
+/// [`shard_id`].
+///
+/// The returned vector has one entry per shard, indexed by shard Id, so shards
+/// responsible for none of the guilds have an empty entry. Guild Ids keep
+/// their relative order within each shard.
+///
+/// # Panics
+///
+/// Panics if `shard_count` is `0`.
+///
+/// # Examples
+///
+/// Find which of three guilds need to be handled by shard `3`, out of 5:
+///
+/// ```rust
+/// use serenity_utils::group_by_shard;
+///
+/// let guilds = [81384788765712384, 41771983423143937, 172018499005317120];
+/// let shards = group_by_shard(guilds.iter().cloned(), 5);
+///
+/// assert_eq!(shards.len(), 5);
+/// assert_eq!(shards[3], [81384788765712384]);
+/// assert!(shards[0].is_empty());
+/// ```
+///
+/// [`shard_id`]: fn.shard_id.html
+pub fn group_by_shard<I>(guild_ids: I, shard_count: u64) -> Vec<Vec<u64>>
+    where I: IntoIterator<Item = u64> {
+    assert!(shard_count != 0, "shard_count must be at least 1");
+
+    let mut shards = vec![Vec::new(); shard_count as usize];
+
+    for guild_id in guild_ids {
+        shards[shard_id(guild_id, shard_count) as usize].push(guild_id);
+    }
+
+    shards
+}
+
+/// Checks that a bot token has the structure of a genuine one.
+///
+/// Tokens have three dot-separated parts in the URL-safe base64 alphabet: the
+/// bot's user Id, a timestamp, and an HMAC. A leading `"Bot "`, as used in the
+/// `Authorization` header, is accepted and ignored.
+///
+/// This only checks the shape of the token, and so can't tell whether Discord
+/// will accept it. It is useful for catching tokens that were pasted wrongly
+/// at startup, rather than at the first failed request.
+///
+/// # Errors
+///
+/// Returns a [`TokenError`] describing the first problem found. The error
+/// never includes the token, and so is safe to log.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity_utils::{validate_token, TokenError, TokenPart};
+///
+/// let token = "MTIzNDU2Nzg5MDEyMzQ1Njc4.GaBcDe.abcdefghijklmnopqrstuvwxyz0123456789AB";
+///
+/// assert!(validate_token(token).is_ok());
+/// assert!(validate_token(&format!("Bot {}", token)).is_ok());
+///
+/// assert_eq!(validate_token(&format!("{}\n", token)), Err(TokenError::Whitespace));
+/// assert_eq!(validate_token("MTIzNDU2Nzg5MDEyMzQ1Njc4"), Err(TokenError::PartCount(1)));
+/// assert_eq!(
+///     validate_token("MTIzNDU2Nzg5MDEyMzQ1Njc4.GaBcDe.short"),
+///     Err(TokenError::InvalidLength(TokenPart::Hmac)),
+/// );
+/// ```
+///
+/// [`TokenError`]: enum.TokenError.html
+pub fn validate_token(token: &str) -> StdResult<(), TokenError> {
+    let token = token.strip_prefix("Bot ").unwrap_or(token);
+
+    if token.is_empty() {
+        return Err(TokenError::Empty);
+    }
+
+    if token.chars().any(char::is_whitespace) {
+        return Err(TokenError::Whitespace);
+    }
+
+    let parts = token.split('.').collect::<Vec<_>>();
+
+    if parts.len() != 3 {
+        return Err(TokenError::PartCount(parts.len()));
+    }
+
+    let checks = [
+        (TokenPart::UserId, parts[0], 16, 32),
+        (TokenPart::Timestamp, parts[1], 4, 8),
+        (TokenPart::Hmac, parts[2], 20, 64),
+    ];
+
+    for &(part, content, min, max) in &checks {
+        if !content.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+            return Err(TokenError::InvalidCharacter(part));
+        }
+
+        if content.len() < min || content.len() > max {
+            return Err(TokenError::InvalidLength(part));
+        }
+    }
+
+    Ok(())
+}
 
 /// A function for doing automatic `read`ing (and the releasing of the guard as well)
 /// This is particularly useful if you just want to use the cache for this one time,
@@ -0,0 +1,108 @@
+/// Builds a [`MessageBuilder`] from a comma-separated list of items, as a
+/// shorthand for a chain of builder calls.
+///
+/// Each item is one of:
+///
+/// - `@id`, a mention of the user with the Id, as by [`user`];
+/// - `#id`, a mention of the channel with the Id, as by [`channel`];
+/// - `&id`, a mention of the role with the Id, as by [`role`];
+/// - `bold(text)`, `italic(text)`, `underline(text)`, `strike(text)`,
+///   `spoiler(text)`, `mono(text)`, or `safe(text)`, pushing the text as by
+///   the builder method of the same name prefixed with `push_`. The text can
+///   be anything implementing `AsRef<str>`;
+/// - any other expression, pushed as by [`push`].
+///
+/// As `&` starts a role mention, a reference to push as text must be wrapped
+/// in parentheses, such as `(&name)`.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate serenity_utils;
+/// #
+/// # fn main() {
+/// let (user, channel) = (114941315417899012, 81384788765712384);
+///
+/// let content = msg!("Welcome ", @user, " to ", #channel, "! ", bold("Read the rules."));
+///
+/// let expected = "Welcome <@114941315417899012> to <#81384788765712384>! \
+///                 **Read the rules.**";
+///
+/// assert_eq!(content.build(), expected);
+/// # }
+/// ```
+///
+/// Items must be separated by commas:
+///
+/// ```rust,compile_fail
+/// # #[macro_use] extern crate serenity_utils;
+/// #
+/// # fn main() {
+/// let content = msg!("Welcome " @114941315417899012).build();
+/// # }
+/// ```
+///
+/// Styles take exactly one argument:
+///
+/// ```rust,compile_fail
+/// # #[macro_use] extern crate serenity_utils;
+/// #
+/// # fn main() {
+/// let content = msg!(bold("a", "b")).build();
+/// # }
+/// ```
+///
+/// [`MessageBuilder`]: struct.MessageBuilder.html
+/// [`channel`]: struct.MessageBuilder.html#method.channel
+/// [`push`]: struct.MessageBuilder.html#method.push
+/// [`role`]: struct.MessageBuilder.html#method.role
+/// [`user`]: struct.MessageBuilder.html#method.user
+#[macro_export]
+macro_rules! msg {
+    (@__str $text:expr) => { ::std::convert::AsRef::<str>::as_ref(&$text) };
+    (@__msg $builder:expr;) => { $builder };
+    (@__msg $builder:expr; ,) => { $builder };
+    (@__msg $builder:expr; @ $id:expr, $($rest:tt)*) => {
+        msg!(@__msg $builder.user($id); $($rest)*)
+    };
+    (@__msg $builder:expr; # $id:expr, $($rest:tt)*) => {
+        msg!(@__msg $builder.channel($id); $($rest)*)
+    };
+    (@__msg $builder:expr; & $id:expr, $($rest:tt)*) => {
+        msg!(@__msg $builder.role($id); $($rest)*)
+    };
+    (@__msg $builder:expr; bold($text:expr), $($rest:tt)*) => {
+        msg!(@__msg $builder.push_bold(msg!(@__str $text)); $($rest)*)
+    };
+    (@__msg $builder:expr; italic($text:expr), $($rest:tt)*) => {
+        msg!(@__msg $builder.push_italic(msg!(@__str $text)); $($rest)*)
+    };
+    (@__msg $builder:expr; underline($text:expr), $($rest:tt)*) => {
+        msg!(@__msg $builder.push_underline(msg!(@__str $text)); $($rest)*)
+    };
+    (@__msg $builder:expr; strike($text:expr), $($rest:tt)*) => {
+        msg!(@__msg $builder.push_strike(msg!(@__str $text)); $($rest)*)
+    };
+    (@__msg $builder:expr; spoiler($text:expr), $($rest:tt)*) => {
+        msg!(@__msg $builder.push_spoiler(msg!(@__str $text)); $($rest)*)
+    };
+    (@__msg $builder:expr; mono($text:expr), $($rest:tt)*) => {
+        msg!(@__msg $builder.push_mono(msg!(@__str $text)); $($rest)*)
+    };
+    (@__msg $builder:expr; safe($text:expr), $($rest:tt)*) => {
+        msg!(@__msg $builder.push_safe(msg!(@__str $text)); $($rest)*)
+    };
+    (@__msg $builder:expr; $content:expr, $($rest:tt)*) => {
+        msg!(@__msg $builder.push($content); $($rest)*)
+    };
+    (@__msg $builder:expr; $($rest:tt)*) => {
+        compile_error!(concat!(
+            "expected a comma-separated item, found `",
+            stringify!($($rest)*),
+            "`",
+        ))
+    };
+    ($($item:tt)*) => {
+        msg!(@__msg $crate::MessageBuilder::new(); $($item)*,)
+    };
+}
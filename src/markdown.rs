@@ -0,0 +1,384 @@
+/// Removes Discord's formatting from content, leaving the text that is shown.
+///
+/// Bold, italic, underline, strikethrough, and spoiler markers are removed, as
+/// are the backticks around code spans and blocks, the language of a code
+/// block, block quote prefixes (`> ` and `>>> `), and header and subtext
+/// markers (`# `, `## `, `### `, and `-# `) at the start of a line. Nothing
+/// within code is treated as formatting, and escaped characters, such as
+/// `\*`, are left as the character itself.
+///
+/// Markers which aren't closed are left as they are, as Discord shows them as
+/// written. Masked links are replaced by their text; use
+/// [`remove_markdown_with_urls`] to keep their URLs as well.
+///
+/// Mentions and other tags are left as they are.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity_utils::remove_markdown;
+///
+/// let content = "> **Note:** run `cargo **build**` \\*first\\*, see [the guide](https://a.b)";
+///
+/// assert_eq!(remove_markdown(content), "Note: run cargo **build** *first*, see the guide");
+/// ```
+///
+/// [`remove_markdown_with_urls`]: fn.remove_markdown_with_urls.html
+pub fn remove_markdown(content: &str) -> String { strip(content, false) }
+
+/// Removes Discord's formatting from content, as by [`remove_markdown`], but
+/// writes masked links as their text followed by their URL in parentheses.
+///
+/// A link whose text is its URL is written as just the URL.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity_utils::remove_markdown_with_urls;
+///
+/// let content = "See [*the guide*](https://a.b) or [https://c.d](https://c.d)";
+///
+/// assert_eq!(
+///     remove_markdown_with_urls(content),
+///     "See the guide (https://a.b) or https://c.d",
+/// );
+/// ```
+///
+/// [`remove_markdown`]: fn.remove_markdown.html
+pub fn remove_markdown_with_urls(content: &str) -> String { strip(content, true) }
+
+/// A piece of content with its formatting removed, or a formatting marker
+/// which is only removed if it turns out to be closed.
+enum Piece {
+    Text(String),
+    Marker(&'static str, bool),
+}
+
+fn strip(content: &str, urls: bool) -> String {
+    let mut pieces = vec![];
+    // The indices within the pieces of markers which haven't been closed yet.
+    let mut open: Vec<usize> = vec![];
+    let mut text = String::new();
+    let mut rest = content;
+    let mut prev = None;
+    let mut line_start = true;
+
+    while let Some(c) = rest.chars().next() {
+        if line_start {
+            line_start = false;
+            rest = strip_line_prefixes(rest);
+
+            continue;
+        }
+
+        if c == '`' {
+            if let Some((code, len)) = code(rest) {
+                text.push_str(code);
+                rest = &rest[len..];
+                prev = Some('`');
+
+                continue;
+            }
+
+            // Backticks which don't open anything are shown as written.
+            let run = rest.len() - rest.trim_start_matches('`').len();
+            text.push_str(&rest[..run]);
+            rest = &rest[run..];
+            prev = Some('`');
+
+            continue;
+        }
+
+        if c == '\\' {
+            match rest[1..].chars().next() {
+                Some(escaped) if escaped.is_ascii_punctuation() => {
+                    text.push(escaped);
+                    rest = &rest[1 + escaped.len_utf8()..];
+                },
+                _ => {
+                    text.push(c);
+                    rest = &rest[1..];
+                },
+            }
+
+            prev = None;
+
+            continue;
+        }
+
+        if c == '[' {
+            if let Some((label, url, len)) = masked_link(rest) {
+                let label = strip(label, urls);
+
+                if !urls || label == url {
+                    text.push_str(if label.is_empty() { url } else { &label });
+                } else {
+                    text.push_str(&label);
+                    text.push_str(" (");
+                    text.push_str(url);
+                    text.push(')');
+                }
+
+                rest = &rest[len..];
+                prev = Some(')');
+
+                continue;
+            }
+        }
+
+        let innermost = open.last().and_then(|&idx| match pieces[idx] {
+            Piece::Marker(marker, _) => Some(marker),
+            Piece::Text(_) => None,
+        });
+
+        if let Some(marker) = marker(rest, prev, innermost) {
+            let (closes, opens) = flanking(marker, prev, rest[marker.len()..].chars().next());
+
+            // Markers with nothing between them don't close each other.
+            let closed = if closes {
+                open.iter()
+                    .rposition(|&idx| match pieces[idx] {
+                        Piece::Marker(open, _) => open == marker,
+                        Piece::Text(_) => false,
+                    })
+                    .filter(|&position| open[position] + 1 < pieces.len() || !text.is_empty())
+            } else {
+                None
+            };
+
+            if closed.is_some() || opens {
+                if !text.is_empty() {
+                    pieces.push(Piece::Text(text));
+                    text = String::new();
+                }
+
+                match closed {
+                    Some(position) => {
+                        if let Piece::Marker(_, ref mut matched) = pieces[open[position]] {
+                            *matched = true;
+                        }
+
+                        open.truncate(position);
+                        pieces.push(Piece::Marker(marker, true));
+                    },
+                    None => {
+                        open.push(pieces.len());
+                        pieces.push(Piece::Marker(marker, false));
+                    },
+                }
+
+                rest = &rest[marker.len()..];
+                prev = Some(c);
+
+                continue;
+            }
+        }
+
+        text.push(c);
+        rest = &rest[c.len_utf8()..];
+        prev = Some(c);
+        line_start = c == '\n';
+    }
+
+    pieces.push(Piece::Text(text));
+
+    let mut stripped = String::with_capacity(content.len());
+
+    for piece in &pieces {
+        match *piece {
+            Piece::Text(ref text) => stripped.push_str(text),
+            Piece::Marker(marker, false) => stripped.push_str(marker),
+            Piece::Marker(_, true) => {},
+        }
+    }
+
+    stripped
+}
+
+/// Removes a block quote prefix and then a header or subtext marker from the
+/// start of a line.
+fn strip_line_prefixes(line: &str) -> &str {
+    let mut line = line;
+
+    for quote in &[">>> ", "> "] {
+        if let Some(rest) = line.strip_prefix(quote) {
+            line = rest;
+
+            break;
+        }
+    }
+
+    for header in &["# ", "## ", "### ", "-# "] {
+        if let Some(rest) = line.strip_prefix(header) {
+            return rest.trim_start_matches(' ');
+        }
+    }
+
+    line
+}
+
+/// Parses a code span or block at the start of the content, returning the
+/// code within it and the length of the whole span or block.
+///
+/// The language of a code block is left out, along with the newlines around
+/// its code.
+fn code(content: &str) -> Option<(&str, usize)> {
+    let (fence, len) = code_at(content)?;
+    let mut code = &content[fence..len - fence];
+
+    if fence == 3 {
+        if let Some(newline) = code.find('\n') {
+            if !code[..newline].trim().contains(char::is_whitespace) {
+                code = &code[newline + 1..];
+            }
+        }
+
+        return Some((code.strip_suffix('\n').unwrap_or(code), len));
+    }
+
+    // Double backticks allow a backtick at either end of the code, which
+    // needs a space to separate it from the fence.
+    if fence == 2 && code.len() > 2 && code.starts_with(' ') && code.ends_with(' ') {
+        code = &code[1..code.len() - 1];
+    }
+
+    Some((code, len))
+}
+
+/// Finds the code span or block at the start of the content, returning the
+/// number of backticks fencing it, either `1`, `2`, or `3`, and its length,
+/// including the backticks.
+///
+/// A code block is opened by a run of three or more backticks and closed by
+/// the next three, while a code span is only closed by a run of exactly as
+/// many backticks as opened it, and must have something within it. This is
+/// the one place these rules are written, so that everything which needs to
+/// tell code apart from the rest of a message agrees on where it is.
+pub fn code_at(content: &str) -> Option<(usize, usize)> {
+    let run = content.len() - content.trim_start_matches('`').len();
+
+    if run >= 3 {
+        let end = 3 + content[3..].find("```")?;
+
+        return Some((3, end + 3));
+    }
+
+    if run == 0 {
+        return None;
+    }
+
+    match closing_run(&content[run..], run)? {
+        0 => None,
+        len => Some((run, run + len + run)),
+    }
+}
+
+/// Finds the start of the first run of exactly the given number of backticks,
+/// which is the only run that closes a code span.
+pub fn closing_run(content: &str, run: usize) -> Option<usize> {
+    let mut idx = 0;
+
+    while let Some(start) = content[idx..].find('`') {
+        let start = idx + start;
+        let len = content[start..].len() - content[start..].trim_start_matches('`').len();
+
+        if len == run {
+            return Some(start);
+        }
+
+        idx = start + len;
+    }
+
+    None
+}
+
+/// Parses a masked link at the start of the content, returning its text, its
+/// URL, and the length of the whole link.
+fn masked_link(content: &str) -> Option<(&str, &str, usize)> {
+    let label_end = content.find("](")?;
+    let label = &content[1..label_end];
+
+    if label.contains('\n') || label.contains('[') {
+        return None;
+    }
+
+    let after = &content[label_end + 2..];
+    let url_end = after.find(')')?;
+    let mut url = after[..url_end].trim();
+
+    if url.len() >= 2 && url.starts_with('<') && url.ends_with('>') {
+        url = &url[1..url.len() - 1];
+    }
+
+    let web = url.starts_with("https://") || url.starts_with("http://");
+
+    if !web || url.contains(char::is_whitespace) {
+        return None;
+    }
+
+    Some((label, url, label_end + 2 + url_end + 1))
+}
+
+/// Finds the formatting marker at the start of the content, if any, given
+/// the character before it and the innermost marker still open.
+///
+/// Where a marker could be either a single or a double `*` or `_`, a single
+/// one is preferred if it closes the innermost open marker, so that `***a***`
+/// is closed as `*` and then `**`.
+pub fn marker(content: &str, prev: Option<char>, innermost: Option<&str>) -> Option<&'static str> {
+    const MARKERS: &[(&str, Option<&str>)] = &[
+        ("**", Some("*")),
+        ("__", Some("_")),
+        ("~~", None),
+        ("||", None),
+    ];
+
+    for &(double, single) in MARKERS {
+        let closes_single = single.is_some() && innermost == single && is_word_edge(prev);
+
+        if content.starts_with(double) && !closes_single {
+            return Some(double);
+        }
+
+        if let Some(single) = single {
+            if content.starts_with(single) {
+                return Some(single);
+            }
+        }
+    }
+
+    None
+}
+
+/// Whether a marker can close and open formatting, given the characters on
+/// either side of it.
+///
+/// A single `*` or `_` doesn't close after whitespace, nor open before it,
+/// and `_` doesn't do either within a word.
+pub fn flanking(marker: &str, prev: Option<char>, after: Option<char>) -> (bool, bool) {
+    let single = marker.len() == 1;
+    let intraword = marker == "_" && is_word(prev) && is_word(after);
+
+    let closes = !intraword && (!single || is_word_edge(prev));
+    let opens = !intraword && (!single || is_word_edge(after));
+
+    (closes, opens)
+}
+
+/// Whether a character next to a single `*` or `_` lets it open or close
+/// formatting, which it can't do beside whitespace.
+pub fn is_word_edge(c: Option<char>) -> bool {
+    match c {
+        Some(c) => !c.is_whitespace(),
+        None => false,
+    }
+}
+
+/// Whether a character is part of a word, for deciding whether `_` is a
+/// marker.
+pub fn is_word(c: Option<char>) -> bool {
+    match c {
+        Some(c) => c.is_alphanumeric(),
+        None => false,
+    }
+}
@@ -0,0 +1,275 @@
+use std::fmt::{
+    Debug,
+    Display,
+    Error as FmtError,
+    Formatter,
+    Result as FmtResult,
+    Write as FmtWrite,
+};
+use super::emoji::{EmojiIdentifier, ReactionType};
+use super::timestamp::TimestampStyle;
+
+/// A trait for values that can be mentioned in message content, such as
+/// users, channels, and emoji.
+///
+/// Implementors write their mention via [`fmt_mention`], and
+/// [`mention_display`] wraps that in a [`MentionDisplay`], so that the
+/// mention can be formatted straight into a message without allocating a
+/// string for it.
+///
+/// This is implemented for references to any mentionable value too, so that
+/// values don't need to be cloned to be mentioned. It is also implemented for:
+///
+/// - [`EmojiIdentifier`], as the emoji's tag;
+/// - [`GuildNavigation`], as its `<id:...>` mention;
+/// - [`Mention`], as the mention it was parsed from;
+/// - [`ReactionType`], as the emoji's tag, or the unicode emoji itself.
+///
+/// **Note**: [`mention`] used to be the method to implement, returning a
+/// `String`. It is now deprecated: implement [`fmt_mention`] instead, and call
+/// [`mention_display`], converting it with `to_string` where a `String` is
+/// still needed.
+///
+/// # Examples
+///
+/// Implementing the trait for a user model:
+///
+/// ```rust
+/// use serenity_utils::Mentionable;
+/// use std::fmt::{Formatter, Result as FmtResult};
+///
+/// struct User {
+///     id: u64,
+/// }
+///
+/// impl Mentionable for User {
+///     fn fmt_mention(&self, f: &mut Formatter) -> FmtResult { write!(f, "<@{}>", self.id) }
+/// }
+///
+/// let user = User { id: 114941315417899012 };
+///
+/// assert_eq!(user.mention_display(), "<@114941315417899012>");
+/// assert_eq!((&user).mention_display(), "<@114941315417899012>");
+/// assert_eq!(format!("Hi {}!", user.mention_display()), "Hi <@114941315417899012>!");
+/// ```
+///
+/// [`EmojiIdentifier`]: struct.EmojiIdentifier.html
+/// [`GuildNavigation`]: enum.GuildNavigation.html
+/// [`Mention`]: enum.Mention.html
+/// [`MentionDisplay`]: struct.MentionDisplay.html
+/// [`ReactionType`]: enum.ReactionType.html
+/// [`fmt_mention`]: #tymethod.fmt_mention
+/// [`mention`]: #method.mention
+/// [`mention_display`]: #method.mention_display
+pub trait Mentionable {
+    /// Writes a mention that will be rendered by Discord's client.
+    fn fmt_mention(&self, f: &mut Formatter) -> FmtResult;
+
+    /// Creates a mention that will be rendered by Discord's client, which
+    /// can be formatted via its `Display` implementation.
+    fn mention_display(&self) -> MentionDisplay<'_, Self> { MentionDisplay(self) }
+
+    /// Creates a mention that will be rendered by Discord's client, as a
+    /// `String`.
+    #[deprecated(note = "use `mention_display`, with `to_string` if a `String` is needed")]
+    fn mention(&self) -> String { self.mention_display().to_string() }
+}
+
+impl<T: Mentionable + ?Sized> Mentionable for &T {
+    fn fmt_mention(&self, f: &mut Formatter) -> FmtResult { (**self).fmt_mention(f) }
+}
+
+impl Mentionable for EmojiIdentifier {
+    fn fmt_mention(&self, f: &mut Formatter) -> FmtResult { Display::fmt(self, f) }
+}
+
+impl Mentionable for GuildNavigation {
+    fn fmt_mention(&self, f: &mut Formatter) -> FmtResult { Display::fmt(self, f) }
+}
+
+impl Mentionable for ReactionType {
+    fn fmt_mention(&self, f: &mut Formatter) -> FmtResult { Display::fmt(self, f) }
+}
+
+/// A mention of a [`Mentionable`] value, as returned by
+/// [`Mentionable::mention_display`].
+///
+/// The `Display` implementation writes the mention directly, without
+/// allocating.
+///
+/// [`Mentionable`]: trait.Mentionable.html
+/// [`Mentionable::mention_display`]: trait.Mentionable.html#method.mention_display
+pub struct MentionDisplay<'a, M: ?Sized + 'a>(&'a M);
+
+impl<'a, M: ?Sized> Clone for MentionDisplay<'a, M> {
+    fn clone(&self) -> Self { *self }
+}
+
+impl<'a, M: ?Sized> Copy for MentionDisplay<'a, M> {}
+
+impl<'a, M: Mentionable + ?Sized> Debug for MentionDisplay<'a, M> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult { Debug::fmt(&self.to_string(), f) }
+}
+
+impl<'a, M: Mentionable + ?Sized> Display for MentionDisplay<'a, M> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult { self.0.fmt_mention(f) }
+}
+
+impl<'a, M: Mentionable + ?Sized> From<MentionDisplay<'a, M>> for String {
+    fn from(mention: MentionDisplay<'a, M>) -> String { mention.to_string() }
+}
+
+impl<'a, M: Mentionable + ?Sized> PartialEq<str> for MentionDisplay<'a, M> {
+    fn eq(&self, other: &str) -> bool {
+        let mut rest = Unmatched(other);
+
+        write!(rest, "{}", self).is_ok() && rest.0.is_empty()
+    }
+}
+
+impl<'a, 'b, M: Mentionable + ?Sized> PartialEq<&'b str> for MentionDisplay<'a, M> {
+    fn eq(&self, other: &&'b str) -> bool { *self == **other }
+}
+
+impl<'a, M: Mentionable + ?Sized> PartialEq<String> for MentionDisplay<'a, M> {
+    fn eq(&self, other: &String) -> bool { *self == **other }
+}
+
+/// The part of a string that hasn't been matched yet by written content,
+/// failing on the first mismatch. This compares with content as it is written,
+/// without allocating.
+struct Unmatched<'a>(&'a str);
+
+impl<'a> FmtWrite for Unmatched<'a> {
+    fn write_str(&mut self, s: &str) -> FmtResult {
+        if !self.0.starts_with(s) {
+            return Err(FmtError);
+        }
+
+        self.0 = &self.0[s.len()..];
+
+        Ok(())
+    }
+}
+
+/// A special mention linking to one of a guild's navigation screens.
+///
+/// The `Display` implementation formats the navigation as its mention tag.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity_utils::GuildNavigation;
+///
+/// assert_eq!(GuildNavigation::Customize.to_string(), "<id:customize>");
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum GuildNavigation {
+    /// The "Channels & Roles" screen.
+    Customize,
+    /// The "Browse Channels" screen.
+    Browse,
+    /// The "Server Guide" screen.
+    Guide,
+    /// The "Linked Roles" screen.
+    LinkedRoles,
+}
+
+impl GuildNavigation {
+    /// The name of the navigation within its mention tag.
+    pub fn name(&self) -> &'static str {
+        use self::GuildNavigation::*;
+
+        match *self {
+            Customize => "customize",
+            Browse => "browse",
+            Guide => "guide",
+            LinkedRoles => "linked-roles",
+        }
+    }
+
+    /// Retrieves the navigation with the given name within a mention tag.
+    pub fn from_name(name: &str) -> Option<GuildNavigation> {
+        use self::GuildNavigation::*;
+
+        match name {
+            "customize" => Some(Customize),
+            "browse" => Some(Browse),
+            "guide" => Some(Guide),
+            "linked-roles" => Some(LinkedRoles),
+            _ => None,
+        }
+    }
+}
+
+impl Display for GuildNavigation {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult { write!(f, "<id:{}>", self.name()) }
+}
+
+/// A mention parsed from message content by [`parse_mention`].
+///
+/// The `Display` implementation formats the mention as its tag again.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity_utils::{parse_mention, Mention};
+///
+/// match parse_mention("<@&136107769680887808>") {
+///     Some(Mention::Role(id)) => assert_eq!(id, 136107769680887808),
+///     _ => unreachable!(),
+/// }
+///
+/// assert_eq!(Mention::Channel(81384788765712384).to_string(), "<#81384788765712384>");
+/// ```
+///
+/// [`parse_mention`]: fn.parse_mention.html
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Mention {
+    /// A mention of a channel, in the form of `<#id>`.
+    Channel(u64),
+    /// A mention of a slash command, in the form of `</name:id>`.
+    Command {
+        /// The Id of the command.
+        id: u64,
+        /// The name of the command, including any subcommand group and
+        /// subcommand, separated by spaces.
+        name: String,
+    },
+    /// A custom emoji, in the form of `<:name:id>` or `<a:name:id>`.
+    Emoji(EmojiIdentifier),
+    /// A mention of a guild's navigation screen, such as `<id:browse>`.
+    Navigation(GuildNavigation),
+    /// A mention of a role, in the form of `<@&id>`.
+    Role(u64),
+    /// A timestamp, in the form of `<t:timestamp>` or `<t:timestamp:style>`.
+    Timestamp {
+        /// The style of the timestamp, if one was given.
+        style: Option<TimestampStyle>,
+        /// The timestamp, in seconds since the Unix epoch.
+        timestamp: i64,
+    },
+    /// A mention of a user, in the form of `<@id>` or `<@!id>`.
+    User(u64),
+}
+
+impl Display for Mention {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match *self {
+            Mention::Channel(id) => write!(f, "<#{}>", id),
+            Mention::Command { id, ref name } => write!(f, "</{}:{}>", name, id),
+            Mention::Emoji(ref emoji) => Display::fmt(emoji, f),
+            Mention::Navigation(navigation) => Display::fmt(&navigation, f),
+            Mention::Role(id) => write!(f, "<@&{}>", id),
+            Mention::Timestamp { style: Some(style), timestamp } => {
+                write!(f, "<t:{}:{}>", timestamp, style)
+            },
+            Mention::Timestamp { style: None, timestamp } => write!(f, "<t:{}>", timestamp),
+            Mention::User(id) => write!(f, "<@{}>", id),
+        }
+    }
+}
+
+impl Mentionable for Mention {
+    fn fmt_mention(&self, f: &mut Formatter) -> FmtResult { Display::fmt(self, f) }
+}
@@ -0,0 +1,4622 @@
+use std::collections::hash_map::DefaultHasher;
+use std::fmt::{Display, Formatter, Result as FmtResult, Write as FmtWrite};
+use std::hash::{Hash, Hasher};
+use std::iter::FromIterator;
+use std::mem;
+use std::ops::{Add, BitOr, BitOrAssign, Deref, Range};
+use std::result::Result as StdResult;
+use std::time::Duration;
+use super::ansi::AnsiBuilder;
+use super::bytes::{self, ByteStyle};
+use super::content::{self, TokenKind};
+use super::duration::{self, DurationStyle};
+use super::emoji::{emoji_shortcode, ReactionType};
+use super::error::{
+    ContentFull,
+    MarkdownIssue,
+    MarkdownKind,
+    MessageTooLong,
+    ParseIdError,
+    StaleCheckpoint,
+    UnknownEmoji,
+};
+use super::{
+    discord_len,
+    is_mass_mention,
+    normalize_into,
+    parse_channel,
+    parse_id,
+    parse_role,
+    parse_username,
+};
+use super::markdown::closing_run;
+use super::mention::{GuildNavigation, Mentionable};
+use super::progress::{self, ProgressStyle};
+use super::split::{split_message, truncate};
+use super::status::{self, StatusIcons};
+use super::table::TableBuilder;
+use super::timestamp::{IntoTimestamp, TimestampStyle};
+
+#[cfg(feature = "serde")]
+use serde::de::{Deserialize, Deserializer, Error as DeError};
+#[cfg(feature = "serde")]
+use serde::ser::{Serialize, Serializer};
+
+/// The maximum number of characters Discord allows in the content of a
+/// message, counted in unicode code points as by [`MessageBuilder::len`].
+///
+/// [`MessageBuilder::len`]: struct.MessageBuilder.html#method.len
+pub const MESSAGE_CODE_LIMIT: usize = 2000;
+
+/// A look-alike replacement for backticks inside of code spans.
+const GRAVE_ACCENT: char = '\u{02CB}';
+
+/// Inserted between markers that would otherwise run together, such as two
+/// adjacent underlined segments producing `____`.
+const ZERO_WIDTH_SPACE: char = '\u{200B}';
+
+/// The message builder is useful for easily building messages, formatting
+/// content with Discord markdown.
+///
+/// # Examples
+///
+/// Build a message, bolding a username and italicizing a note:
+///
+/// ```rust
+/// use serenity_utils::MessageBuilder;
+///
+/// let content = MessageBuilder::new()
+///     .push("Hello, ")
+///     .push_bold("acdenisSK")
+///     .push("! ")
+///     .push_italic("Welcome.")
+///     .build();
+///
+/// assert_eq!(content, "Hello, **acdenisSK**! *Welcome.*");
+/// ```
+///
+/// To inspect the content while building, prefer [`as_str`] over the tuple
+/// struct's first value. The builder can also be compared against strings
+/// directly:
+///
+/// ```rust
+/// use serenity_utils::MessageBuilder;
+///
+/// let builder = MessageBuilder::new().push_bold("hi");
+///
+/// assert_eq!(builder.as_str(), "**hi**");
+/// assert_eq!(builder, "**hi**");
+/// ```
+///
+/// [`as_str`]: #method.as_str
+#[derive(Clone, Debug, Default)]
+pub struct MessageBuilder(pub String);
+
+impl MessageBuilder {
+    /// Creates a new, empty builder.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// assert!(MessageBuilder::new().build().is_empty());
+    /// ```
+    pub fn new() -> MessageBuilder { MessageBuilder::default() }
+
+    /// Creates a new, empty builder with space for at least the given number
+    /// of bytes of content, so that pushing content up to that size doesn't
+    /// need to reallocate.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let builder = MessageBuilder::with_capacity(64);
+    ///
+    /// assert!(builder.is_empty());
+    /// assert!(builder.capacity() >= 64);
+    /// ```
+    pub fn with_capacity(capacity: usize) -> MessageBuilder {
+        MessageBuilder(String::with_capacity(capacity))
+    }
+
+    /// Creates a builder which continues from existing content, checking that
+    /// the content fits within [`MESSAGE_CODE_LIMIT`].
+    ///
+    /// Unlike creating the builder via `From<String>`, this returns an error
+    /// if the content is already too long for Discord to accept, with its
+    /// length counted as by [`len`].
+    ///
+    /// This takes the place of an implementation of `TryFrom<String>`, which
+    /// can't be added since the builder implements `From<String>`: the
+    /// standard library already implements `TryFrom<U>` for every type
+    /// implementing `From<U>`, with an error that can never occur, and the two
+    /// implementations would conflict.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MessageTooLong`] if the content is longer than
+    /// [`MESSAGE_CODE_LIMIT`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let builder = MessageBuilder::try_from_content("Hello, ".to_string()).unwrap();
+    ///
+    /// assert_eq!(builder.push_bold("world"), "Hello, **world**");
+    ///
+    /// let err = MessageBuilder::try_from_content("a".repeat(2001)).unwrap_err();
+    ///
+    /// assert_eq!(err.length, 2001);
+    /// assert_eq!(err.limit, 2000);
+    /// ```
+    ///
+    /// [`MESSAGE_CODE_LIMIT`]: constant.MESSAGE_CODE_LIMIT.html
+    /// [`MessageTooLong`]: struct.MessageTooLong.html
+    /// [`len`]: #method.len
+    pub fn try_from_content(content: String) -> StdResult<MessageBuilder, MessageTooLong> {
+        let builder = MessageBuilder(content);
+        builder.check_length()?;
+
+        Ok(builder)
+    }
+
+    /// Returns the number of bytes of content the builder can hold without
+    /// reallocating.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// assert_eq!(MessageBuilder::new().capacity(), 0);
+    /// ```
+    pub fn capacity(&self) -> usize { self.0.capacity() }
+
+    /// Pulls the inner value out of the builder.
+    ///
+    /// This is equivalent to simply retrieving the tuple struct's first value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new().push("test").build();
+    ///
+    /// assert_eq!(content, "test");
+    /// ```
+    pub fn build(self) -> String { self.0 }
+
+    /// Edits the builder in place, calling the given function with the
+    /// builder and storing the builder it returns.
+    ///
+    /// This allows any of the chained methods to be used through a mutable
+    /// reference, such as in a loop or a helper function adding a section to
+    /// a message, without rebinding the builder each time.
+    ///
+    /// # Examples
+    ///
+    /// Conditionally push lines in a loop:
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let mut builder = MessageBuilder::new();
+    /// builder.edit(|b| b.push_bold_line("Online"));
+    ///
+    /// for &(name, online) in &[("acdenisSK", true), ("Zeyla", false), ("Mei", true)] {
+    ///     if online {
+    ///         builder.edit(|b| b.push("- ").push_line(name));
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(builder.build(), "**Online**\n- acdenisSK\n- Mei\n");
+    /// ```
+    ///
+    /// Add a section in a helper function:
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// fn footer(builder: &mut MessageBuilder, version: &str) {
+    ///     builder.edit(|b| b.push_subtext("v").push_mono(version));
+    /// }
+    ///
+    /// let mut builder = MessageBuilder::new().push("Pong!");
+    /// footer(&mut builder, "0.1.0");
+    ///
+    /// assert_eq!(builder.build(), "Pong!\n-# v\n`0.1.0`");
+    /// ```
+    pub fn edit<F: FnOnce(MessageBuilder) -> MessageBuilder>(&mut self, f: F) -> &mut Self {
+        let builder = mem::replace(self, MessageBuilder::new());
+        *self = f(builder);
+
+        self
+    }
+
+    /// Returns the message content built so far.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let builder = MessageBuilder::new().push("a").push_italic("b");
+    ///
+    /// assert_eq!(builder.as_str(), "a*b*");
+    /// ```
+    pub fn as_str(&self) -> &str { &self.0 }
+
+    /// Returns the length of the message content, as counted by Discord.
+    ///
+    /// Discord counts the length of content in unicode code points, rather
+    /// than in bytes. This means that each of `é`, `漢`, and `👋` count as one
+    /// character, even though they take up two, three, and four bytes.
+    ///
+    /// Code points aren't the same as what a reader sees as one character: an
+    /// `e` followed by a combining accent counts as two, and a family emoji
+    /// joined with zero-width joiners counts every emoji and joiner in it.
+    /// The same rule is used by [`remaining`], [`try_build`], and when
+    /// splitting or truncating content, so they never disagree.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let builder = MessageBuilder::new().push("👋 漢字");
+    ///
+    /// assert_eq!(builder.len(), 4);
+    /// assert_eq!(builder.0.len(), 11);
+    /// ```
+    ///
+    /// [`remaining`]: #method.remaining
+    /// [`try_build`]: #method.try_build
+    pub fn len(&self) -> usize { discord_len(&self.0) }
+
+    /// Whether the message content is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// assert!(MessageBuilder::new().is_empty());
+    /// assert!(!MessageBuilder::new().push("a").is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool { self.0.is_empty() }
+
+    /// Returns how many more characters fit in the message content before it
+    /// reaches [`MESSAGE_CODE_LIMIT`], as counted by [`len`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let builder = MessageBuilder::new().push("👋");
+    ///
+    /// assert_eq!(builder.remaining(), 1999);
+    /// ```
+    ///
+    /// [`MESSAGE_CODE_LIMIT`]: constant.MESSAGE_CODE_LIMIT.html
+    /// [`len`]: #method.len
+    pub fn remaining(&self) -> usize { MESSAGE_CODE_LIMIT.saturating_sub(self.len()) }
+
+    /// Pulls the inner value out of the builder, checking that it fits within
+    /// [`MESSAGE_CODE_LIMIT`].
+    ///
+    /// Unlike [`build`], this returns an error if Discord would reject the
+    /// content for being too long, with its length counted as by [`len`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// assert!(MessageBuilder::new().push("hi").try_build().is_ok());
+    ///
+    /// let err = MessageBuilder::new().push("a".repeat(2001)).try_build().unwrap_err();
+    ///
+    /// assert_eq!(err.length, 2001);
+    /// assert_eq!(err.limit, 2000);
+    /// ```
+    ///
+    /// [`MESSAGE_CODE_LIMIT`]: constant.MESSAGE_CODE_LIMIT.html
+    /// [`build`]: #method.build
+    /// [`len`]: #method.len
+    pub fn try_build(self) -> StdResult<String, MessageTooLong> {
+        self.check_length()?;
+
+        Ok(self.0)
+    }
+
+    /// Pulls the inner value out of the builder, checking that none of its
+    /// formatting is left open.
+    ///
+    /// Refer to [`check_markdown`] for the formatting which is checked. The
+    /// length of the content isn't checked; use [`try_build`] for that.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// assert!(MessageBuilder::new().push_bold("hi").build_checked().is_ok());
+    ///
+    /// let issues = MessageBuilder::new().push("**hi").build_checked().unwrap_err();
+    ///
+    /// assert_eq!(issues[0].to_string(), "Unclosed bold text at byte 0");
+    /// ```
+    ///
+    /// [`check_markdown`]: #method.check_markdown
+    /// [`try_build`]: #method.try_build
+    pub fn build_checked(self) -> StdResult<String, Vec<MarkdownIssue>> {
+        self.check_markdown()?;
+
+        Ok(self.0)
+    }
+
+    /// Checks that all of the formatting in the message content is closed, as
+    /// otherwise it runs on and changes how everything after it renders.
+    ///
+    /// Bold, italic, underlined, and struck-through text, spoilers, code
+    /// spans, and code blocks are checked. Markers are found as by
+    /// [`content::parse`], so markers within code, markers escaped with a
+    /// backslash, and markers which can't open formatting where they are,
+    /// such as a `*` between spaces, are ignored. Each issue is returned with
+    /// the byte offset of the marker left open, in the order the markers
+    /// appear.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::{MarkdownIssue, MarkdownKind, MessageBuilder};
+    ///
+    /// let builder = MessageBuilder::new().push_mono("**").push(" and ||a secret");
+    /// let issues = builder.check_markdown().unwrap_err();
+    ///
+    /// assert_eq!(issues, vec![MarkdownIssue { kind: MarkdownKind::Spoiler, offset: 9 }]);
+    /// ```
+    ///
+    /// [`content::parse`]: content/fn.parse.html
+    pub fn check_markdown(&self) -> StdResult<(), Vec<MarkdownIssue>> {
+        let issues = content::unclosed_markers(&self.0)
+            .into_iter()
+            .map(|(offset, marker)| {
+                let kind = match marker {
+                    "**" => MarkdownKind::Bold,
+                    "*" | "_" => MarkdownKind::Italic,
+                    "__" => MarkdownKind::Underline,
+                    "~~" => MarkdownKind::Strikethrough,
+                    "||" => MarkdownKind::Spoiler,
+                    "```" => MarkdownKind::CodeBlock,
+                    _ => MarkdownKind::CodeSpan,
+                };
+
+                MarkdownIssue { kind, offset }
+            })
+            .collect::<Vec<_>>();
+
+        if issues.is_empty() {
+            Ok(())
+        } else {
+            Err(issues)
+        }
+    }
+
+    /// Splits the message content built so far into segments, such as
+    /// mentions, styled text, and code blocks, so that an earlier part of the
+    /// message can be removed or replaced.
+    ///
+    /// The segments are found from the content itself, as by
+    /// [`content::parse`], and so include content pushed in any way. Styled
+    /// text, including inline code, is a single segment along with everything
+    /// within it, and each newline outside of a code block is a segment of its
+    /// own.
+    ///
+    /// **Note**: Segments are pieces of the parsed content, not the pushes
+    /// which built it. The builder only stores its content, which can also be
+    /// changed directly through the tuple struct's first value, so storing
+    /// the builder as a list of pushed segments is out of scope while that
+    /// value is public. Instead, the content is parsed on each call, which
+    /// takes time proportional to its length. Content written in separate
+    /// pushes which parses as one piece, such as two pieces of text, is one
+    /// segment, and content written in one push can be several, such as a
+    /// block of lines pushed by several calls to [`push_line`].
+    ///
+    /// To be able to drop a block of optional content later, push it as a
+    /// single segment, such as a code block or styled text, or take a
+    /// [`checkpoint`] before pushing it if it comes last.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::{MessageBuilder, SegmentKind};
+    ///
+    /// let builder = MessageBuilder::new()
+    ///     .push("Hi ")
+    ///     .user(114941315417899012)
+    ///     .push_line("!")
+    ///     .push_bold("Rules:");
+    ///
+    /// let kinds = builder.segments().iter().map(|segment| segment.kind).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(kinds, [
+    ///     SegmentKind::Text,
+    ///     SegmentKind::Mention,
+    ///     SegmentKind::Text,
+    ///     SegmentKind::LineBreak,
+    ///     SegmentKind::Styled,
+    /// ]);
+    /// assert_eq!(builder.segments()[4].content, "**Rules:**");
+    /// ```
+    ///
+    /// [`checkpoint`]: #method.checkpoint
+    /// [`content::parse`]: content/fn.parse.html
+    /// [`push_line`]: #method.push_line
+    pub fn segments(&self) -> Vec<Segment<'_>> {
+        let mut segments = vec![];
+
+        for token in content::parse(&self.0) {
+            let kind = match token.kind {
+                TokenKind::Text(ref text) => {
+                    let mut start = token.range.start;
+
+                    for (idx, line) in text.split('\n').enumerate() {
+                        if idx > 0 {
+                            segments.push(Segment {
+                                kind: SegmentKind::LineBreak,
+                                content: "\n",
+                                range: start..start + 1,
+                            });
+                            start += 1;
+                        }
+
+                        if !line.is_empty() {
+                            let range = start..start + line.len();
+
+                            segments.push(Segment {
+                                kind: SegmentKind::Text,
+                                content: &self.0[range.clone()],
+                                range,
+                            });
+                            start += line.len();
+                        }
+                    }
+
+                    continue;
+                },
+                TokenKind::CodeBlock { .. } => SegmentKind::CodeBlock,
+                TokenKind::CodeSpan { .. } | TokenKind::Styled { .. } => SegmentKind::Styled,
+                _ => SegmentKind::Mention,
+            };
+
+            segments.push(Segment {
+                kind,
+                content: &self.0[token.range.clone()],
+                range: token.range,
+            });
+        }
+
+        segments
+    }
+
+    /// Removes a segment of the message content, by its index within
+    /// [`segments`].
+    ///
+    /// The segments are found again on each call, as by [`segments`], so this
+    /// takes time proportional to the length of the content. Removing a
+    /// segment can join the segments either side of it, such as two pieces of
+    /// text, so find the segments again before removing or replacing another.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is not less than the number of [`segments`].
+    ///
+    /// # Examples
+    ///
+    /// Drop the largest segment, such as an optional block of details pushed
+    /// as a single code block, if the message would be too long:
+    ///
+    /// ```rust
+    /// use serenity_utils::{MessageBuilder, MESSAGE_CODE_LIMIT};
+    ///
+    /// let mut builder = MessageBuilder::new()
+    ///     .push_line("Build failed.")
+    ///     .push_codeblock(&"error: oops\n".repeat(200), None)
+    ///     .push_italic("See the logs for more.");
+    ///
+    /// if builder.len() > MESSAGE_CODE_LIMIT {
+    ///     let segments = builder.segments();
+    ///     let (largest, _) = segments
+    ///         .iter()
+    ///         .enumerate()
+    ///         .max_by_key(|(_, segment)| segment.len())
+    ///         .unwrap();
+    ///
+    ///     builder.remove_segment(largest);
+    /// }
+    ///
+    /// assert_eq!(builder, "Build failed.\n*See the logs for more.*");
+    /// ```
+    ///
+    /// [`segments`]: #method.segments
+    pub fn remove_segment(&mut self, idx: usize) -> &mut Self {
+        let range = self.segment_range(idx);
+        self.0.replace_range(range, "");
+
+        self
+    }
+
+    /// Replaces a segment of the message content, by its index within
+    /// [`segments`], with the given content exactly as it is.
+    ///
+    /// As with [`remove_segment`], the segments are found again on each call,
+    /// so find them again before removing or replacing another.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is not less than the number of [`segments`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let mut builder = MessageBuilder::new().push("Status: ").push_bold("pending");
+    /// builder.replace_segment(1, "**done**");
+    ///
+    /// assert_eq!(builder, "Status: **done**");
+    /// ```
+    ///
+    /// [`remove_segment`]: #method.remove_segment
+    /// [`segments`]: #method.segments
+    pub fn replace_segment<D: Display>(&mut self, idx: usize, content: D) -> &mut Self {
+        let range = self.segment_range(idx);
+        self.0.replace_range(range, &content.to_string());
+
+        self
+    }
+
+    /// Marks the message content built so far, so that anything pushed
+    /// after it can be undone with [`rollback_to`].
+    ///
+    /// The checkpoint stays valid however much is pushed after it, so content
+    /// can be pushed speculatively and only kept if it fits. Pushing content
+    /// after a checkpoint costs nothing extra.
+    ///
+    /// The checkpoint remembers the content by a hash of it, so taking one
+    /// takes time proportional to the length of the content so far, as does
+    /// rolling back to it. The builder can't track changes itself, as its
+    /// content can be changed directly through the tuple struct's first
+    /// value. For content within Discord's limits, this is still quick.
+    ///
+    /// # Examples
+    ///
+    /// Push sections until one doesn't fit, undoing just that one:
+    ///
+    /// ```rust
+    /// use serenity_utils::{MessageBuilder, MESSAGE_CODE_LIMIT};
+    ///
+    /// let sections = ["a".repeat(900), "b".repeat(900), "c".repeat(900)];
+    /// let mut builder = MessageBuilder::new();
+    ///
+    /// for section in &sections {
+    ///     let checkpoint = builder.checkpoint();
+    ///     builder.append_ref(&MessageBuilder::new().push_line(section));
+    ///
+    ///     if builder.len() > MESSAGE_CODE_LIMIT {
+    ///         builder.rollback_to(checkpoint).unwrap();
+    ///
+    ///         break;
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(builder.len(), 1802);
+    /// ```
+    ///
+    /// [`rollback_to`]: #method.rollback_to
+    pub fn checkpoint(&self) -> Checkpoint {
+        Checkpoint {
+            end: self.0.len(),
+            hash: hash_content(&self.0),
+        }
+    }
+
+    /// Undoes everything pushed since a [`checkpoint`], restoring the message
+    /// content to exactly what it was when the checkpoint was taken.
+    ///
+    /// The content up to the checkpoint is hashed again to check that it
+    /// hasn't changed, which takes time proportional to its length. The
+    /// capacity of the builder is kept.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StaleCheckpoint`], leaving the content as it is, if the
+    /// content the checkpoint was taken at has since been changed, such as by
+    /// rolling back to an earlier checkpoint or removing a segment before it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::{MessageBuilder, StaleCheckpoint};
+    ///
+    /// let mut builder = MessageBuilder::new().push("a");
+    /// let first = builder.checkpoint();
+    /// builder.append_ref(&MessageBuilder::new().push("b"));
+    /// let second = builder.checkpoint();
+    /// builder.append_ref(&MessageBuilder::new().push("c"));
+    ///
+    /// builder.rollback_to(first).unwrap();
+    /// assert_eq!(builder, "a");
+    ///
+    /// // The content the second checkpoint was taken at is gone.
+    /// assert_eq!(builder.rollback_to(second).unwrap_err(), StaleCheckpoint);
+    /// assert_eq!(builder, "a");
+    /// ```
+    ///
+    /// [`StaleCheckpoint`]: struct.StaleCheckpoint.html
+    /// [`checkpoint`]: #method.checkpoint
+    pub fn rollback_to(&mut self, checkpoint: Checkpoint) -> StdResult<&mut Self, StaleCheckpoint> {
+        let valid = checkpoint.end <= self.0.len()
+            && self.0.is_char_boundary(checkpoint.end)
+            && hash_content(&self.0[..checkpoint.end]) == checkpoint.hash;
+
+        if !valid {
+            return Err(StaleCheckpoint);
+        }
+
+        self.0.truncate(checkpoint.end);
+
+        Ok(self)
+    }
+
+    /// Finds the range of a segment by its index within the segments.
+    ///
+    /// Panics if the index is out of bounds, as documented by the public
+    /// methods using this.
+    fn segment_range(&self, idx: usize) -> Range<usize> {
+        let segments = self.segments();
+
+        match segments.get(idx) {
+            Some(segment) => segment.range.clone(),
+            None => panic!("segment index {} out of bounds for {} segments", idx, segments.len()),
+        }
+    }
+
+    /// Pulls the inner value out of the builder, split into chunks which each
+    /// fit within [`MESSAGE_CODE_LIMIT`].
+    ///
+    /// Refer to [`split_message`] for how the content is split.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let chunks = MessageBuilder::new()
+    ///     .push_line(&"a".repeat(1500))
+    ///     .push_line(&"b".repeat(1500))
+    ///     .build_chunks();
+    ///
+    /// assert_eq!(chunks.len(), 2);
+    /// assert_eq!(chunks[1], format!("{}\n", "b".repeat(1500)));
+    /// ```
+    ///
+    /// [`MESSAGE_CODE_LIMIT`]: constant.MESSAGE_CODE_LIMIT.html
+    /// [`split_message`]: fn.split_message.html
+    pub fn build_chunks(self) -> Vec<String> { split_message(&self.0) }
+
+    /// Empties the message content, keeping the space allocated for it so
+    /// that the builder can be reused without reallocating.
+    ///
+    /// # Examples
+    ///
+    /// Reuse a builder through a mutable reference with [`edit`]:
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let mut builder = MessageBuilder::with_capacity(64).push("first");
+    /// builder.edit(|b| b.clear().push("second"));
+    ///
+    /// assert_eq!(builder, "second");
+    /// assert!(builder.capacity() >= 64);
+    /// ```
+    ///
+    /// [`edit`]: #method.edit
+    pub fn clear(mut self) -> Self {
+        self.0.clear();
+
+        self
+    }
+
+    /// Cuts the message content back to at most the given number of
+    /// characters, as counted by [`len`], keeping the space allocated for it.
+    ///
+    /// Unlike [`truncate_to`], no ellipsis is added and formatting is left as
+    /// it is. Content which already fits is left as-is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let builder = MessageBuilder::new().push("👋 hello").truncate(3);
+    ///
+    /// assert_eq!(builder, "👋 h");
+    /// ```
+    ///
+    /// [`len`]: #method.len
+    /// [`truncate_to`]: #method.truncate_to
+    pub fn truncate(mut self, length: usize) -> Self {
+        if let Some((idx, _)) = self.0.char_indices().nth(length) {
+            self.0.truncate(idx);
+        }
+
+        self
+    }
+
+    /// Shortens the message content to at most the given number of
+    /// characters, as counted by [`len`], ending it with an ellipsis (`…`).
+    ///
+    /// The content is never cut within a mention, emoji, or timestamp tag,
+    /// nor within an emoji made up of several characters. If the cut leaves
+    /// bold text, a code block, or other formatting open, it is closed after
+    /// the ellipsis. Content which already fits is left as-is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .push_bold("a long announcement")
+    ///     .truncate_to(11)
+    ///     .build();
+    ///
+    /// assert_eq!(content, "**a long…**");
+    /// ```
+    ///
+    /// [`len`]: #method.len
+    pub fn truncate_to(mut self, length: usize) -> Self {
+        self.0 = truncate(&self.0, length);
+
+        self
+    }
+
+    /// Pulls the inner value out of the builder, shortened to at most the
+    /// given number of characters.
+    ///
+    /// Refer to [`truncate_to`] for how the content is shortened.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new().push("hello world").build_truncated(8);
+    ///
+    /// assert_eq!(content, "hello w…");
+    /// ```
+    ///
+    /// [`truncate_to`]: #method.truncate_to
+    pub fn build_truncated(self, length: usize) -> String { self.truncate_to(length).0 }
+
+    /// Pushes a string, or any other value implementing [`Display`], to the
+    /// internal message content, without any formatting.
+    ///
+    /// The value is written directly into the content, without first being
+    /// converted to a string of its own.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new().push("test").push("ing").build();
+    ///
+    /// assert_eq!(content, "testing");
+    /// ```
+    ///
+    /// Push numbers without converting them by hand:
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new().push(3).push(" of ").push(4.5).build();
+    ///
+    /// assert_eq!(content, "3 of 4.5");
+    /// ```
+    ///
+    /// [`Display`]: https://doc.rust-lang.org/std/fmt/trait.Display.html
+    pub fn push<D: Display>(mut self, content: D) -> Self {
+        let _ = write!(self.0, "{}", content);
+
+        self
+    }
+
+    /// Pushes a string, or any other value implementing [`Display`], to the
+    /// internal message content, followed by a newline.
+    ///
+    /// # Examples
+    ///
+    /// Build a three-line message, without pushing any newlines by hand:
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .push_bold_line("Stats")
+    ///     .push_line("Servers: 3")
+    ///     .push_mono_line("v0.1.0")
+    ///     .build();
+    ///
+    /// assert_eq!(content, "**Stats**\nServers: 3\n`v0.1.0`\n");
+    /// ```
+    ///
+    /// [`Display`]: https://doc.rust-lang.org/std/fmt/trait.Display.html
+    pub fn push_line<D: Display>(self, content: D) -> Self {
+        self.push(content).push("\n")
+    }
+
+    /// Pushes content to the internal message content, only if it still
+    /// fits within [`MESSAGE_CODE_LIMIT`] afterwards.
+    ///
+    /// Lengths are counted as by [`len`], the same as by [`try_build`]. Either
+    /// all of the content is pushed or none of it is, so this can be used to
+    /// stream content into a message until it's full.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ContentFull`], leaving the content as it is, if the content
+    /// doesn't fit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::{ContentFull, MessageBuilder};
+    ///
+    /// let mut builder = MessageBuilder::new().push("a".repeat(1995));
+    ///
+    /// assert!(builder.push_checked("bbb").is_ok());
+    ///
+    /// let err = builder.push_checked("cccc").unwrap_err();
+    ///
+    /// assert_eq!(err, ContentFull { needed: 4, remaining: 2 });
+    /// assert_eq!(builder.len(), 1998);
+    /// ```
+    ///
+    /// [`ContentFull`]: struct.ContentFull.html
+    /// [`MESSAGE_CODE_LIMIT`]: constant.MESSAGE_CODE_LIMIT.html
+    /// [`len`]: #method.len
+    /// [`try_build`]: #method.try_build
+    pub fn push_checked(&mut self, content: &str) -> StdResult<&mut Self, ContentFull> {
+        self.check_fits(discord_len(content))?;
+        self.0.push_str(content);
+
+        Ok(self)
+    }
+
+    /// Pushes content to the internal message content followed by a newline,
+    /// only if both still fit within [`MESSAGE_CODE_LIMIT`] afterwards.
+    ///
+    /// Refer to [`push_checked`] for how the length is checked.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ContentFull`], leaving the content as it is, if the content
+    /// and newline don't fit.
+    ///
+    /// # Examples
+    ///
+    /// Append log lines until the message is full, then send it:
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let lines = (0..500).map(|i| format!("[info] request {} handled", i));
+    /// let mut builder = MessageBuilder::new();
+    ///
+    /// for line in lines {
+    ///     if builder.push_line_checked(&line).is_err() {
+    ///         break;
+    ///     }
+    /// }
+    ///
+    /// assert!(builder.len() <= 2000);
+    /// assert!(builder.ends_with("handled\n"));
+    /// ```
+    ///
+    /// [`ContentFull`]: struct.ContentFull.html
+    /// [`MESSAGE_CODE_LIMIT`]: constant.MESSAGE_CODE_LIMIT.html
+    /// [`push_checked`]: #method.push_checked
+    pub fn push_line_checked(&mut self, content: &str) -> StdResult<&mut Self, ContentFull> {
+        self.check_fits(discord_len(content) + 1)?;
+        self.0.push_str(content);
+        self.0.push('\n');
+
+        Ok(self)
+    }
+
+    /// Pushes content to the internal message content with any of the
+    /// builder's methods, keeping it only if it still fits within
+    /// [`MESSAGE_CODE_LIMIT`] afterwards.
+    ///
+    /// This works with every way of pushing content, such as
+    /// [`push_bold_line`] or [`push_codeblock`], at the cost of copying the
+    /// content built so far. Refer to [`push_checked`] for how the length is
+    /// checked.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ContentFull`], leaving the content as it is, if what was
+    /// pushed doesn't fit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let mut builder = MessageBuilder::new().push_line("Results:");
+    ///
+    /// builder.push_checked_with(|builder| builder.push_bold_line("Passed: 12")).unwrap();
+    ///
+    /// let err = builder
+    ///     .push_checked_with(|builder| builder.push_codeblock(&"x".repeat(2000), None))
+    ///     .unwrap_err();
+    ///
+    /// assert_eq!(err.needed, 2008);
+    /// assert_eq!(builder, "Results:\n**Passed: 12**\n");
+    /// ```
+    ///
+    /// [`ContentFull`]: struct.ContentFull.html
+    /// [`MESSAGE_CODE_LIMIT`]: constant.MESSAGE_CODE_LIMIT.html
+    /// [`push_bold_line`]: #method.push_bold_line
+    /// [`push_checked`]: #method.push_checked
+    /// [`push_codeblock`]: #method.push_codeblock
+    pub fn push_checked_with<F>(&mut self, f: F) -> StdResult<&mut Self, ContentFull>
+        where F: FnOnce(MessageBuilder) -> MessageBuilder {
+        let pushed = f(self.clone());
+
+        if pushed.len() > MESSAGE_CODE_LIMIT {
+            return Err(ContentFull {
+                needed: pushed.len().saturating_sub(self.len()),
+                remaining: self.remaining(),
+            });
+        }
+
+        *self = pushed;
+
+        Ok(self)
+    }
+
+    /// Pushes content to the internal message content as a paragraph,
+    /// separated from any content before it by exactly one blank line.
+    ///
+    /// Newlines already at the end of the message content are added to or
+    /// trimmed as needed, and newlines at the start and end of the paragraph
+    /// itself are dropped, so that paragraphs don't accumulate runs of blank
+    /// lines between them. Nothing is pushed before the first paragraph of an
+    /// empty builder, and nothing at all is pushed if the paragraph is only
+    /// newlines.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .push_paragraph("Welcome!\n")
+    ///     .push_paragraph("Read the rules.")
+    ///     .push_paragraph("\n\nHave fun.\n\n\n")
+    ///     .build();
+    ///
+    /// assert_eq!(content, "Welcome!\n\nRead the rules.\n\nHave fun.");
+    /// ```
+    pub fn push_paragraph(mut self, content: &str) -> Self {
+        let content = content.trim_matches('\n');
+
+        if content.is_empty() {
+            return self;
+        }
+
+        let trimmed = self.0.trim_end_matches('\n').len();
+        self.0.truncate(trimmed);
+
+        if !self.0.is_empty() {
+            self.0.push_str("\n\n");
+        }
+
+        self.0.push_str(content);
+
+        self
+    }
+
+    /// Pushes a line in the form of `**key:** value` to the internal message
+    /// content, terminated with a newline.
+    ///
+    /// Both the key and the value have markdown escaped, as by [`push_safe`],
+    /// so that neither can break out of the bold key or format the value. Use
+    /// [`push_kv_raw`] for a value that is already formatted.
+    ///
+    /// # Examples
+    ///
+    /// Build a short card about a user:
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .push_kv("Name", "zey_la")
+    ///     .push_kv("Created", "2016-01-01")
+    ///     .push_kv("Servers", 3)
+    ///     .build();
+    ///
+    /// assert_eq!(content, "**Name:** zey\\_la\n**Created:** 2016-01-01\n**Servers:** 3\n");
+    /// ```
+    ///
+    /// [`push_kv_raw`]: #method.push_kv_raw
+    /// [`push_safe`]: #method.push_safe
+    pub fn push_kv<D: Display>(mut self, key: &str, value: D) -> Self {
+        self.push_key(key);
+        escape_markdown(&mut self.0, &value.to_string());
+        self.0.push('\n');
+
+        self
+    }
+
+    /// Pushes a line in the form of `**key:** value` to the internal message
+    /// content like [`push_kv`], but without escaping markdown in the value.
+    ///
+    /// The key is still escaped, so that it can't break out of the bold text.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .push_kv_raw("*Status*", "__online__")
+    ///     .build();
+    ///
+    /// assert_eq!(content, "**\\*Status\\*:** __online__\n");
+    /// ```
+    ///
+    /// [`push_kv`]: #method.push_kv
+    pub fn push_kv_raw<D: Display>(mut self, key: &str, value: D) -> Self {
+        self.push_key(key);
+        let _ = write!(self.0, "{}", value);
+        self.0.push('\n');
+
+        self
+    }
+
+    /// Pushes a set of fields to the internal message content, each in the
+    /// form of `**key:** value` as by [`push_kv`].
+    ///
+    /// When `inline` is `false`, each field is pushed on a line of its own.
+    /// Otherwise, fields are pushed on a single line separated by ` • `.
+    /// Either way, the fields are terminated with a newline.
+    ///
+    /// Fields with an empty or whitespace-only value are skipped, and nothing
+    /// is pushed if there are no fields left. If a value would take the
+    /// message over [`MESSAGE_CODE_LIMIT`], it is cut short with an ellipsis
+    /// (`…`) and no further fields are pushed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let fields = vec![("Owner", "Zeyla"), ("Region", ""), ("Members", "42")];
+    ///
+    /// let content = MessageBuilder::new().push_fields(fields.clone(), false).build();
+    /// assert_eq!(content, "**Owner:** Zeyla\n**Members:** 42\n");
+    ///
+    /// let content = MessageBuilder::new().push_fields(fields, true).build();
+    /// assert_eq!(content, "**Owner:** Zeyla • **Members:** 42\n");
+    /// ```
+    ///
+    /// [`MESSAGE_CODE_LIMIT`]: constant.MESSAGE_CODE_LIMIT.html
+    /// [`push_kv`]: #method.push_kv
+    pub fn push_fields<I, K, V>(mut self, fields: I, inline: bool) -> Self
+        where I: IntoIterator<Item = (K, V)>, K: AsRef<str>, V: Display {
+        let mut pushed = false;
+
+        for (key, value) in fields {
+            let value = value.to_string();
+
+            if value.trim().is_empty() {
+                continue;
+            }
+
+            let start = self.0.len();
+
+            if inline && pushed {
+                self.0.push_str(" • ");
+            }
+
+            self.push_key(key.as_ref());
+
+            let mut escaped = String::with_capacity(value.len());
+            escape_markdown(&mut escaped, &value);
+
+            // Leave room for the final newline.
+            let available = self.remaining().saturating_sub(1);
+
+            if discord_len(&escaped) <= available {
+                self.0.push_str(&escaped);
+            } else {
+                // Leave room for the ellipsis, and don't end on an escape.
+                let keep = available.saturating_sub(1);
+                let end = escaped.char_indices().nth(keep).map_or(escaped.len(), |(idx, _)| idx);
+                let cut = escaped[..end].trim_end_matches('\\');
+
+                if cut.is_empty() {
+                    self.0.truncate(start);
+
+                    break;
+                }
+
+                self.0.push_str(cut);
+                self.0.push_str("…\n");
+
+                return self;
+            }
+
+            if !inline {
+                self.0.push('\n');
+            }
+
+            pushed = true;
+        }
+
+        if inline && pushed {
+            self.0.push('\n');
+        }
+
+        self
+    }
+
+    /// Pushes the content of another builder to the internal message
+    /// content, as it is.
+    ///
+    /// This is useful for stitching together sections of a message built
+    /// separately. If this builder is empty, the other builder's content is
+    /// taken as-is rather than copied.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// fn header() -> MessageBuilder { MessageBuilder::new().push_h2("Stats") }
+    /// fn body() -> MessageBuilder { MessageBuilder::new().push_bold("3").push(" servers") }
+    ///
+    /// let content = header().append(body()).build();
+    ///
+    /// assert_eq!(content, "## Stats\n**3** servers");
+    /// ```
+    pub fn append(mut self, other: MessageBuilder) -> Self {
+        if self.0.is_empty() && self.0.capacity() < other.0.len() {
+            return other;
+        }
+
+        self.0.push_str(&other.0);
+
+        self
+    }
+
+    /// Pushes the content of another builder to the internal message
+    /// content, without taking ownership of either builder.
+    ///
+    /// Refer to [`append`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let signature = MessageBuilder::new().push_subtext("sent by a bot");
+    /// let mut first = MessageBuilder::new().push_line("Hi");
+    /// let mut second = MessageBuilder::new().push_line("Bye");
+    /// first.append_ref(&signature);
+    /// second.append_ref(&signature);
+    ///
+    /// assert_eq!(first, "Hi\n-# sent by a bot\n");
+    /// assert_eq!(second, "Bye\n-# sent by a bot\n");
+    /// ```
+    ///
+    /// [`append`]: #method.append
+    pub fn append_ref(&mut self, other: &MessageBuilder) -> &mut Self {
+        self.0.push_str(&other.0);
+
+        self
+    }
+
+    /// Pushes content to the internal message content like [`push`], only if
+    /// the condition is true.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let beta = true;
+    /// let content = MessageBuilder::new().push("v0.1.0").push_if(beta, "-beta").build();
+    ///
+    /// assert_eq!(content, "v0.1.0-beta");
+    /// ```
+    ///
+    /// [`push`]: #method.push
+    pub fn push_if<D: Display>(self, condition: bool, content: D) -> Self {
+        if condition {
+            self.push(content)
+        } else {
+            self
+        }
+    }
+
+    /// Pushes one of two contents to the internal message content like
+    /// [`push`], depending on whether the condition is true.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let count = 1;
+    /// let content = MessageBuilder::new()
+    ///     .push(count)
+    ///     .push_if_else(count == 1, " server", " servers")
+    ///     .build();
+    ///
+    /// assert_eq!(content, "1 server");
+    /// ```
+    ///
+    /// [`push`]: #method.push
+    pub fn push_if_else<D: Display, E: Display>(self, condition: bool, then: D, otherwise: E)
+        -> Self {
+        if condition {
+            self.push(then)
+        } else {
+            self.push(otherwise)
+        }
+    }
+
+    /// Pushes content to the internal message content like [`push`], only if
+    /// there is any.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let nickname = None::<&str>;
+    /// let content = MessageBuilder::new().push("Zeyla").push_opt(nickname).build();
+    ///
+    /// assert_eq!(content, "Zeyla");
+    /// ```
+    ///
+    /// [`push`]: #method.push
+    pub fn push_opt<D: Display>(self, content: Option<D>) -> Self {
+        match content {
+            Some(content) => self.push(content),
+            None => self,
+        }
+    }
+
+    /// Calls the given function with the builder, returning the builder it
+    /// returns.
+    ///
+    /// This keeps a chain going through sections that need more than a
+    /// single condition, or that are built by a helper function.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let errors = vec!["missing name", "bad colour"];
+    /// let content = MessageBuilder::new()
+    ///     .push_line("Checked the config.")
+    ///     .push_with(|b| if errors.is_empty() {
+    ///         b.push_success("No problems found")
+    ///     } else {
+    ///         b.push_bold_line("Problems:").push_list(&errors)
+    ///     })
+    ///     .build();
+    ///
+    /// assert_eq!(content, "Checked the config.\n**Problems:**\n- missing name\n- bad colour\n");
+    /// ```
+    pub fn push_with<F: FnOnce(MessageBuilder) -> MessageBuilder>(self, f: F) -> Self { f(self) }
+
+    /// Pushes [`Content`] to the internal message content, wrapped in the
+    /// markdown of each of its modifiers.
+    ///
+    /// Refer to [`Content`] for the order in which the modifiers are nested.
+    ///
+    /// # Examples
+    ///
+    /// Push content which is both bold and italic:
+    ///
+    /// ```rust
+    /// use serenity_utils::ContentModifier::*;
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .push("This is ")
+    ///     .push_content(Bold + Italic + "important")
+    ///     .build();
+    ///
+    /// assert_eq!(content, "This is ***important***");
+    /// ```
+    ///
+    /// [`Content`]: struct.Content.html
+    pub fn push_content<C: Into<Content>>(mut self, content: C) -> Self {
+        let content = content.into();
+        self.push_marked(&content.markers(), &content.inner);
+
+        self
+    }
+
+    /// Pushes content to the internal message content, wrapped in the markdown
+    /// of each of the given styles.
+    ///
+    /// This is equivalent to [`push_content`] with the matching modifiers
+    /// applied, and so the markers are nested in the same order. When
+    /// [`Styles::CODE`] is combined with other styles, the code span is placed
+    /// innermost, as markdown inside of it wouldn't be rendered.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::{MessageBuilder, Styles};
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .push_styled("text", Styles::BOLD | Styles::UNDERLINE)
+    ///     .build();
+    ///
+    /// assert_eq!(content, "__**text**__");
+    /// ```
+    ///
+    /// [`push_content`]: #method.push_content
+    /// [`Styles::CODE`]: struct.Styles.html#associatedconstant.CODE
+    pub fn push_styled(mut self, content: &str, styles: Styles) -> Self {
+        let modifiers = Content {
+            bold: styles.contains(Styles::BOLD),
+            italic: styles.contains(Styles::ITALIC),
+            underline: styles.contains(Styles::UNDERLINE),
+            strikethrough: styles.contains(Styles::STRIKETHROUGH),
+            spoiler: styles.contains(Styles::SPOILER),
+            code: styles.contains(Styles::CODE),
+            inner: String::new(),
+        };
+
+        self.push_marked(&modifiers.markers(), content);
+
+        self
+    }
+
+    /// Pushes a string to the internal message content, escaping any Discord
+    /// markdown in it so that it is displayed as written.
+    ///
+    /// The characters `*`, `_`, `~`, `|`, `` ` ``, and `>` are escaped with a
+    /// backslash. A character that is already escaped is left as it is, so
+    /// content is never escaped twice, and this can be safely used on content
+    /// that has been through it before. Any other content is left untouched.
+    ///
+    /// Mentions of `@everyone` and `@here` are also broken up with a
+    /// zero-width space after the `@`, so that echoing content can't ping a
+    /// whole server. Refer to [`escape_mass_mentions`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .push("You said: ")
+    ///     .push_safe("**bold** and ||spoiler||")
+    ///     .build();
+    ///
+    /// assert_eq!(content, r"You said: \*\*bold\*\* and \|\|spoiler\|\|");
+    /// ```
+    ///
+    /// [`escape_mass_mentions`]: fn.escape_mass_mentions.html
+    pub fn push_safe(mut self, content: &str) -> Self {
+        escape_markdown(&mut self.0, content);
+
+        self
+    }
+
+    /// Pushes a string to the internal message content, breaking up anything
+    /// that would ping someone with zero-width spaces.
+    ///
+    /// A zero-width space is inserted after every `@`, and between `<` and a
+    /// following `@` or `#`. Neither mass mentions nor user, role, or channel
+    /// mentions are then rendered, while the text still reads the same.
+    ///
+    /// Markdown is left as-is; use [`push_safe`] to escape it. As this only
+    /// inserts zero-width spaces, content can be pushed through both.
+    ///
+    /// For replacing mentions with the names of what they mention instead,
+    /// use [`content_safe`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .push_sanitized("hi @everyone, <@114941315417899012> says **hi**")
+    ///     .build();
+    ///
+    /// assert_eq!(
+    ///     content,
+    ///     "hi @\u{200B}everyone, <\u{200B}@\u{200B}114941315417899012> says **hi**",
+    /// );
+    /// ```
+    ///
+    /// [`content_safe`]: fn.content_safe.html
+    /// [`push_safe`]: #method.push_safe
+    pub fn push_sanitized(mut self, content: &str) -> Self {
+        for (idx, c) in content.char_indices() {
+            self.0.push(c);
+
+            let next = content[idx + c.len_utf8()..].chars().next();
+            let tag = c == '<' && (next == Some('@') || next == Some('#'));
+
+            if c == '@' || tag {
+                self.0.push(ZERO_WIDTH_SPACE);
+            }
+        }
+
+        self
+    }
+
+    /// Pushes content to the internal message content with invisible
+    /// characters removed, as with [`normalize`].
+    ///
+    /// This is useful for content from users, such as nicknames, which can
+    /// hide invisible characters to spoof other content.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .push("Uploaded by ")
+    ///     .push_normalized("ad\u{200B}min\u{202E}")
+    ///     .build();
+    ///
+    /// assert_eq!(content, "Uploaded by admin");
+    /// ```
+    ///
+    /// [`normalize`]: fn.normalize.html
+    pub fn push_normalized(mut self, content: &str) -> Self {
+        normalize_into(&mut self.0, content);
+
+        self
+    }
+
+    /// Pushes content to the internal message content, wrapped in bold
+    /// markdown (`**`).
+    ///
+    /// Nothing is pushed if the content is empty, so as not to leave a
+    /// dangling `****` in the message.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .push("Name: ")
+    ///     .push_bold("Zeyla")
+    ///     .push_bold("")
+    ///     .build();
+    ///
+    /// assert_eq!(content, "Name: **Zeyla**");
+    /// ```
+    pub fn push_bold(mut self, content: &str) -> Self {
+        self.wrap("**", content);
+
+        self
+    }
+
+    /// Pushes content to the internal message content, wrapped in italic
+    /// markdown (`*`).
+    ///
+    /// Nothing is pushed if the content is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new().push_italic("whisper").build();
+    ///
+    /// assert_eq!(content, "*whisper*");
+    /// ```
+    pub fn push_italic(mut self, content: &str) -> Self {
+        self.wrap("*", content);
+
+        self
+    }
+
+    /// Pushes content to the internal message content, wrapped in underline
+    /// markdown (`__`).
+    ///
+    /// Nothing is pushed if the content is empty. When the content directly
+    /// follows another marker using the same character, a zero-width space
+    /// is inserted between the two so they don't merge into one run.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .push_underline("one")
+    ///     .push_underline("two")
+    ///     .build();
+    ///
+    /// assert_eq!(content, "__one__\u{200B}__two__");
+    /// ```
+    pub fn push_underline(mut self, content: &str) -> Self {
+        self.wrap("__", content);
+
+        self
+    }
+
+    /// Pushes content to the internal message content, wrapped in
+    /// strikethrough markdown (`~~`).
+    ///
+    /// Nothing is pushed if the content is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .push_strike("old")
+    ///     .push(" new")
+    ///     .build();
+    ///
+    /// assert_eq!(content, "~~old~~ new");
+    /// ```
+    pub fn push_strike(mut self, content: &str) -> Self {
+        self.wrap("~~", content);
+
+        self
+    }
+
+    /// Pushes content to the internal message content, wrapped in spoiler
+    /// markdown (`||`).
+    ///
+    /// So that the spoiler can't be ended early by the content itself, a
+    /// zero-width space is inserted between any two neighbouring pipes in the
+    /// content, as well as next to a pipe at either end of it.
+    ///
+    /// Nothing is pushed if the content is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .push("The answer is ")
+    ///     .push_spoiler("42")
+    ///     .build();
+    ///
+    /// assert_eq!(content, "The answer is ||42||");
+    /// ```
+    ///
+    /// Pipes in the content are broken up:
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new().push_spoiler("a||b").build();
+    ///
+    /// assert_eq!(content, "||a|\u{200B}|b||");
+    /// ```
+    pub fn push_spoiler(mut self, content: &str) -> Self {
+        if content.is_empty() {
+            return self;
+        }
+
+        if self.0.ends_with('|') {
+            self.0.push(ZERO_WIDTH_SPACE);
+        }
+
+        self.0.push_str("||");
+        self.push_separated(content, '|');
+        self.0.push_str("||");
+
+        self
+    }
+
+    /// Pushes content to the internal message content, wrapped in inline code
+    /// markdown (`` ` ``).
+    ///
+    /// If the content itself contains backticks, the span is fenced with the
+    /// fewest backticks that don't appear as a run in the content, such as
+    /// `` `` `` for content with single backticks, so that nothing in it ends
+    /// the span early. When the content also starts or ends with a backtick,
+    /// a space is placed between it and the fence, which Discord strips when
+    /// rendering.
+    ///
+    /// Discord only has fences of one or two backticks for inline code, so
+    /// content containing runs of both lengths is pushed as by
+    /// [`push_mono_safe`] instead, with its backticks replaced.
+    ///
+    /// Nothing is pushed if the content is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .push("Run ")
+    ///     .push_mono("cargo test")
+    ///     .build();
+    ///
+    /// assert_eq!(content, "Run `cargo test`");
+    /// ```
+    ///
+    /// Content with backticks gets a doubled fence:
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new().push_mono("`already` quoted").build();
+    ///
+    /// assert_eq!(content, "`` `already` quoted ``");
+    ///
+    /// let content = MessageBuilder::new().push_mono("a``b").build();
+    ///
+    /// assert_eq!(content, "`a``b`");
+    /// ```
+    ///
+    /// [`push_mono_safe`]: #method.push_mono_safe
+    pub fn push_mono(mut self, content: &str) -> Self {
+        if !content.contains('`') {
+            self.wrap("`", content);
+
+            return self;
+        }
+
+        if self.0.ends_with('`') {
+            self.0.push(ZERO_WIDTH_SPACE);
+        }
+
+        let pad = if content.starts_with('`') || content.ends_with('`') {
+            " "
+        } else {
+            ""
+        };
+
+        let fence = match (closing_run(content, 1), closing_run(content, 2)) {
+            (None, _) => "`",
+            (_, None) => "``",
+            _ => return self.push_mono_safe(content),
+        };
+
+        self.0.push_str(fence);
+        self.0.push_str(pad);
+        self.0.push_str(content);
+        self.0.push_str(pad);
+        self.0.push_str(fence);
+
+        self
+    }
+
+    /// Pushes content to the internal message content, wrapped in inline code
+    /// markdown (`` ` ``), no matter what the content contains.
+    ///
+    /// Discord has no way of escaping a backtick inside of a code span, so
+    /// each backtick in the content is replaced with a look-alike character,
+    /// the modifier letter grave accent (`U+02CB`, `ˋ`). The span is then
+    /// always closed by its own fence.
+    ///
+    /// Nothing is pushed if the content is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .push("Your name is ")
+    ///     .push_mono_safe("``evil``")
+    ///     .build();
+    ///
+    /// assert_eq!(content, "Your name is `ˋˋevilˋˋ`");
+    /// ```
+    pub fn push_mono_safe(mut self, content: &str) -> Self {
+        if content.is_empty() {
+            return self;
+        }
+
+        if self.0.ends_with('`') {
+            self.0.push(ZERO_WIDTH_SPACE);
+        }
+
+        self.0.push('`');
+
+        for c in content.chars() {
+            self.0.push(if c == '`' { GRAVE_ACCENT } else { c });
+        }
+
+        self.0.push('`');
+
+        self
+    }
+
+    /// Pushes content to the internal message content, wrapped in bold markdown
+    /// and followed by a newline.
+    ///
+    /// Refer to [`push_bold`] for how the content is wrapped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new().push_bold_line("Warning").build();
+    ///
+    /// assert_eq!(content, "**Warning**\n");
+    /// ```
+    ///
+    /// [`push_bold`]: #method.push_bold
+    pub fn push_bold_line(self, content: &str) -> Self {
+        self.push_bold(content).push("\n")
+    }
+
+    /// Pushes content to the internal message content, wrapped in italic
+    /// markdown and followed by a newline.
+    ///
+    /// Refer to [`push_italic`] for how the content is wrapped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new().push_italic_line("sigh").build();
+    ///
+    /// assert_eq!(content, "*sigh*\n");
+    /// ```
+    ///
+    /// [`push_italic`]: #method.push_italic
+    pub fn push_italic_line(self, content: &str) -> Self {
+        self.push_italic(content).push("\n")
+    }
+
+    /// Pushes content to the internal message content, wrapped in underline
+    /// markdown and followed by a newline.
+    ///
+    /// Refer to [`push_underline`] for how the content is wrapped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new().push_underline_line("Title").build();
+    ///
+    /// assert_eq!(content, "__Title__\n");
+    /// ```
+    ///
+    /// [`push_underline`]: #method.push_underline
+    pub fn push_underline_line(self, content: &str) -> Self {
+        self.push_underline(content).push("\n")
+    }
+
+    /// Pushes content to the internal message content, wrapped in strikethrough
+    /// markdown and followed by a newline.
+    ///
+    /// Refer to [`push_strike`] for how the content is wrapped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new().push_strike_line("typo").build();
+    ///
+    /// assert_eq!(content, "~~typo~~\n");
+    /// ```
+    ///
+    /// [`push_strike`]: #method.push_strike
+    pub fn push_strike_line(self, content: &str) -> Self {
+        self.push_strike(content).push("\n")
+    }
+
+    /// Pushes content to the internal message content, wrapped in inline code
+    /// markdown and followed by a newline.
+    ///
+    /// Refer to [`push_mono`] for how the content is wrapped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new().push_mono_line("!help").build();
+    ///
+    /// assert_eq!(content, "`!help`\n");
+    /// ```
+    ///
+    /// [`push_mono`]: #method.push_mono
+    pub fn push_mono_line(self, content: &str) -> Self {
+        self.push_mono(content).push("\n")
+    }
+
+    /// Pushes a code block to the internal message content, with an optional
+    /// language to use for syntax highlighting.
+    ///
+    /// The fence is laid out as Discord expects it: the opening fence and
+    /// language are followed by a newline, and the closing fence is placed on
+    /// its own line. A newline is only added before the closing fence if the
+    /// content doesn't already end with one.
+    ///
+    /// Any run of three or more backticks within the content would end the
+    /// block early and render the rest of the content as markdown, so such
+    /// runs are broken up with zero-width spaces. Single and double backticks
+    /// are left as they are, as they can't end the block.
+    ///
+    /// Nothing is pushed if the content is empty.
+    ///
+    /// # Examples
+    ///
+    /// Push a Rust code block:
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .push_codeblock("fn main() {}", Some("rust"))
+    ///     .build();
+    ///
+    /// assert_eq!(content, "```rust\nfn main() {}\n```");
+    /// ```
+    ///
+    /// Push a code block without a language:
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .push("Output:")
+    ///     .push_codeblock("ok\n", None)
+    ///     .build();
+    ///
+    /// assert_eq!(content, "Output:```\nok\n```");
+    /// ```
+    ///
+    /// Fences within the content don't end the block:
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .push_codeblock("Use ```rust to start a block, and `x` for code.", Some("md"))
+    ///     .build();
+    ///
+    /// assert_eq!(content, "```md\nUse ``\u{200B}`rust to start a block, and `x` for code.\n```");
+    /// ```
+    pub fn push_codeblock(mut self, content: &str, language: Option<&str>) -> Self {
+        self.codeblock(content, language, false);
+
+        self
+    }
+
+    /// Pushes a code block to the internal message content like
+    /// [`push_codeblock`], but separates every backtick in the content.
+    ///
+    /// Where [`push_codeblock`] only breaks up the runs of backticks which
+    /// could end the block, this inserts a zero-width space between every two
+    /// neighbouring backticks, so that no run of backticks is left at all.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .push_codeblock_safe("a\n```\nb", None)
+    ///     .build();
+    ///
+    /// assert_eq!(content, "```\na\n`\u{200B}`\u{200B}`\nb\n```");
+    /// ```
+    ///
+    /// [`push_codeblock`]: #method.push_codeblock
+    pub fn push_codeblock_safe(mut self, content: &str, language: Option<&str>) -> Self {
+        self.codeblock(content, language, true);
+
+        self
+    }
+
+    /// Pushes text coloured with ANSI escape sequences to the internal message
+    /// content, inside of an `ansi` code block.
+    ///
+    /// Any code fences within the text are neutralized as with
+    /// [`push_codeblock_safe`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::{AnsiBuilder, AnsiColour, AnsiStyle, MessageBuilder};
+    ///
+    /// let text = AnsiBuilder::new()
+    ///     .push_styled("warning", &[AnsiStyle::Foreground(AnsiColour::Yellow)]);
+    ///
+    /// let content = MessageBuilder::new().push_ansi(&text).build();
+    ///
+    /// assert_eq!(content, "```ansi\n\u{1b}[33mwarning\u{1b}[0m\n```");
+    /// ```
+    ///
+    /// [`push_codeblock_safe`]: #method.push_codeblock_safe
+    pub fn push_ansi(mut self, text: &AnsiBuilder) -> Self {
+        self.codeblock(text.as_str(), Some("ansi"), true);
+
+        self
+    }
+
+    /// Pushes a `diff` code block to the internal message content, which
+    /// Discord highlights with removed lines in red and added lines in green.
+    ///
+    /// The removed lines are listed first, each prefixed with `- `, followed by
+    /// the added lines, each prefixed with `+ `. Because the prefix is always
+    /// pushed, lines which themselves start with `+` or `-` keep the color of
+    /// the side they were given on. Any code fences within the lines are
+    /// neutralized as with [`push_codeblock_safe`].
+    ///
+    /// Nothing is pushed if there are no lines.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .push_diff(vec!["new name"], vec!["old name"])
+    ///     .build();
+    ///
+    /// assert_eq!(content, "```diff\n- old name\n+ new name\n```");
+    /// ```
+    ///
+    /// [`push_codeblock_safe`]: #method.push_codeblock_safe
+    pub fn push_diff<'a, A, R>(mut self, added: A, removed: R) -> Self
+        where A: IntoIterator<Item = &'a str>, R: IntoIterator<Item = &'a str> {
+        let removed = removed.into_iter().flat_map(str::lines).map(|line| ("- ", line));
+        let added = added.into_iter().flat_map(str::lines).map(|line| ("+ ", line));
+        let mut lines = removed.chain(added).peekable();
+
+        if lines.peek().is_none() {
+            return self;
+        }
+
+        self.0.push_str("```diff\n");
+
+        for (prefix, line) in lines {
+            self.0.push_str(prefix);
+            self.push_runs_separated(line, '`');
+            self.0.push('\n');
+        }
+
+        self.0.push_str("```");
+
+        self
+    }
+
+    /// Pushes a table to the internal message content, inside of a code block
+    /// so that its columns line up.
+    ///
+    /// Any code fences in the table's cells are neutralized as with
+    /// [`push_codeblock_safe`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::{MessageBuilder, TableBuilder};
+    ///
+    /// let table = TableBuilder::new(&["Name", "Score"]).row(&["Zeyla", "12"]);
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .push("Leaderboard:")
+    ///     .push_table(&table)
+    ///     .build();
+    ///
+    /// assert_eq!(content, "Leaderboard:```\nName  | Score\n------+------\nZeyla | 12\n```");
+    /// ```
+    ///
+    /// [`push_codeblock_safe`]: #method.push_codeblock_safe
+    pub fn push_table(mut self, table: &TableBuilder) -> Self {
+        self.codeblock(&table.build(), None, true);
+
+        self
+    }
+
+    fn codeblock(&mut self, content: &str, language: Option<&str>, safe: bool) {
+        if content.is_empty() {
+            return;
+        }
+
+        self.0.push_str("```");
+
+        if let Some(language) = language {
+            self.0.push_str(language);
+        }
+
+        self.0.push('\n');
+
+        if safe {
+            self.push_runs_separated(content, '`');
+        } else {
+            self.push_fences_separated(content);
+        }
+
+        if !content.ends_with('\n') {
+            self.0.push('\n');
+        }
+
+        self.0.push_str("```");
+    }
+
+    /// Pushes a mention of a mentionable value to the internal message content.
+    ///
+    /// # Examples
+    ///
+    /// Mention the author of a message, through a reference to it:
+    ///
+    /// ```rust
+    /// use serenity_utils::{Mentionable, MessageBuilder};
+    /// use std::fmt::{Formatter, Result as FmtResult};
+    ///
+    /// struct User {
+    ///     id: u64,
+    /// }
+    ///
+    /// impl Mentionable for User {
+    ///     fn fmt_mention(&self, f: &mut Formatter) -> FmtResult { write!(f, "<@{}>", self.id) }
+    /// }
+    ///
+    /// struct Message {
+    ///     author: User,
+    /// }
+    ///
+    /// fn reply(message: &Message) -> String {
+    ///     MessageBuilder::new()
+    ///         .push("Hey ")
+    ///         .mention(&message.author)
+    ///         .build()
+    /// }
+    ///
+    /// let message = Message { author: User { id: 114941315417899012 } };
+    ///
+    /// assert_eq!(reply(&message), "Hey <@114941315417899012>");
+    /// ```
+    pub fn mention<M: Mentionable>(mut self, item: M) -> Self {
+        let _ = write!(self.0, "{}", item.mention_display());
+
+        self
+    }
+
+    /// Pushes a quote of an existing message to the internal message content,
+    /// attributed to its author.
+    ///
+    /// This pushes a line with a mention of the author and, if given, a
+    /// timestamp tag of when the message was sent. It is followed by the
+    /// message's content, with every line prefixed by `> `.
+    ///
+    /// The content is defused so that quoting it can't be abused:
+    ///
+    /// - lines starting with `>` are escaped, so they don't form quotes of
+    ///   their own;
+    /// - code fences are broken up with zero-width spaces;
+    /// - mass mentions and user, role, and channel mentions are broken up with
+    ///   zero-width spaces, so that nobody is pinged.
+    ///
+    /// If the quote would take the message past [`MESSAGE_CODE_LIMIT`], its
+    /// content is truncated as by [`truncate_to`], so that it isn't cut within a
+    /// tag or an emoji, and ends in an ellipsis (`…`). If there isn't room for
+    /// any of it, only the line with the author is pushed.
+    ///
+    /// **Note**: The mention of the author will still ping them, unless the
+    /// message is sent with allowed mentions excluding them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .quote_message(114941315417899012, "hi @everyone\n> fake quote", Some(1700000000))
+    ///     .build();
+    ///
+    /// let expected = "<@114941315417899012> <t:1700000000:f>\n\
+    ///                 > hi @\u{200B}everyone\n\
+    ///                 > \\> fake quote\n";
+    ///
+    /// assert_eq!(content, expected);
+    /// ```
+    ///
+    /// [`MESSAGE_CODE_LIMIT`]: constant.MESSAGE_CODE_LIMIT.html
+    /// [`truncate_to`]: #method.truncate_to
+    pub fn quote_message(mut self, author_id: u64, content: &str, timestamp: Option<i64>)
+        -> Self {
+        self.start_line();
+        let _ = write!(self.0, "<@{}>", author_id);
+
+        if let Some(timestamp) = timestamp {
+            let _ = write!(self.0, " <t:{}:{}>", timestamp, TimestampStyle::ShortDateTime);
+        }
+
+        self.0.push('\n');
+
+        let mut quote = String::new();
+
+        for line in content.lines() {
+            quote.push_str("> ");
+
+            if line.starts_with('>') {
+                quote.push('\\');
+            }
+
+            defuse(&mut quote, line);
+            quote.push('\n');
+        }
+
+        let available = MESSAGE_CODE_LIMIT.saturating_sub(self.len());
+
+        if discord_len(&quote) > available {
+            // Without room for even a quoted ellipsis, only the author line
+            // is pushed.
+            if available < "> …\n".chars().count() {
+                return self;
+            }
+
+            // Leave room for the final newline.
+            let truncated = truncate(quote.trim_end_matches('\n'), available - 1);
+            let ellipsis = truncated.rfind('…').unwrap_or(truncated.len());
+            let mut kept = &truncated[..ellipsis];
+
+            // The ellipsis mustn't start a line of its own, outside the quote.
+            for suffix in &["\n> ", "\n>", "\n"] {
+                if let Some(stripped) = kept.strip_suffix(suffix) {
+                    kept = stripped;
+
+                    break;
+                }
+            }
+
+            self.0.push_str(if kept.is_empty() { "> " } else { kept });
+            self.0.push_str(&truncated[ellipsis..]);
+            self.0.push('\n');
+
+            return self;
+        }
+
+        self.0.push_str(&quote);
+
+        self
+    }
+
+    /// Pushes a mention of a channel to the internal message content, in the
+    /// form of `<#id>`.
+    ///
+    /// To push the Id itself instead of a mention, use [`push`] with the Id
+    /// converted to a string.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .push("Head over to ")
+    ///     .channel(81384788765712384)
+    ///     .build();
+    ///
+    /// assert_eq!(content, "Head over to <#81384788765712384>");
+    /// ```
+    ///
+    /// [`push`]: #method.push
+    pub fn channel(mut self, id: u64) -> Self {
+        let _ = write!(self.0, "<#{}>", id);
+
+        self
+    }
+
+    /// Pushes a mention of a channel to the internal message content like
+    /// [`channel`], from either its Id or an existing mention of it.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseIdError`] if the string is neither an Id nor a channel
+    /// mention, in which case nothing is pushed. An Id of `0` is never valid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .try_channel("<#381880193700069377>")?
+    ///     .push(" and ")
+    ///     .try_channel("81384788765712384")?
+    ///     .build();
+    ///
+    /// assert_eq!(content, "<#381880193700069377> and <#81384788765712384>");
+    ///
+    /// assert!(MessageBuilder::new().try_channel("<@81384788765712384>").is_err());
+    /// # Ok::<(), serenity_utils::ParseIdError>(())
+    /// ```
+    ///
+    /// [`ParseIdError`]: struct.ParseIdError.html
+    /// [`channel`]: #method.channel
+    pub fn try_channel(self, id: &str) -> StdResult<Self, ParseIdError> {
+        Ok(self.channel(id_or_mention(id, parse_channel)?))
+    }
+
+    /// Pushes a mention of a role to the internal message content, in the form
+    /// of `<@&id>`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new().role(136107769680887808).build();
+    ///
+    /// assert_eq!(content, "<@&136107769680887808>");
+    /// ```
+    pub fn role(mut self, id: u64) -> Self {
+        let _ = write!(self.0, "<@&{}>", id);
+
+        self
+    }
+
+    /// Pushes a mention of a role to the internal message content like
+    /// [`role`], from either its Id or an existing mention of it.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseIdError`] if the string is neither an Id nor a role
+    /// mention, in which case nothing is pushed. An Id of `0` is never valid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new().try_role("<@&136107769680887808>")?.build();
+    ///
+    /// assert_eq!(content, "<@&136107769680887808>");
+    ///
+    /// assert!(MessageBuilder::new().try_role("admins").is_err());
+    /// # Ok::<(), serenity_utils::ParseIdError>(())
+    /// ```
+    ///
+    /// [`ParseIdError`]: struct.ParseIdError.html
+    /// [`role`]: #method.role
+    pub fn try_role(self, id: &str) -> StdResult<Self, ParseIdError> {
+        Ok(self.role(id_or_mention(id, parse_role)?))
+    }
+
+    /// Pushes a mention of a user to the internal message content, in the form
+    /// of `<@id>`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .push("Welcome, ")
+    ///     .user(114941315417899012)
+    ///     .build();
+    ///
+    /// assert_eq!(content, "Welcome, <@114941315417899012>");
+    /// ```
+    pub fn user(mut self, id: u64) -> Self {
+        let _ = write!(self.0, "<@{}>", id);
+
+        self
+    }
+
+    /// Pushes a mention of a user to the internal message content like
+    /// [`user`], from either their Id or an existing mention of them, which
+    /// may be a nickname mention.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseIdError`] if the string is neither an Id nor a user
+    /// mention, in which case nothing is pushed. An Id of `0` is never valid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .push("Welcome, ")
+    ///     .try_user("<@!114941315417899012>")?
+    ///     .build();
+    ///
+    /// assert_eq!(content, "Welcome, <@114941315417899012>");
+    ///
+    /// assert!(MessageBuilder::new().try_user("0").is_err());
+    /// # Ok::<(), serenity_utils::ParseIdError>(())
+    /// ```
+    ///
+    /// [`ParseIdError`]: struct.ParseIdError.html
+    /// [`user`]: #method.user
+    pub fn try_user(self, id: &str) -> StdResult<Self, ParseIdError> {
+        Ok(self.user(id_or_mention(id, parse_username)?))
+    }
+
+    /// Allows `@everyone` and `@here` to be mentioned, returning a builder on
+    /// which [`MassMentions::mention_everyone`] and
+    /// [`MassMentions::mention_here`] ping.
+    ///
+    /// Mass mentions are opted into explicitly so that a chain which pings a
+    /// whole server stands out in review, rather than looking like any other
+    /// [`push`]. Without the opt-in, [`mention_everyone`] and [`mention_here`]
+    /// push mentions which are broken up and don't ping anyone.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .allow_mass_mentions()
+    ///     .mention_everyone()
+    ///     .into_builder()
+    ///     .push(" The event starts in an hour!")
+    ///     .build();
+    ///
+    /// assert_eq!(content, "@everyone The event starts in an hour!");
+    /// ```
+    ///
+    /// [`MassMentions::mention_everyone`]: struct.MassMentions.html#method.mention_everyone
+    /// [`MassMentions::mention_here`]: struct.MassMentions.html#method.mention_here
+    /// [`mention_everyone`]: #method.mention_everyone
+    /// [`mention_here`]: #method.mention_here
+    /// [`push`]: #method.push
+    pub fn allow_mass_mentions(self) -> MassMentions { MassMentions(self) }
+
+    /// Pushes a mention of `@everyone` to the internal message content,
+    /// broken up with a zero-width space so that it doesn't ping anyone.
+    ///
+    /// The mention only pings once mass mentions are allowed with
+    /// [`allow_mass_mentions`], which doesn't apply to mentions pushed before
+    /// it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new().mention_everyone().build();
+    ///
+    /// assert_eq!(content, "@\u{200B}everyone");
+    /// ```
+    ///
+    /// [`allow_mass_mentions`]: #method.allow_mass_mentions
+    pub fn mention_everyone(mut self) -> Self {
+        self.0.push('@');
+        self.0.push(ZERO_WIDTH_SPACE);
+        self.0.push_str("everyone");
+
+        self
+    }
+
+    /// Pushes a mention of `@here` to the internal message content, broken
+    /// up with a zero-width space so that it doesn't ping anyone.
+    ///
+    /// Refer to [`mention_everyone`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new().mention_here().build();
+    ///
+    /// assert_eq!(content, "@\u{200B}here");
+    /// ```
+    ///
+    /// [`mention_everyone`]: #method.mention_everyone
+    pub fn mention_here(mut self) -> Self {
+        self.0.push('@');
+        self.0.push(ZERO_WIDTH_SPACE);
+        self.0.push_str("here");
+
+        self
+    }
+
+    /// Pushes a clickable slash command mention to the internal message
+    /// content, in the form of `</name:id>`.
+    ///
+    /// Subcommands and subcommand groups are mentioned by including them in
+    /// the name, separated by spaces, such as `"config set"`. Surrounding
+    /// whitespace is trimmed and runs of whitespace within the name are
+    /// collapsed to a single space, as otherwise Discord displays the mention
+    /// as plain text.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .push("Try ")
+    ///     .mention_command("config set", 1_091_234_567_890_123_456)
+    ///     .build();
+    ///
+    /// assert_eq!(content, "Try </config set:1091234567890123456>");
+    /// ```
+    pub fn mention_command(mut self, name: &str, id: u64) -> Self {
+        self.0.push_str("</");
+
+        for (idx, part) in name.split_whitespace().enumerate() {
+            if idx > 0 {
+                self.0.push(' ');
+            }
+
+            self.0.push_str(part);
+        }
+
+        let _ = write!(self.0, ":{}>", id);
+
+        self
+    }
+
+    /// Pushes an emoji to the internal message content.
+    ///
+    /// Unicode emoji, given as a string, are pushed as-is. Custom emoji are
+    /// pushed as their tag, in the form of `<:name:id>`, or `<a:name:id>` if
+    /// animated.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::{MessageBuilder, ReactionType};
+    ///
+    /// let custom = ReactionType::Custom {
+    ///     animated: false,
+    ///     id: 302516740095606785,
+    ///     name: "smugAnimeFace".to_string(),
+    /// };
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .emoji("✅")
+    ///     .push(" Done ")
+    ///     .emoji(custom)
+    ///     .build();
+    ///
+    /// assert_eq!(content, "✅ Done <:smugAnimeFace:302516740095606785>");
+    /// ```
+    pub fn emoji<R: Into<ReactionType>>(mut self, emoji: R) -> Self {
+        let _ = write!(self.0, "{}", emoji.into());
+
+        self
+    }
+
+    /// Pushes a custom emoji to the internal message content from its parts,
+    /// in the form of `<:name:id>`, or `<a:name:id>` if animated.
+    ///
+    /// This is like pushing a [`ReactionType::Custom`] or [`EmojiIdentifier`]
+    /// via [`emoji`], but without needing to own the name. Animated emoji
+    /// must be pushed as animated, or they are shown as a broken image.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .custom_emoji("blobcat", 271629243716468736, true)
+    ///     .custom_emoji("ferris", 302516740095606785, false)
+    ///     .build();
+    ///
+    /// assert_eq!(content, "<a:blobcat:271629243716468736><:ferris:302516740095606785>");
+    /// ```
+    ///
+    /// [`EmojiIdentifier`]: struct.EmojiIdentifier.html
+    /// [`ReactionType::Custom`]: enum.ReactionType.html#variant.Custom
+    /// [`emoji`]: #method.emoji
+    pub fn custom_emoji(mut self, name: &str, id: u64, animated: bool) -> Self {
+        let prefix = if animated { "a" } else { "" };
+        let _ = write!(self.0, "<{}:{}:{}>", prefix, name, id);
+
+        self
+    }
+
+    /// Pushes a unicode emoji to the internal message content, looked up by
+    /// its shortcode as with [`emoji_shortcode`].
+    ///
+    /// This avoids writing emoji directly in source files, where some of them
+    /// are hard to see.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnknownEmoji`] if the shortcode isn't known, rather than
+    /// pushing it as text.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .push_emoji_shortcode("white_check_mark")
+    ///     .unwrap()
+    ///     .push(" Done")
+    ///     .build();
+    ///
+    /// assert_eq!(content, "✅ Done");
+    ///
+    /// assert!(MessageBuilder::new().push_emoji_shortcode("white_check_mrak").is_err());
+    /// ```
+    ///
+    /// [`emoji_shortcode`]: fn.emoji_shortcode.html
+    /// [`UnknownEmoji`]: struct.UnknownEmoji.html
+    pub fn push_emoji_shortcode(mut self, name: &str) -> StdResult<Self, UnknownEmoji> {
+        match emoji_shortcode(name) {
+            Some(emoji) => {
+                self.0.push_str(emoji);
+
+                Ok(self)
+            },
+            None => Err(UnknownEmoji { name: name.to_string() }),
+        }
+    }
+
+    /// Pushes a mention of one of a guild's navigation screens to the internal
+    /// message content, such as `<id:customize>`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::{GuildNavigation, MessageBuilder};
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .push("Pick your roles in ")
+    ///     .mention_navigation(GuildNavigation::Customize)
+    ///     .build();
+    ///
+    /// assert_eq!(content, "Pick your roles in <id:customize>");
+    /// ```
+    pub fn mention_navigation(mut self, navigation: GuildNavigation) -> Self {
+        let _ = write!(self.0, "{}", navigation);
+
+        self
+    }
+
+    /// Pushes a timestamp tag to the internal message content, which Discord
+    /// displays in each viewer's own locale and timezone.
+    ///
+    /// The time may be given as seconds since the Unix epoch (negative for
+    /// times before it), or as any other type implementing [`IntoTimestamp`],
+    /// such as `SystemTime`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::{MessageBuilder, TimestampStyle};
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .push("Ends ")
+    ///     .push_timestamp(1700000000, TimestampStyle::Relative)
+    ///     .build();
+    ///
+    /// assert_eq!(content, "Ends <t:1700000000:R>");
+    /// ```
+    ///
+    /// Push the current time:
+    ///
+    /// ```rust
+    /// use serenity_utils::{MessageBuilder, TimestampStyle};
+    /// use std::time::SystemTime;
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .push("Sent at ")
+    ///     .push_timestamp(SystemTime::now(), TimestampStyle::LongTime)
+    ///     .build();
+    ///
+    /// assert!(content.starts_with("Sent at <t:"));
+    /// ```
+    ///
+    /// [`IntoTimestamp`]: trait.IntoTimestamp.html
+    pub fn push_timestamp<T: IntoTimestamp>(mut self, time: T, style: TimestampStyle) -> Self {
+        let _ = write!(self.0, "<t:{}:{}>", time.into_timestamp(), style);
+
+        self
+    }
+
+    /// Pushes a timestamp tag without a style to the internal message content,
+    /// which Discord displays as [`TimestampStyle::ShortDateTime`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new().push_timestamp_default(1700000000).build();
+    ///
+    /// assert_eq!(content, "<t:1700000000>");
+    /// ```
+    ///
+    /// [`TimestampStyle::ShortDateTime`]: enum.TimestampStyle.html#variant.ShortDateTime
+    pub fn push_timestamp_default<T: IntoTimestamp>(mut self, time: T) -> Self {
+        let _ = write!(self.0, "<t:{}>", time.into_timestamp());
+
+        self
+    }
+
+    /// Pushes a timestamp tag to the internal message content, displayed
+    /// relative to now, such as `3 hours ago`.
+    ///
+    /// Refer to [`push_timestamp`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new().push_relative(1700000000).build();
+    ///
+    /// assert_eq!(content, "<t:1700000000:R>");
+    /// ```
+    ///
+    /// [`push_timestamp`]: #method.push_timestamp
+    pub fn push_relative<T: IntoTimestamp>(self, time: T) -> Self {
+        self.push_timestamp(time, TimestampStyle::Relative)
+    }
+
+    /// Pushes a timestamp tag to the internal message content, displayed as
+    /// a short date, such as `11/28/2018`.
+    ///
+    /// Refer to [`push_timestamp`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new().push_date(1700000000).build();
+    ///
+    /// assert_eq!(content, "<t:1700000000:d>");
+    /// ```
+    ///
+    /// [`push_timestamp`]: #method.push_timestamp
+    pub fn push_date<T: IntoTimestamp>(self, time: T) -> Self {
+        self.push_timestamp(time, TimestampStyle::ShortDate)
+    }
+
+    /// Pushes a timestamp tag to the internal message content, displayed as
+    /// a long date and time, such as `Wednesday, November 28, 2018 4:20 PM`.
+    ///
+    /// Refer to [`push_timestamp`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new().push_datetime(1700000000).build();
+    ///
+    /// assert_eq!(content, "<t:1700000000:F>");
+    /// ```
+    ///
+    /// [`push_timestamp`]: #method.push_timestamp
+    pub fn push_datetime<T: IntoTimestamp>(self, time: T) -> Self {
+        self.push_timestamp(time, TimestampStyle::LongDateTime)
+    }
+
+    /// Pushes a bullet list to the internal message content, with each item on
+    /// its own `- ` line.
+    ///
+    /// The list always starts on a new line, so a newline is pushed first if
+    /// the message content doesn't already end with one. Nothing is pushed if
+    /// there are no items.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .push("Shopping:")
+    ///     .push_list(&["eggs", "milk"])
+    ///     .build();
+    ///
+    /// assert_eq!(content, "Shopping:\n- eggs\n- milk\n");
+    /// ```
+    pub fn push_list<I, T>(self, items: I) -> Self
+        where I: IntoIterator<Item = T>, T: AsRef<str> {
+        self.push_nested_list(items, 0)
+    }
+
+    /// Pushes a bullet list to the internal message content like
+    /// [`push_list`], indented by the given nesting level.
+    ///
+    /// Each level of nesting indents the items by two spaces, which Discord
+    /// renders as a sub-list of the items above it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .push_list(&["fruit"])
+    ///     .push_nested_list(&["apple", "pear"], 1)
+    ///     .build();
+    ///
+    /// assert_eq!(content, "- fruit\n  - apple\n  - pear\n");
+    /// ```
+    ///
+    /// [`push_list`]: #method.push_list
+    pub fn push_nested_list<I, T>(mut self, items: I, level: usize) -> Self
+        where I: IntoIterator<Item = T>, T: AsRef<str> {
+        let mut items = items.into_iter().peekable();
+
+        if items.peek().is_none() {
+            return self;
+        }
+
+        self.start_line();
+
+        for item in items {
+            for _ in 0..level {
+                self.0.push_str("  ");
+            }
+
+            self.0.push_str("- ");
+            self.0.push_str(item.as_ref());
+            self.0.push('\n');
+        }
+
+        self
+    }
+
+    /// Pushes a numbered list to the internal message content, with each item
+    /// on its own line, numbered from `1`.
+    ///
+    /// The list always starts on a new line, so a newline is pushed first if
+    /// the message content doesn't already end with one. Any further lines
+    /// within an item are indented to line up with the item's text, so that
+    /// they stay attached to its number. Nothing is pushed if there are no
+    /// items.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .push_numbered_list(&["first", "second\ncontinued"])
+    ///     .build();
+    ///
+    /// assert_eq!(content, "1. first\n2. second\n   continued\n");
+    /// ```
+    pub fn push_numbered_list<I, T>(self, items: I) -> Self
+        where I: IntoIterator<Item = T>, T: AsRef<str> {
+        self.push_numbered_list_from(items, 1)
+    }
+
+    /// Pushes a numbered list to the internal message content like
+    /// [`push_numbered_list`], numbered from the given value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .push_numbered_list_from(&["third", "fourth"], 3)
+    ///     .build();
+    ///
+    /// assert_eq!(content, "3. third\n4. fourth\n");
+    /// ```
+    ///
+    /// [`push_numbered_list`]: #method.push_numbered_list
+    pub fn push_numbered_list_from<I, T>(mut self, items: I, start: u64) -> Self
+        where I: IntoIterator<Item = T>, T: AsRef<str> {
+        let mut items = items.into_iter().peekable();
+
+        if items.peek().is_none() {
+            return self;
+        }
+
+        self.start_line();
+
+        let mut number = start;
+
+        for item in items {
+            let marker_len = self.0.len();
+            let _ = write!(self.0, "{}. ", number);
+            let indent = self.0.len() - marker_len;
+            let mut lines = item.as_ref().lines().peekable();
+
+            // An empty item still needs its own line, or the next item would
+            // follow its number on the same one.
+            if lines.peek().is_none() {
+                self.0.push('\n');
+            }
+
+            for (idx, line) in lines.enumerate() {
+                if idx > 0 {
+                    for _ in 0..indent {
+                        self.0.push(' ');
+                    }
+                }
+
+                self.0.push_str(line);
+                self.0.push('\n');
+            }
+
+            number = number.saturating_add(1);
+        }
+
+        self
+    }
+
+    /// Pushes each item to the internal message content, with the separator
+    /// between each of them.
+    ///
+    /// Items can be strings or any other value implementing [`Display`], and
+    /// are pushed without any formatting, as with [`push`]. Nothing is pushed
+    /// if there are no items.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .push("Winners: ")
+    ///     .push_join(&["alice", "bob", "carol"], ", ")
+    ///     .build();
+    ///
+    /// assert_eq!(content, "Winners: alice, bob, carol");
+    /// ```
+    ///
+    /// [`Display`]: https://doc.rust-lang.org/std/fmt/trait.Display.html
+    /// [`push`]: #method.push
+    pub fn push_join<I, D>(self, items: I, separator: &str) -> Self
+        where I: IntoIterator<Item = D>, D: Display {
+        self.push_join_last(items, separator, separator)
+    }
+
+    /// Pushes each item to the internal message content like [`push_join`],
+    /// but with a different separator before the last item.
+    ///
+    /// # Examples
+    ///
+    /// Join names in an English sentence, with an Oxford comma:
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .push_join_last(&["alice", "bob", "carol"], ", ", ", and ")
+    ///     .build();
+    ///
+    /// assert_eq!(content, "alice, bob, and carol");
+    ///
+    /// let content = MessageBuilder::new().push_join_last(&["alice"], ", ", ", and ").build();
+    ///
+    /// assert_eq!(content, "alice");
+    /// ```
+    ///
+    /// [`push_join`]: #method.push_join
+    pub fn push_join_last<I, D>(mut self, items: I, separator: &str, last: &str) -> Self
+        where I: IntoIterator<Item = D>, D: Display {
+        let mut items = items.into_iter().peekable();
+        let mut first = true;
+
+        while let Some(item) = items.next() {
+            if !first {
+                self.0.push_str(if items.peek().is_some() { separator } else { last });
+            }
+
+            let _ = write!(self.0, "{}", item);
+            first = false;
+        }
+
+        self
+    }
+
+    /// Pushes a mention of each item to the internal message content, with
+    /// the separator between each of them.
+    ///
+    /// Refer to [`mention`] for more information on mentioning items, and to
+    /// [`push_join`] for how they are joined.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::{MessageBuilder, ReactionType};
+    ///
+    /// let emoji = vec![ReactionType::from("👍"), ReactionType::from("👎")];
+    /// let content = MessageBuilder::new()
+    ///     .push("Vote with ")
+    ///     .mention_join(&emoji, " or ")
+    ///     .build();
+    ///
+    /// assert_eq!(content, "Vote with 👍 or 👎");
+    /// ```
+    ///
+    /// [`mention`]: #method.mention
+    /// [`push_join`]: #method.push_join
+    pub fn mention_join<I, M>(mut self, items: I, separator: &str) -> Self
+        where I: IntoIterator<Item = M>, M: Mentionable {
+        let mut first = true;
+
+        for item in items {
+            if !first {
+                self.0.push_str(separator);
+            }
+
+            let _ = write!(self.0, "{}", item.mention_display());
+            first = false;
+        }
+
+        self
+    }
+
+    /// Pushes a link to the internal message content, wrapped in angle
+    /// brackets so that Discord doesn't show an embed for it.
+    ///
+    /// A URL that is already wrapped in angle brackets isn't wrapped again.
+    /// Use [`push_link_embedded`] for a link that should be embedded.
+    ///
+    /// The URL must use the `http` or `https` scheme. If it doesn't, or it
+    /// contains whitespace or control characters, nothing is pushed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .push("Docs: ")
+    ///     .push_link("https://docs.rs/serenity")
+    ///     .push(", source: ")
+    ///     .push_link("<https://github.com/serenity-rs/serenity>")
+    ///     .push_link("not a link")
+    ///     .build();
+    ///
+    /// let expected = "Docs: <https://docs.rs/serenity>, \
+    ///                 source: <https://github.com/serenity-rs/serenity>";
+    ///
+    /// assert_eq!(content, expected);
+    /// ```
+    ///
+    /// [`push_link_embedded`]: #method.push_link_embedded
+    pub fn push_link(mut self, url: &str) -> Self {
+        let url = unwrap_angle_brackets(url);
+
+        if is_web_url(url) {
+            self.0.push('<');
+            self.push_bare_url(url);
+            self.0.push('>');
+        }
+
+        self
+    }
+
+    /// Pushes a link to the internal message content as-is, so that Discord
+    /// shows an embed for it.
+    ///
+    /// Angle brackets around the URL are removed. Use [`push_link`] for a
+    /// link that shouldn't be embedded.
+    ///
+    /// The URL must use the `http` or `https` scheme. If it doesn't, or it
+    /// contains whitespace or control characters, nothing is pushed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .push_link_embedded("<https://www.youtube.com/watch?v=dQw4w9WgXcQ>")
+    ///     .push_link_embedded("ftp://example.com")
+    ///     .build();
+    ///
+    /// assert_eq!(content, "https://www.youtube.com/watch?v=dQw4w9WgXcQ");
+    /// ```
+    ///
+    /// [`push_link`]: #method.push_link
+    pub fn push_link_embedded(mut self, url: &str) -> Self {
+        let url = unwrap_angle_brackets(url);
+
+        if is_web_url(url) {
+            self.push_bare_url(url);
+        }
+
+        self
+    }
+
+    /// Pushes a masked link to the internal message content, in the form of
+    /// `[name](url)`.
+    ///
+    /// Parentheses and spaces in the URL are percent-encoded, so that a URL
+    /// such as a Wikipedia article link doesn't end the link early.
+    ///
+    /// **Note**: Discord renders masked links in message content and in embed
+    /// descriptions and field values. Elsewhere, such as in embed titles and
+    /// field names, the markdown is displayed as-is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .push("See ")
+    ///     .push_named_link("Rust", "https://en.wikipedia.org/wiki/Rust_(programming_language)")
+    ///     .build();
+    ///
+    /// let expected = "See [Rust](https://en.wikipedia.org/wiki/Rust_%28programming_language%29)";
+    ///
+    /// assert_eq!(content, expected);
+    /// ```
+    pub fn push_named_link(mut self, name: &str, url: &str) -> Self {
+        self.0.push('[');
+        self.0.push_str(name);
+        self.0.push_str("](");
+        self.push_link_url(url);
+        self.0.push(')');
+
+        self
+    }
+
+    /// Pushes a masked link to the internal message content like
+    /// [`push_named_link`], but guards against the name altering the link.
+    ///
+    /// Brackets, parentheses, and backslashes in the name are escaped with a
+    /// backslash, so that user-provided text can't close the link text early
+    /// and point it somewhere else.
+    ///
+    /// The URL must use the `http` or `https` scheme. If it doesn't, or it
+    /// contains whitespace or control characters, only the escaped name is
+    /// pushed, without a link.
+    ///
+    /// # Examples
+    ///
+    /// A malicious name doesn't change where the link points:
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .push_named_link_safe("click me](https://evil.example)", "https://example.com")
+    ///     .build();
+    ///
+    /// assert_eq!(content, r"[click me\]\(https://evil.example\)](https://example.com)");
+    /// ```
+    ///
+    /// An invalid URL results in only the name being pushed:
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .push_named_link_safe("run me", "javascript:alert(1)")
+    ///     .build();
+    ///
+    /// assert_eq!(content, "run me");
+    /// ```
+    ///
+    /// [`push_named_link`]: #method.push_named_link
+    pub fn push_named_link_safe(mut self, name: &str, url: &str) -> Self {
+        let valid = is_web_url(url);
+
+        if valid {
+            self.0.push('[');
+        }
+
+        for c in name.chars() {
+            if let '[' | ']' | '(' | ')' | '\\' = c {
+                self.0.push('\\');
+            }
+
+            self.0.push(c);
+        }
+
+        if valid {
+            self.0.push_str("](");
+            self.push_link_url(url);
+            self.0.push(')');
+        }
+
+        self
+    }
+
+    /// Pushes content to the internal message content as a block quote,
+    /// terminating it with a newline so that following content starts on a
+    /// fresh line.
+    ///
+    /// Every line of the content is prefixed with `> `, so that multi-line
+    /// content is quoted in full. As the prefix only renders at the start of a
+    /// line, a newline is pushed first if the message content doesn't already
+    /// end with one.
+    ///
+    /// Nothing is pushed if the content is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .push_quote_line("what is\nthe time?")
+    ///     .push("It's noon.")
+    ///     .build();
+    ///
+    /// assert_eq!(content, "> what is\n> the time?\nIt's noon.");
+    /// ```
+    pub fn push_quote_line(mut self, content: &str) -> Self {
+        self.prefix_lines("> ", content);
+
+        self
+    }
+
+    /// Pushes content to the internal message content as subtext (`-# `),
+    /// which renders as small grey text, terminated with a newline.
+    ///
+    /// Every line of the content is prefixed with the marker. As the marker
+    /// only renders at the start of a line, a newline is pushed first if the
+    /// message content doesn't already end with one.
+    ///
+    /// Nothing is pushed if the content is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .push("Pong!")
+    ///     .push_subtext("requested by Zeyla")
+    ///     .build();
+    ///
+    /// assert_eq!(content, "Pong!\n-# requested by Zeyla\n");
+    /// ```
+    pub fn push_subtext(mut self, content: &str) -> Self {
+        self.prefix_lines("-# ", content);
+
+        self
+    }
+
+    /// Pushes content to the internal message content as a multi-line block
+    /// quote, using the `>>> ` marker.
+    ///
+    /// The marker only takes effect at the start of a line, so a newline is
+    /// pushed first if the message content doesn't already end with one.
+    ///
+    /// **Note**: Discord quotes _everything_ after the marker up to the end of
+    /// the message, so anything pushed after this will also be part of the
+    /// quote. This is usually the last push in a chain.
+    ///
+    /// Nothing is pushed if the content is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .push("You said:")
+    ///     .push_quote_block("first line\nsecond line")
+    ///     .build();
+    ///
+    /// assert_eq!(content, "You said:\n>>> first line\nsecond line");
+    /// ```
+    pub fn push_quote_block(mut self, content: &str) -> Self {
+        if content.is_empty() {
+            return self;
+        }
+
+        self.start_line();
+        self.0.push_str(">>> ");
+        self.0.push_str(content);
+
+        self
+    }
+
+    /// Pushes content to the internal message content as a large header
+    /// (`# `), terminated with a newline.
+    ///
+    /// Headers only render at the start of a line, so a newline is pushed
+    /// first if the message content doesn't already end with one.
+    ///
+    /// Nothing is pushed if the content is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .push("Announcement")
+    ///     .push_h1("Server maintenance")
+    ///     .push("Starting at noon.")
+    ///     .build();
+    ///
+    /// assert_eq!(content, "Announcement\n# Server maintenance\nStarting at noon.");
+    /// ```
+    pub fn push_h1(mut self, content: &str) -> Self {
+        self.header("# ", content);
+
+        self
+    }
+
+    /// Pushes content to the internal message content as a medium header
+    /// (`## `), terminated with a newline.
+    ///
+    /// Refer to [`push_h1`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new().push_h2("Rules").build();
+    ///
+    /// assert_eq!(content, "## Rules\n");
+    /// ```
+    ///
+    /// [`push_h1`]: #method.push_h1
+    pub fn push_h2(mut self, content: &str) -> Self {
+        self.header("## ", content);
+
+        self
+    }
+
+    /// Pushes content to the internal message content as a small header
+    /// (`### `), terminated with a newline.
+    ///
+    /// Refer to [`push_h1`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new().push_h3("Details").build();
+    ///
+    /// assert_eq!(content, "### Details\n");
+    /// ```
+    ///
+    /// [`push_h1`]: #method.push_h1
+    pub fn push_h3(mut self, content: &str) -> Self {
+        self.header("### ", content);
+
+        self
+    }
+
+    /// Pushes a line to the internal message content reporting that an
+    /// action succeeded, prefixed with `✅`.
+    ///
+    /// Refer to [`push_status`] for more information, and to push the line
+    /// with a different icon. Use [`push_success_with`] to push it with the icon
+    /// of a set of [`StatusIcons`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new().push_success("Done").build();
+    ///
+    /// assert_eq!(content, "✅ Done\n");
+    /// ```
+    ///
+    /// [`StatusIcons`]: struct.StatusIcons.html
+    /// [`push_success_with`]: #method.push_success_with
+    /// [`push_status`]: #method.push_status
+    pub fn push_success(self, content: &str) -> Self { self.push_status(status::SUCCESS, content) }
+
+    /// Pushes a line to the internal message content reporting that an
+    /// action succeeded, like [`push_success`], prefixed with the success
+    /// icon of the given set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::{MessageBuilder, ReactionType, StatusIcons};
+    ///
+    /// let icons = StatusIcons {
+    ///     success: ReactionType::from("👍"),
+    ///     ..StatusIcons::default()
+    /// };
+    /// let content = MessageBuilder::new().push_success_with(&icons, "Done").build();
+    ///
+    /// assert_eq!(content, "👍 Done\n");
+    /// ```
+    ///
+    /// [`push_success`]: #method.push_success
+    pub fn push_success_with(self, icons: &StatusIcons, content: &str) -> Self {
+        self.push_status(&icons.success, content)
+    }
+
+    /// Pushes a line to the internal message content with a warning, prefixed
+    /// with `⚠️`.
+    ///
+    /// Refer to [`push_status`] for more information, and to push the line
+    /// with a different icon. Use [`push_warning_with`] to push it with the icon
+    /// of a set of [`StatusIcons`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new().push_warning("Careful").build();
+    ///
+    /// assert_eq!(content, "⚠\u{FE0F} Careful\n");
+    /// ```
+    ///
+    /// [`StatusIcons`]: struct.StatusIcons.html
+    /// [`push_warning_with`]: #method.push_warning_with
+    /// [`push_status`]: #method.push_status
+    pub fn push_warning(self, content: &str) -> Self { self.push_status(status::WARNING, content) }
+
+    /// Pushes a line to the internal message content with a warning, like
+    /// [`push_warning`], prefixed with the warning icon of the given set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::{MessageBuilder, ReactionType, StatusIcons};
+    ///
+    /// let icons = StatusIcons {
+    ///     warning: ReactionType::from("🟡"),
+    ///     ..StatusIcons::default()
+    /// };
+    /// let content = MessageBuilder::new().push_warning_with(&icons, "Careful").build();
+    ///
+    /// assert_eq!(content, "🟡 Careful\n");
+    /// ```
+    ///
+    /// [`push_warning`]: #method.push_warning
+    pub fn push_warning_with(self, icons: &StatusIcons, content: &str) -> Self {
+        self.push_status(&icons.warning, content)
+    }
+
+    /// Pushes a line to the internal message content reporting that an
+    /// action failed, prefixed with `❌`.
+    ///
+    /// Refer to [`push_status`] for more information, and to push the line
+    /// with a different icon. Use [`push_error_with`] to push it with the icon
+    /// of a set of [`StatusIcons`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new().push_error("Failed").build();
+    ///
+    /// assert_eq!(content, "❌ Failed\n");
+    /// ```
+    ///
+    /// [`StatusIcons`]: struct.StatusIcons.html
+    /// [`push_error_with`]: #method.push_error_with
+    /// [`push_status`]: #method.push_status
+    pub fn push_error(self, content: &str) -> Self { self.push_status(status::ERROR, content) }
+
+    /// Pushes a line to the internal message content reporting that an
+    /// action failed, like [`push_error`], prefixed with the error icon of
+    /// the given set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::{MessageBuilder, ReactionType, StatusIcons};
+    ///
+    /// let icons = StatusIcons {
+    ///     error: ReactionType::from("🔥"),
+    ///     ..StatusIcons::default()
+    /// };
+    /// let content = MessageBuilder::new().push_error_with(&icons, "Failed").build();
+    ///
+    /// assert_eq!(content, "🔥 Failed\n");
+    /// ```
+    ///
+    /// [`push_error`]: #method.push_error
+    pub fn push_error_with(self, icons: &StatusIcons, content: &str) -> Self {
+        self.push_status(&icons.error, content)
+    }
+
+    /// Pushes a status line to the internal message content: the given icon,
+    /// a space, and the content, terminated with a newline.
+    ///
+    /// The icon can be any emoji or string, such as one of the
+    /// [`StatusIcons`]. As the status is its own line, a newline is pushed
+    /// first if the message content doesn't already end with one.
+    ///
+    /// Nothing is pushed if the content is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::{MessageBuilder, StatusIcons};
+    ///
+    /// let icons = StatusIcons::default();
+    /// let content = MessageBuilder::new()
+    ///     .push("Deploying...")
+    ///     .push_status(&icons.error, "Build failed")
+    ///     .push_status("🔁", "Retrying")
+    ///     .build();
+    ///
+    /// assert_eq!(content, "Deploying...\n❌ Build failed\n🔁 Retrying\n");
+    /// ```
+    ///
+    /// [`StatusIcons`]: struct.StatusIcons.html
+    pub fn push_status<D: Display>(mut self, icon: D, content: &str) -> Self {
+        if content.is_empty() {
+            return self;
+        }
+
+        self.start_line();
+        let _ = writeln!(self.0, "{} {}", icon, content);
+
+        self
+    }
+
+    /// Pushes a progress bar to the internal message content, in the default
+    /// [`ProgressStyle`], such as `▰▰▰▰▱▱▱▱ 52%`.
+    ///
+    /// Refer to [`push_progress_bar_styled`] for more information, and to push
+    /// a bar in another style.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .push("Downloading ")
+    ///     .push_progress_bar(52.0, 100.0, 8)
+    ///     .build();
+    ///
+    /// assert_eq!(content, "Downloading ▰▰▰▰▱▱▱▱ 52%");
+    /// ```
+    ///
+    /// [`ProgressStyle`]: struct.ProgressStyle.html
+    /// [`push_progress_bar_styled`]: #method.push_progress_bar_styled
+    pub fn push_progress_bar(self, value: f64, max: f64, width: usize) -> Self {
+        self.push_progress_bar_styled(value, max, width, &ProgressStyle::default())
+    }
+
+    /// Pushes a progress bar of `width` characters to the internal message
+    /// content, filled in proportion to how much of `max` the `value` is.
+    ///
+    /// The value is clamped to between `0` and `max`, and a `max` of zero or
+    /// less is shown as no progress. Both the bar and the percentage are
+    /// rounded down, so that a bar is only shown as full, or at 100%, once the
+    /// value reaches the maximum.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::{MessageBuilder, ProgressStyle};
+    ///
+    /// let style = ProgressStyle {
+    ///     brackets: Some(('[', ']')),
+    ///     ..ProgressStyle::default()
+    /// };
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .push_progress_bar_styled(99.9, 100.0, 4, &style)
+    ///     .build();
+    ///
+    /// assert_eq!(content, "[▰▰▰▱] 99%");
+    /// ```
+    pub fn push_progress_bar_styled(mut self, value: f64, max: f64, width: usize,
+                                    style: &ProgressStyle) -> Self {
+        let ratio = progress::ratio(value, max);
+        let filled = progress::scale(ratio, width);
+
+        if let Some((open, _)) = style.brackets {
+            self.0.push(open);
+        }
+
+        for idx in 0..width {
+            self.0.push(if idx < filled { style.filled } else { style.empty });
+        }
+
+        if let Some((_, close)) = style.brackets {
+            self.0.push(close);
+        }
+
+        if style.percentage {
+            let _ = write!(self.0, " {}%", progress::scale(ratio, 100));
+        }
+
+        self
+    }
+
+    /// Pushes a duration to the internal message content in the default
+    /// [`DurationStyle`], such as `2d 4h 13m`.
+    ///
+    /// Refer to [`push_duration_styled`] for more information, and to push a
+    /// duration in another style.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    /// use std::time::Duration;
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .push("Uptime: ")
+    ///     .push_duration(Duration::from_secs(188_000))
+    ///     .build();
+    ///
+    /// assert_eq!(content, "Uptime: 2d 4h 13m 20s");
+    /// ```
+    ///
+    /// [`DurationStyle`]: struct.DurationStyle.html
+    /// [`push_duration_styled`]: #method.push_duration_styled
+    pub fn push_duration(self, duration: Duration) -> Self {
+        self.push_duration_styled(duration, &DurationStyle::default())
+    }
+
+    /// Pushes a duration to the internal message content, in days, hours,
+    /// minutes, and seconds.
+    ///
+    /// Units which are zero are left out, starting from the largest unit which
+    /// isn't, and only up to the style's maximum number of components are
+    /// pushed. Smaller units, and any part of a second, are dropped rather than
+    /// rounded. A duration of less than a second is pushed as `0s`, or
+    /// `0 seconds` in the long style, rather than as nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::{DurationStyle, MessageBuilder};
+    /// use std::time::Duration;
+    ///
+    /// let style = DurationStyle {
+    ///     long: true,
+    ///     ..DurationStyle::default()
+    /// };
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .push("Try again in ")
+    ///     .push_duration_styled(Duration::from_secs(190), &style)
+    ///     .build();
+    ///
+    /// assert_eq!(content, "Try again in 3 minutes, 10 seconds");
+    /// ```
+    pub fn push_duration_styled(mut self, duration: Duration, style: &DurationStyle) -> Self {
+        duration::write_duration(&mut self.0, duration, style);
+
+        self
+    }
+
+    /// Pushes a number of bytes to the internal message content in the default
+    /// [`ByteStyle`], such as `3.4 MiB`.
+    ///
+    /// Refer to [`push_bytes_styled`] for more information, and to push a size
+    /// in another style.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .push("Attachment: ")
+    ///     .push_bytes(3_565_158)
+    ///     .build();
+    ///
+    /// assert_eq!(content, "Attachment: 3.4 MiB");
+    /// ```
+    ///
+    /// [`ByteStyle`]: struct.ByteStyle.html
+    /// [`push_bytes_styled`]: #method.push_bytes_styled
+    pub fn push_bytes(self, bytes: u64) -> Self {
+        self.push_bytes_styled(bytes, &ByteStyle::default())
+    }
+
+    /// Pushes a number of bytes to the internal message content, in the
+    /// largest unit the size is at least one of.
+    ///
+    /// Sizes under a kilobyte are pushed as a whole number of bytes, such as
+    /// `1023 B`. Larger sizes are rounded to the style's number of decimal
+    /// places, and if that rounds up to a whole one of the next unit, they're
+    /// pushed in it instead, so that a size just under a mebibyte is pushed as
+    /// `1.0 MiB` rather than `1024.0 KiB`. Exbibytes, or exabytes, are the
+    /// largest unit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::{ByteStyle, MessageBuilder};
+    ///
+    /// let style = ByteStyle {
+    ///     binary: false,
+    ///     decimals: 0,
+    /// };
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .push_bytes_styled(999, &style)
+    ///     .push(", ")
+    ///     .push_bytes_styled(1_500_000_000, &style)
+    ///     .build();
+    ///
+    /// assert_eq!(content, "999 B, 2 GB");
+    /// ```
+    pub fn push_bytes_styled(mut self, bytes: u64, style: &ByteStyle) -> Self {
+        bytes::write_bytes(&mut self.0, bytes, style);
+
+        self
+    }
+
+    /// Pushes a number to the internal message content, with its digits
+    /// grouped in threes by commas, such as `1,234,567`.
+    ///
+    /// Refer to [`push_number_separated`] to group digits with another
+    /// separator.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .push_number(1_234_567u32)
+    ///     .push(" members")
+    ///     .build();
+    ///
+    /// assert_eq!(content, "1,234,567 members");
+    /// ```
+    ///
+    /// [`push_number_separated`]: #method.push_number_separated
+    pub fn push_number<N: Into<i128>>(self, number: N) -> Self {
+        self.push_number_separated(number, ',')
+    }
+
+    /// Pushes a number to the internal message content, with its digits
+    /// grouped in threes by the given separator.
+    ///
+    /// Many locales group digits with `.` or a thin space (`\u{2009}`)
+    /// rather than a comma. A negative number is prefixed with `-`, which is
+    /// not separated from its digits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .push_number_separated(-1_234_567i64, '.')
+    ///     .push(" / ")
+    ///     .push_number_separated(9_876u16, '\u{2009}')
+    ///     .build();
+    ///
+    /// assert_eq!(content, "-1.234.567 / 9\u{2009}876");
+    /// ```
+    pub fn push_number_separated<N: Into<i128>>(mut self, number: N, separator: char) -> Self {
+        let number = number.into();
+
+        if number < 0 {
+            self.0.push('-');
+        }
+
+        let digits = number.unsigned_abs().to_string();
+
+        for (idx, digit) in digits.chars().enumerate() {
+            if idx > 0 && (digits.len() - idx) % 3 == 0 {
+                self.0.push(separator);
+            }
+
+            self.0.push(digit);
+        }
+
+        self
+    }
+
+    fn header(&mut self, marker: &str, content: &str) {
+        if content.is_empty() {
+            return;
+        }
+
+        self.start_line();
+        self.0.push_str(marker);
+        self.0.push_str(content);
+        self.0.push('\n');
+    }
+
+    fn push_key(&mut self, key: &str) {
+        self.0.push_str("**");
+        escape_markdown(&mut self.0, key);
+        self.0.push_str(":** ");
+    }
+
+    fn push_link_url(&mut self, url: &str) {
+        for c in url.chars() {
+            match c {
+                '(' => self.0.push_str("%28"),
+                ')' => self.0.push_str("%29"),
+                ' ' => self.0.push_str("%20"),
+                c => self.0.push(c),
+            }
+        }
+    }
+
+    fn push_bare_url(&mut self, url: &str) {
+        for c in url.chars() {
+            match c {
+                '<' => self.0.push_str("%3C"),
+                '>' => self.0.push_str("%3E"),
+                c => self.0.push(c),
+            }
+        }
+    }
+
+    fn prefix_lines(&mut self, prefix: &str, content: &str) {
+        if content.is_empty() {
+            return;
+        }
+
+        self.start_line();
+
+        for line in content.lines() {
+            self.0.push_str(prefix);
+            self.0.push_str(line);
+            self.0.push('\n');
+        }
+    }
+
+    /// Checks that content of the given length can be pushed without going
+    /// over the limit.
+    fn check_fits(&self, needed: usize) -> StdResult<(), ContentFull> {
+        let length = self.len();
+
+        if length + needed <= MESSAGE_CODE_LIMIT {
+            Ok(())
+        } else {
+            Err(ContentFull {
+                needed,
+                remaining: MESSAGE_CODE_LIMIT.saturating_sub(length),
+            })
+        }
+    }
+
+    fn check_length(&self) -> StdResult<(), MessageTooLong> {
+        let length = self.len();
+
+        if length > MESSAGE_CODE_LIMIT {
+            Err(MessageTooLong {
+                length,
+                limit: MESSAGE_CODE_LIMIT,
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Pushes a newline if the content is not empty and not already at the
+    /// start of a line.
+    fn start_line(&mut self) {
+        if !self.0.is_empty() && !self.0.ends_with('\n') {
+            self.0.push('\n');
+        }
+    }
+
+    fn wrap(&mut self, marker: &str, content: &str) {
+        if content.is_empty() {
+            return;
+        }
+
+        if self.0.ends_with(&marker[..1]) {
+            self.0.push(ZERO_WIDTH_SPACE);
+        }
+
+        self.0.push_str(marker);
+        self.0.push_str(content);
+        self.0.push_str(marker);
+    }
+
+    /// Pushes content wrapped in each of the given markers, outermost first,
+    /// the same as `wrap` does for a single marker.
+    fn push_marked(&mut self, markers: &[&str; 6], content: &str) {
+        if content.is_empty() {
+            return;
+        }
+
+        if let Some(marker) = markers.iter().find(|marker| !marker.is_empty()) {
+            if self.0.ends_with(&marker[..1]) {
+                self.0.push(ZERO_WIDTH_SPACE);
+            }
+        }
+
+        for marker in markers {
+            self.0.push_str(marker);
+        }
+
+        self.0.push_str(content);
+
+        for marker in markers.iter().rev() {
+            self.0.push_str(marker);
+        }
+    }
+
+    /// Pushes content, inserting a zero-width space between every two
+    /// neighbouring occurrences of `ch`.
+    fn push_runs_separated(&mut self, content: &str, ch: char) {
+        let mut prev = None;
+
+        for c in content.chars() {
+            if c == ch && prev == Some(ch) {
+                self.0.push(ZERO_WIDTH_SPACE);
+            }
+
+            self.0.push(c);
+            prev = Some(c);
+        }
+    }
+
+    /// Pushes content, breaking up every run of three or more backticks with
+    /// zero-width spaces so that none of them form a code fence.
+    fn push_fences_separated(&mut self, content: &str) {
+        let mut run = 0;
+
+        for c in content.chars() {
+            if c == '`' {
+                if run == 2 {
+                    self.0.push(ZERO_WIDTH_SPACE);
+                    run = 0;
+                }
+
+                run += 1;
+            } else {
+                run = 0;
+            }
+
+            self.0.push(c);
+        }
+    }
+
+    /// Pushes content, inserting a zero-width space between every two
+    /// neighbouring occurrences of `ch`, and between `ch` and the markers
+    /// surrounding the content.
+    fn push_separated(&mut self, content: &str, ch: char) {
+        let mut prev = Some(ch);
+
+        for c in content.chars() {
+            if c == ch && prev == Some(ch) {
+                self.0.push(ZERO_WIDTH_SPACE);
+            }
+
+            self.0.push(c);
+            prev = Some(c);
+        }
+
+        if prev == Some(ch) {
+            self.0.push(ZERO_WIDTH_SPACE);
+        }
+    }
+}
+
+/// A markdown modifier that can be applied to [`Content`].
+///
+/// Modifiers can be added to each other and to a string to build up
+/// [`Content`], which can then be pushed via [`MessageBuilder::push_content`].
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity_utils::ContentModifier::*;
+/// use serenity_utils::MessageBuilder;
+///
+/// let content = MessageBuilder::new()
+///     .push_content(Underline + Spoiler + "plot twist")
+///     .build();
+///
+/// assert_eq!(content, "||__plot twist__||");
+/// ```
+///
+/// [`Content`]: struct.Content.html
+/// [`MessageBuilder::push_content`]: struct.MessageBuilder.html#method.push_content
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ContentModifier {
+    Bold,
+    Italic,
+    Underline,
+    Strikethrough,
+    Spoiler,
+    Code,
+}
+
+/// A string along with the set of markdown modifiers to wrap it in.
+///
+/// Regardless of the order in which modifiers are added, they are always
+/// nested in the same order, from outermost to innermost: spoiler,
+/// underline, strikethrough, bold, italic, and code. This means that bold and
+/// italic content is rendered as `***content***`. Code is innermost, as
+/// markdown inside of a code span is not rendered.
+///
+/// Nothing is rendered if the string is empty.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity_utils::ContentModifier::*;
+///
+/// let content = Code + Bold + Strikethrough + "old_name";
+///
+/// assert_eq!(content.to_string(), "~~**`old_name`**~~");
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Content {
+    pub bold: bool,
+    pub italic: bool,
+    pub underline: bool,
+    pub strikethrough: bool,
+    pub spoiler: bool,
+    pub code: bool,
+    pub inner: String,
+}
+
+impl Content {
+    /// Applies a modifier to the content.
+    pub fn apply(&mut self, modifier: ContentModifier) {
+        use self::ContentModifier::*;
+
+        match modifier {
+            Bold => self.bold = true,
+            Italic => self.italic = true,
+            Underline => self.underline = true,
+            Strikethrough => self.strikethrough = true,
+            Spoiler => self.spoiler = true,
+            Code => self.code = true,
+        }
+    }
+
+    /// The markers of the content, from outermost to innermost, with the
+    /// markers of modifiers that aren't applied being empty.
+    fn markers(&self) -> [&'static str; 6] {
+        let marker = |applied, marker| if applied { marker } else { "" };
+
+        [
+            marker(self.spoiler, "||"),
+            marker(self.underline, "__"),
+            marker(self.strikethrough, "~~"),
+            marker(self.bold, "**"),
+            marker(self.italic, "*"),
+            marker(self.code, "`"),
+        ]
+    }
+}
+
+impl Display for Content {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        if self.inner.is_empty() {
+            return Ok(());
+        }
+
+        let markers = self.markers();
+
+        for marker in &markers {
+            f.write_str(marker)?;
+        }
+
+        f.write_str(&self.inner)?;
+
+        for marker in markers.iter().rev() {
+            f.write_str(marker)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> From<&'a str> for Content {
+    fn from(inner: &'a str) -> Content {
+        Content {
+            inner: inner.to_string(),
+            ..Content::default()
+        }
+    }
+}
+
+impl From<String> for Content {
+    fn from(inner: String) -> Content {
+        Content {
+            inner,
+            ..Content::default()
+        }
+    }
+}
+
+impl From<ContentModifier> for Content {
+    fn from(modifier: ContentModifier) -> Content {
+        let mut content = Content::default();
+        content.apply(modifier);
+
+        content
+    }
+}
+
+impl Add<ContentModifier> for Content {
+    type Output = Content;
+
+    fn add(mut self, modifier: ContentModifier) -> Content {
+        self.apply(modifier);
+
+        self
+    }
+}
+
+impl<'a> Add<&'a str> for Content {
+    type Output = Content;
+
+    fn add(mut self, inner: &'a str) -> Content {
+        self.inner.push_str(inner);
+
+        self
+    }
+}
+
+impl Add<ContentModifier> for ContentModifier {
+    type Output = Content;
+
+    fn add(self, modifier: ContentModifier) -> Content { Content::from(self) + modifier }
+}
+
+impl<'a> Add<&'a str> for ContentModifier {
+    type Output = Content;
+
+    fn add(self, inner: &'a str) -> Content { Content::from(self) + inner }
+}
+
+/// A mark of a builder's message content, as returned by
+/// [`MessageBuilder::checkpoint`].
+///
+/// The checkpoint remembers the content it was taken at, so that rolling back
+/// to it once that content has changed is an error rather than leaving
+/// something other than what was there.
+///
+/// [`MessageBuilder::checkpoint`]: struct.MessageBuilder.html#method.checkpoint
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Checkpoint {
+    end: usize,
+    hash: u64,
+}
+
+/// A segment of a builder's message content, as returned by
+/// [`MessageBuilder::segments`].
+///
+/// [`MessageBuilder::segments`]: struct.MessageBuilder.html#method.segments
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Segment<'a> {
+    /// What the segment is.
+    pub kind: SegmentKind,
+    /// The content of the segment, exactly as it is in the message.
+    pub content: &'a str,
+    /// The byte range of the segment within the message content.
+    pub range: Range<usize>,
+}
+
+impl<'a> Segment<'a> {
+    /// Returns the length of the segment, as counted by Discord in the same
+    /// way as [`MessageBuilder::len`].
+    ///
+    /// [`MessageBuilder::len`]: struct.MessageBuilder.html#method.len
+    pub fn len(&self) -> usize { discord_len(self.content) }
+
+    /// Whether the segment is empty, which it never is when returned by
+    /// [`MessageBuilder::segments`].
+    ///
+    /// [`MessageBuilder::segments`]: struct.MessageBuilder.html#method.segments
+    pub fn is_empty(&self) -> bool { self.content.is_empty() }
+}
+
+/// The kinds of [`Segment`].
+///
+/// [`Segment`]: struct.Segment.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum SegmentKind {
+    /// Text with no formatting, which doesn't span more than one line.
+    Text,
+    /// A tag, such as a mention of a user, a custom emoji, or a timestamp.
+    Mention,
+    /// Styled text, such as bold text or inline code.
+    Styled,
+    /// A code block.
+    CodeBlock,
+    /// A newline outside of a code block.
+    LineBreak,
+}
+
+/// A set of markdown styles, for use with [`MessageBuilder::push_styled`].
+///
+/// Styles can be combined with the `|` operator.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity_utils::Styles;
+///
+/// let styles = Styles::BOLD | Styles::ITALIC;
+///
+/// assert!(styles.contains(Styles::BOLD));
+/// assert!(!styles.contains(Styles::CODE));
+/// ```
+///
+/// [`MessageBuilder::push_styled`]: struct.MessageBuilder.html#method.push_styled
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Styles(u8);
+
+impl Styles {
+    /// Bold markdown (`**`).
+    pub const BOLD: Styles = Styles(1);
+    /// Italic markdown (`*`).
+    pub const ITALIC: Styles = Styles(1 << 1);
+    /// Underline markdown (`__`).
+    pub const UNDERLINE: Styles = Styles(1 << 2);
+    /// Strikethrough markdown (`~~`).
+    pub const STRIKETHROUGH: Styles = Styles(1 << 3);
+    /// Spoiler markdown (`||`).
+    pub const SPOILER: Styles = Styles(1 << 4);
+    /// Inline code markdown (`` ` ``).
+    pub const CODE: Styles = Styles(1 << 5);
+
+    /// Creates an empty set of styles.
+    pub fn empty() -> Styles { Styles(0) }
+
+    /// Whether no styles are set.
+    pub fn is_empty(&self) -> bool { self.0 == 0 }
+
+    /// Whether all of the given styles are set.
+    pub fn contains(&self, other: Styles) -> bool { self.0 & other.0 == other.0 }
+}
+
+impl BitOr for Styles {
+    type Output = Styles;
+
+    fn bitor(self, other: Styles) -> Styles { Styles(self.0 | other.0) }
+}
+
+impl BitOrAssign for Styles {
+    fn bitor_assign(&mut self, other: Styles) { self.0 |= other.0; }
+}
+
+/// Characters with a meaning in Discord markdown, escaped by
+/// [`MessageBuilder::push_safe`].
+///
+/// [`MessageBuilder::push_safe`]: struct.MessageBuilder.html#method.push_safe
+const MARKDOWN_CHARS: &[char] = &['*', '_', '~', '|', '`', '>'];
+
+/// Parses an Id given either by itself or as a mention, ignoring surrounding
+/// whitespace.
+fn id_or_mention(id: &str, parse_mention: fn(&str) -> Option<u64>) -> StdResult<u64, ParseIdError> {
+    match parse_id(id.trim()).or_else(|| parse_mention(id)) {
+        Some(id) => Ok(id),
+        None => Err(ParseIdError {
+            input: id.to_string(),
+        }),
+    }
+}
+
+/// Whether a URL uses the `http` or `https` scheme and has no whitespace or
+/// control characters that would end it early.
+fn is_web_url(url: &str) -> bool {
+    let rest = match url.strip_prefix("https://").or_else(|| url.strip_prefix("http://")) {
+        Some(rest) => rest,
+        None => return false,
+    };
+
+    !rest.is_empty() && !url.chars().any(|c| c.is_whitespace() || c.is_control())
+}
+
+/// Removes one pair of angle brackets from around a URL, if present.
+fn unwrap_angle_brackets(url: &str) -> &str {
+    if url.len() >= 2 && url.starts_with('<') && url.ends_with('>') {
+        &url[1..url.len() - 1]
+    } else {
+        url
+    }
+}
+
+/// Hashes message content, to tell whether it has changed since a checkpoint.
+fn hash_content(content: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    content.hash(&mut hasher);
+
+    hasher.finish()
+}
+
+fn escape_markdown(buf: &mut String, content: &str) {
+    let mut chars = content.char_indices().peekable();
+
+    while let Some((idx, c)) = chars.next() {
+        if c == '\\' {
+            let mut run = 1;
+            buf.push(c);
+
+            while let Some(&(_, '\\')) = chars.peek() {
+                buf.push('\\');
+                chars.next();
+                run += 1;
+            }
+
+            // Only an odd run of backslashes escapes the character after it,
+            // as each pair is an escaped backslash.
+            if let Some(&(_, next)) = chars.peek() {
+                if run % 2 == 1 && MARKDOWN_CHARS.contains(&next) {
+                    buf.push(next);
+                    chars.next();
+                }
+            }
+
+            continue;
+        }
+
+        if MARKDOWN_CHARS.contains(&c) {
+            buf.push('\\');
+        }
+
+        buf.push(c);
+
+        if c == '@' && is_mass_mention(&content[idx + 1..]) {
+            buf.push(ZERO_WIDTH_SPACE);
+        }
+    }
+}
+
+/// Pushes content, breaking up code fences, mass mentions, and user, role,
+/// and channel mentions with zero-width spaces.
+fn defuse(buf: &mut String, content: &str) {
+    let mut prev = None;
+
+    for (idx, c) in content.char_indices() {
+        if c == '`' && prev == Some('`') {
+            buf.push(ZERO_WIDTH_SPACE);
+        }
+
+        buf.push(c);
+
+        let rest = &content[idx + c.len_utf8()..];
+
+        if (c == '@' && is_mass_mention(rest))
+            || (c == '<' && (rest.starts_with('@') || rest.starts_with('#'))) {
+            buf.push(ZERO_WIDTH_SPACE);
+        }
+
+        prev = Some(c);
+    }
+}
+
+/// A [`MessageBuilder`] which is allowed to ping `@everyone` and `@here`, as
+/// returned by [`MessageBuilder::allow_mass_mentions`].
+///
+/// Once the mass mentions have been pushed, the [`MessageBuilder`] can be
+/// taken back out with [`into_builder`] to carry on building the message.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity_utils::MessageBuilder;
+///
+/// let content = MessageBuilder::new()
+///     .push("Hey ")
+///     .allow_mass_mentions()
+///     .mention_here()
+///     .build();
+///
+/// assert_eq!(content, "Hey @here");
+/// ```
+///
+/// [`MessageBuilder`]: struct.MessageBuilder.html
+/// [`MessageBuilder::allow_mass_mentions`]: struct.MessageBuilder.html#method.allow_mass_mentions
+/// [`into_builder`]: #method.into_builder
+#[derive(Clone, Debug)]
+pub struct MassMentions(MessageBuilder);
+
+impl MassMentions {
+    /// Pushes a mention of `@everyone`, which pings every member who can see
+    /// the channel.
+    pub fn mention_everyone(mut self) -> Self {
+        (self.0).0.push_str("@everyone");
+
+        self
+    }
+
+    /// Pushes a mention of `@here`, which pings every online member who can
+    /// see the channel.
+    pub fn mention_here(mut self) -> Self {
+        (self.0).0.push_str("@here");
+
+        self
+    }
+
+    /// Returns the [`MessageBuilder`], to push further content to it. Mass
+    /// mentions pushed to it afterwards are broken up again.
+    ///
+    /// [`MessageBuilder`]: struct.MessageBuilder.html
+    pub fn into_builder(self) -> MessageBuilder { self.0 }
+
+    /// Pulls the inner value out of the builder, as by
+    /// [`MessageBuilder::build`].
+    ///
+    /// [`MessageBuilder::build`]: struct.MessageBuilder.html#method.build
+    pub fn build(self) -> String { self.0.build() }
+}
+
+impl From<MassMentions> for MessageBuilder {
+    fn from(builder: MassMentions) -> MessageBuilder { builder.0 }
+}
+
+impl Display for MessageBuilder {
+    /// Formats the message builder into a string.
+    ///
+    /// This is done by simply taking the internal value of the tuple-struct
+    /// and writing it into the formatter.
+    fn fmt(&self, f: &mut Formatter) -> FmtResult { Display::fmt(self.as_str(), f) }
+}
+
+/// Serializes the builder as its message content, a plain string.
+#[cfg(feature = "serde")]
+impl Serialize for MessageBuilder {
+    fn serialize<S: Serializer>(&self, serializer: S) -> StdResult<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+/// Deserializes a builder from its message content, a plain string.
+///
+/// Like [`MessageBuilder::try_build`], this fails if the content is longer
+/// than [`MESSAGE_CODE_LIMIT`].
+///
+/// [`MESSAGE_CODE_LIMIT`]: constant.MESSAGE_CODE_LIMIT.html
+/// [`MessageBuilder::try_build`]: struct.MessageBuilder.html#method.try_build
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for MessageBuilder {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<MessageBuilder, D::Error> {
+        let builder = MessageBuilder(String::deserialize(deserializer)?);
+        builder.check_length().map_err(DeError::custom)?;
+
+        Ok(builder)
+    }
+}
+
+impl AsRef<str> for MessageBuilder {
+    fn as_ref(&self) -> &str { self.as_str() }
+}
+
+/// Dereferences to the message content built so far, so that `str` methods
+/// can be used to inspect it.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity_utils::MessageBuilder;
+///
+/// let builder = MessageBuilder::new().push("Hello, ").push_bold("world");
+///
+/// assert!(builder.starts_with("Hello"));
+/// assert!(builder.contains("**world**"));
+/// ```
+impl Deref for MessageBuilder {
+    type Target = str;
+
+    fn deref(&self) -> &str { self.as_str() }
+}
+
+impl PartialEq<str> for MessageBuilder {
+    fn eq(&self, other: &str) -> bool { self.as_str() == other }
+}
+
+impl<'a> PartialEq<&'a str> for MessageBuilder {
+    fn eq(&self, other: &&'a str) -> bool { self.as_str() == *other }
+}
+
+impl PartialEq<String> for MessageBuilder {
+    fn eq(&self, other: &String) -> bool { self.as_str() == other }
+}
+
+/// Creates a builder which continues from existing content.
+///
+/// The content isn't checked against [`MESSAGE_CODE_LIMIT`]; use
+/// [`MessageBuilder::try_from_content`] to check it up front, or
+/// [`MessageBuilder::try_build`] once done to check the final content.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity_utils::MessageBuilder;
+///
+/// let prefix = String::from("Hello, ");
+/// let content = MessageBuilder::from(prefix).push_bold("world").build();
+///
+/// assert_eq!(content, "Hello, **world**");
+///
+/// let long = MessageBuilder::from("a".repeat(2001));
+///
+/// assert!(long.try_build().is_err());
+/// ```
+///
+/// [`MESSAGE_CODE_LIMIT`]: constant.MESSAGE_CODE_LIMIT.html
+/// [`MessageBuilder::try_build`]: struct.MessageBuilder.html#method.try_build
+/// [`MessageBuilder::try_from_content`]: struct.MessageBuilder.html#method.try_from_content
+impl From<String> for MessageBuilder {
+    fn from(content: String) -> MessageBuilder { MessageBuilder(content) }
+}
+
+/// Creates a builder which continues from existing content.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity_utils::MessageBuilder;
+///
+/// let content = MessageBuilder::from("Hello, ").push_bold("world").build();
+///
+/// assert_eq!(content, "Hello, **world**");
+/// ```
+impl<'a> From<&'a str> for MessageBuilder {
+    fn from(content: &'a str) -> MessageBuilder { MessageBuilder(content.to_string()) }
+}
+
+/// Pulls the inner value out of the builder, the same as
+/// [`MessageBuilder::build`].
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity_utils::MessageBuilder;
+///
+/// fn send<S: Into<String>>(content: S) -> String { content.into() }
+///
+/// assert_eq!(send(MessageBuilder::new().push_italic("hi")), "*hi*");
+/// ```
+///
+/// [`MessageBuilder::build`]: struct.MessageBuilder.html#method.build
+impl From<MessageBuilder> for String {
+    fn from(builder: MessageBuilder) -> String { builder.0 }
+}
+
+/// Pushes each string to the internal message content, without any
+/// formatting and with nothing between them, the same as calling
+/// [`push`] for each.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity_utils::MessageBuilder;
+///
+/// let mut builder = MessageBuilder::new().push("a");
+/// builder.extend(vec!["b", "c"]);
+///
+/// assert_eq!(builder.build(), "abc");
+/// ```
+///
+/// [`push`]: struct.MessageBuilder.html#method.push
+impl<'a> Extend<&'a str> for MessageBuilder {
+    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) { self.0.extend(iter) }
+}
+
+/// Pushes each string to the internal message content, without any
+/// formatting and with nothing between them, the same as calling
+/// [`push`] for each.
+///
+/// [`push`]: struct.MessageBuilder.html#method.push
+impl Extend<String> for MessageBuilder {
+    fn extend<I: IntoIterator<Item = String>>(&mut self, iter: I) { self.0.extend(iter) }
+}
+
+/// Creates a builder from the strings concatenated, without any formatting
+/// and with nothing between them.
+///
+/// To put each string on its own line, [`push_line`] them instead.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity_utils::MessageBuilder;
+///
+/// let lines = ["first\n", "second\n"];
+/// let builder: MessageBuilder = lines.iter().cloned().collect();
+///
+/// assert_eq!(builder.build(), "first\nsecond\n");
+/// ```
+///
+/// [`push_line`]: struct.MessageBuilder.html#method.push_line
+impl<'a> FromIterator<&'a str> for MessageBuilder {
+    fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> MessageBuilder {
+        MessageBuilder(iter.into_iter().collect())
+    }
+}
+
+/// Creates a builder from the strings concatenated, without any formatting
+/// and with nothing between them.
+///
+/// [`push_line`] each string to put them on their own lines instead.
+///
+/// [`push_line`]: struct.MessageBuilder.html#method.push_line
+impl FromIterator<String> for MessageBuilder {
+    fn from_iter<I: IntoIterator<Item = String>>(iter: I) -> MessageBuilder {
+        MessageBuilder(iter.into_iter().collect())
+    }
+}
+
+/// Writes formatted content directly into the internal message content,
+/// without any markdown being applied or escaped, the same as [`push`].
+///
+/// As the standard library implements [`fmt::Write`] for mutable references
+/// to any writer, a `&mut MessageBuilder` can be passed to generic code that
+/// writes to a [`fmt::Write`] sink as well.
+///
+/// # Examples
+///
+/// Use `write!` to push formatted content:
+///
+/// ```rust
+/// use serenity_utils::MessageBuilder;
+/// use std::fmt::Write;
+///
+/// let mut builder = MessageBuilder::new();
+/// write!(builder, "{} scored {}", "acdenisSK", 42).unwrap();
+///
+/// assert_eq!(builder.build(), "acdenisSK scored 42");
+/// ```
+///
+/// Use `writeln!` to push lines between other builder calls:
+///
+/// ```rust
+/// use serenity_utils::MessageBuilder;
+/// use std::fmt::Write;
+///
+/// let mut builder = MessageBuilder::new().push_bold_line("Scores");
+///
+/// for (rank, name) in ["acdenisSK", "Zeyla"].iter().enumerate() {
+///     writeln!(&mut builder, "{}. {}", rank + 1, name).unwrap();
+/// }
+///
+/// assert_eq!(builder.build(), "**Scores**\n1. acdenisSK\n2. Zeyla\n");
+/// ```
+///
+/// [`fmt::Write`]: https://doc.rust-lang.org/std/fmt/trait.Write.html
+/// [`push`]: struct.MessageBuilder.html#method.push
+impl FmtWrite for MessageBuilder {
+    fn write_str(&mut self, s: &str) -> FmtResult {
+        self.0.push_str(s);
+
+        Ok(())
+    }
+
+    fn write_char(&mut self, c: char) -> FmtResult {
+        self.0.push(c);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,75 @@
+/// The characters and decorations of a progress bar, as pushed by
+/// [`MessageBuilder::push_progress_bar_styled`].
+///
+/// The default style is a bar of `▰` and `▱` followed by the percentage, such
+/// as `▰▰▰▰▱▱▱▱ 50%`, without brackets.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity_utils::{MessageBuilder, ProgressStyle};
+///
+/// let style = ProgressStyle {
+///     filled: '#',
+///     empty: '-',
+///     percentage: false,
+///     brackets: Some(('[', ']')),
+/// };
+///
+/// let content = MessageBuilder::new()
+///     .push_progress_bar_styled(3.0, 4.0, 8, &style)
+///     .build();
+///
+/// assert_eq!(content, "[######--]");
+/// ```
+///
+/// [`MessageBuilder::push_progress_bar_styled`]: struct.MessageBuilder.html#method.push_progress_bar_styled
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ProgressStyle {
+    /// The character for the filled part of the bar.
+    pub filled: char,
+    /// The character for the empty part of the bar.
+    pub empty: char,
+    /// Whether to push the percentage after the bar, separated by a space.
+    pub percentage: bool,
+    /// The characters to push before and after the bar, if any.
+    pub brackets: Option<(char, char)>,
+}
+
+impl ProgressStyle {
+    /// Creates the default style, the same as `ProgressStyle::default`.
+    pub fn new() -> ProgressStyle { ProgressStyle::default() }
+}
+
+impl Default for ProgressStyle {
+    fn default() -> ProgressStyle {
+        ProgressStyle {
+            filled: '▰',
+            empty: '▱',
+            percentage: true,
+            brackets: None,
+        }
+    }
+}
+
+/// Returns how much of the maximum the value is, from `0.0` to `1.0`.
+///
+/// A maximum that isn't positive, and a value that isn't a number, count as no
+/// progress at all.
+pub fn ratio(value: f64, max: f64) -> f64 {
+    if max > 0.0 && !value.is_nan() {
+        (value / max).clamp(0.0, 1.0)
+    } else {
+        0.0
+    }
+}
+
+/// Scales a ratio to a number of steps out of the total, rounding down so
+/// that a ratio just short of `1.0` is never shown as complete.
+pub fn scale(ratio: f64, total: usize) -> usize {
+    // Nudge the result so that floating point error, such as `0.29 * 100.0`
+    // being `28.999999999999996`, doesn't round down a whole step.
+    let steps = (ratio * total as f64 + 1e-9).floor() as usize;
+
+    steps.min(total)
+}
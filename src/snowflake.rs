@@ -0,0 +1,265 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use super::emoji::EmojiIdentifier;
+
+/// The start of Discord's epoch, the first second of 2015, in milliseconds
+/// since the Unix epoch.
+pub const DISCORD_EPOCH: u64 = 1_420_070_400_000;
+
+/// Retrieves the time at which something with a Discord Id was created.
+///
+/// Every Id is a "snowflake", which encodes the number of milliseconds since
+/// [`DISCORD_EPOCH`] at which it was generated in its upper 42 bits. This is
+/// implemented for `u64`, so works with the Id of any user, channel, role,
+/// guild, message, or emoji alike.
+///
+/// # Examples
+///
+/// Find the age of a message, such as for a `ping` command:
+///
+/// ```rust
+/// use serenity_utils::{MessageBuilder, SnowflakeExt};
+///
+/// let message_id: u64 = 175928847299117063;
+///
+/// assert_eq!(message_id.created_at_millis(), 1462015105796);
+///
+/// let content = MessageBuilder::new()
+///     .push("Sent ")
+///     .push_relative(message_id.created_at())
+///     .build();
+///
+/// assert_eq!(content, "Sent <t:1462015105:R>");
+/// ```
+///
+/// [`DISCORD_EPOCH`]: constant.DISCORD_EPOCH.html
+pub trait SnowflakeExt {
+    /// Returns the time of creation in milliseconds since the Unix epoch.
+    ///
+    /// This can't overflow: even the largest possible Id is from the year
+    /// 2154.
+    fn created_at_millis(&self) -> u64;
+
+    /// Returns the time of creation.
+    fn created_at(&self) -> SystemTime {
+        UNIX_EPOCH + Duration::from_millis(self.created_at_millis())
+    }
+}
+
+impl SnowflakeExt for u64 {
+    fn created_at_millis(&self) -> u64 { (*self >> 22) + DISCORD_EPOCH }
+}
+
+impl SnowflakeExt for EmojiIdentifier {
+    fn created_at_millis(&self) -> u64 { self.id.created_at_millis() }
+}
+
+// The largest value of each field of a snowflake, as each is stored in just
+// enough bits for it.
+const MAX_TIMESTAMP: u64 = (1 << 42) - 1;
+const MAX_WORKER: u8 = (1 << 5) - 1;
+const MAX_PROCESS: u8 = (1 << 5) - 1;
+const MAX_INCREMENT: u16 = (1 << 12) - 1;
+
+/// The four fields of a snowflake, as returned by [`decompose_snowflake`].
+///
+/// [`decompose_snowflake`]: fn.decompose_snowflake.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct SnowflakeParts {
+    /// The time the snowflake was generated, in milliseconds since the Unix
+    /// epoch.
+    pub timestamp: u64,
+    /// The internal worker which generated the snowflake, from `0` to `31`.
+    pub worker: u8,
+    /// The internal process which generated the snowflake, from `0` to `31`.
+    pub process: u8,
+    /// The number of snowflakes generated by the process before this one
+    /// within the same millisecond, from `0` to `4095`.
+    pub increment: u16,
+}
+
+/// Generates a snowflake from its four fields, the inverse of
+/// [`decompose_snowflake`].
+///
+/// This is intended for test fixtures, such as a message created five
+/// minutes after a guild, rather than magic numbers whose creation time has
+/// to be worked out by hand.
+///
+/// The timestamp is in milliseconds since the Unix epoch, as returned by
+/// [`SnowflakeExt::created_at_millis`].
+///
+/// # Panics
+///
+/// Panics if the timestamp is before [`DISCORD_EPOCH`] or too far after it to
+/// be stored, the worker or process is over `31`, or the increment is over
+/// `4095`.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity_utils::{generate_snowflake, SnowflakeExt};
+///
+/// let guild_id = generate_snowflake(1_600_000_000_000, 0, 0, 0);
+/// let message_id = generate_snowflake(1_600_000_000_000 + 5 * 60 * 1000, 0, 0, 0);
+///
+/// assert!(message_id > guild_id);
+/// assert_eq!(message_id.created_at_millis() - guild_id.created_at_millis(), 300_000);
+/// ```
+///
+/// [`DISCORD_EPOCH`]: constant.DISCORD_EPOCH.html
+/// [`SnowflakeExt::created_at_millis`]: trait.SnowflakeExt.html#tymethod.created_at_millis
+/// [`decompose_snowflake`]: fn.decompose_snowflake.html
+pub fn generate_snowflake(timestamp: u64, worker: u8, process: u8, increment: u16) -> u64 {
+    assert!(timestamp >= DISCORD_EPOCH, "timestamp must not be before the Discord epoch");
+    assert!(timestamp - DISCORD_EPOCH <= MAX_TIMESTAMP, "timestamp is too far in the future");
+    assert!(worker <= MAX_WORKER, "worker must be at most 31");
+    assert!(process <= MAX_PROCESS, "process must be at most 31");
+    assert!(increment <= MAX_INCREMENT, "increment must be at most 4095");
+
+    ((timestamp - DISCORD_EPOCH) << 22)
+        | (u64::from(worker) << 17)
+        | (u64::from(process) << 12)
+        | u64::from(increment)
+}
+
+/// Splits a snowflake into its four fields, the inverse of
+/// [`generate_snowflake`].
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity_utils::{decompose_snowflake, SnowflakeParts};
+///
+/// assert_eq!(decompose_snowflake(175928847299117063), SnowflakeParts {
+///     timestamp: 1462015105796,
+///     worker: 1,
+///     process: 0,
+///     increment: 7,
+/// });
+/// ```
+///
+/// [`generate_snowflake`]: fn.generate_snowflake.html
+pub fn decompose_snowflake(id: u64) -> SnowflakeParts {
+    SnowflakeParts {
+        timestamp: id.created_at_millis(),
+        worker: ((id >> 17) & u64::from(MAX_WORKER)) as u8,
+        process: ((id >> 12) & u64::from(MAX_PROCESS)) as u8,
+        increment: (id & u64::from(MAX_INCREMENT)) as u16,
+    }
+}
+
+/// Generates increasing snowflakes for test fixtures, starting from a given
+/// time.
+///
+/// Each snowflake is generated at the generator's current time, with the
+/// increment counting up from `0`. Once the increment runs out, the time moves
+/// on by a millisecond, so that every snowflake is unique and greater than
+/// the last.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity_utils::{SnowflakeExt, SnowflakeGenerator};
+/// use std::time::Duration;
+///
+/// let mut generator = SnowflakeGenerator::new(1_600_000_000_000);
+///
+/// let guild_id = generator.next_id();
+/// let channel_id = generator.next_id();
+/// let message_id = generator.advance(Duration::from_secs(5 * 60)).next_id();
+///
+/// assert!(guild_id < channel_id && channel_id < message_id);
+/// assert_eq!(channel_id.created_at_millis(), guild_id.created_at_millis());
+/// assert_eq!(message_id.created_at_millis() - guild_id.created_at_millis(), 300_000);
+/// ```
+#[derive(Clone, Debug)]
+pub struct SnowflakeGenerator {
+    timestamp: u64,
+    worker: u8,
+    process: u8,
+    increment: u16,
+}
+
+impl SnowflakeGenerator {
+    /// Creates a generator starting at the given time, in milliseconds since
+    /// the Unix epoch, with a worker and process of `0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the time is before [`DISCORD_EPOCH`].
+    ///
+    /// [`DISCORD_EPOCH`]: constant.DISCORD_EPOCH.html
+    pub fn new(timestamp: u64) -> SnowflakeGenerator {
+        assert!(timestamp >= DISCORD_EPOCH, "timestamp must not be before the Discord epoch");
+
+        SnowflakeGenerator {
+            timestamp,
+            worker: 0,
+            process: 0,
+            increment: 0,
+        }
+    }
+
+    /// Sets the worker to generate snowflakes with.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the worker is over `31`.
+    pub fn worker(mut self, worker: u8) -> Self {
+        assert!(worker <= MAX_WORKER, "worker must be at most 31");
+        self.worker = worker;
+
+        self
+    }
+
+    /// Sets the process to generate snowflakes with.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the process is over `31`.
+    pub fn process(mut self, process: u8) -> Self {
+        assert!(process <= MAX_PROCESS, "process must be at most 31");
+        self.process = process;
+
+        self
+    }
+
+    /// Moves the generator's time on by the given duration, restarting the
+    /// increment. Any time less than a millisecond is ignored.
+    pub fn advance(&mut self, duration: Duration) -> &mut Self {
+        let millis = duration.as_secs() * 1000 + u64::from(duration.subsec_millis());
+
+        if millis > 0 {
+            self.timestamp += millis;
+            self.increment = 0;
+        }
+
+        self
+    }
+
+    /// Generates the next snowflake.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the generator's time has moved too far past
+    /// [`DISCORD_EPOCH`] to be stored.
+    ///
+    /// [`DISCORD_EPOCH`]: constant.DISCORD_EPOCH.html
+    pub fn next_id(&mut self) -> u64 {
+        let id = generate_snowflake(self.timestamp, self.worker, self.process, self.increment);
+
+        if self.increment == MAX_INCREMENT {
+            self.timestamp += 1;
+            self.increment = 0;
+        } else {
+            self.increment += 1;
+        }
+
+        id
+    }
+}
+
+impl Iterator for SnowflakeGenerator {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> { Some(self.next_id()) }
+}
@@ -0,0 +1,397 @@
+use super::content::{self, Token, TokenKind};
+use super::discord_len;
+use super::markdown::code_at;
+use super::message_builder::MESSAGE_CODE_LIMIT;
+
+/// The fence pushed to close a code block at the end of a chunk.
+const CLOSING_FENCE: &str = "\n```";
+
+/// Splits message content into chunks which each fit within
+/// [`MESSAGE_CODE_LIMIT`], so that it can be sent as several messages.
+/// Lengths are counted in unicode code points, as by [`MessageBuilder::len`].
+///
+/// Content is split at the last paragraph break (a blank line) that fits
+/// within the limit, falling back to the last line break, then the last
+/// whitespace, and only splitting in the middle of a word as a last resort.
+/// The separator a chunk is split at is dropped from the start of the next
+/// chunk.
+///
+/// Content is never split within a character, nor within a mention, emoji,
+/// or timestamp tag such as `<@114941315417899012>`; the tag is instead moved
+/// to the next chunk.
+///
+/// When a split falls within a code block, the chunk is ended with a closing
+/// fence and the next chunk is started with an opening fence in the same
+/// language, so that each message renders the code as code. Inline code spans
+/// are not affected.
+///
+/// Empty content produces no chunks.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity_utils;
+///
+/// let content = format!("{}\n\n{}", "a".repeat(1500), "b".repeat(1500));
+/// let chunks = serenity_utils::split_message(&content);
+///
+/// assert_eq!(chunks, vec!["a".repeat(1500), "b".repeat(1500)]);
+/// ```
+///
+/// A code block spanning two chunks is closed and re-opened:
+///
+/// ```rust
+/// use serenity_utils;
+///
+/// let code = "let x = 1;\n".repeat(250);
+/// let content = format!("```rust\n{}```", code);
+/// let chunks = serenity_utils::split_message(&content);
+///
+/// assert_eq!(chunks.len(), 2);
+/// assert!(chunks[0].ends_with("let x = 1;\n```"));
+/// assert!(chunks[1].starts_with("```rust\nlet x = 1;"));
+/// ```
+///
+/// [`MESSAGE_CODE_LIMIT`]: constant.MESSAGE_CODE_LIMIT.html
+/// [`MessageBuilder::len`]: struct.MessageBuilder.html#method.len
+pub fn split_message(content: &str) -> Vec<String> {
+    let mut chunks = vec![];
+    let mut rest = content;
+    let mut reopen = String::new();
+
+    while !rest.is_empty() {
+        let limit = MESSAGE_CODE_LIMIT - discord_len(&reopen);
+
+        let (mut end, mut next) = match split_point(rest, limit) {
+            Some(point) => point,
+            None => {
+                chunks.push(reopen + rest);
+
+                break;
+            },
+        };
+
+        let mut fence = open_fence(&reopen, &rest[..end]);
+
+        // Make room for the closing fence if the chunk ends in a code block.
+        if fence.is_some() && limit - discord_len(&rest[..end]) < discord_len(CLOSING_FENCE) {
+            if let Some(point) = split_point(rest, limit - CLOSING_FENCE.len()) {
+                end = point.0;
+                next = point.1;
+                fence = open_fence(&reopen, &rest[..end]);
+            }
+        }
+
+        let (start, language) = match fence {
+            Some(fence) => fence,
+            None => {
+                chunks.push(reopen + &rest[..end]);
+                reopen = String::new();
+                rest = &rest[next..];
+
+                continue;
+            },
+        };
+
+        // If the chunk ends with the opening line of a code block, move the
+        // whole block to the next chunk instead of leaving it empty, or cut
+        // its first line short if the block is at the start of the chunk.
+        if reopen.is_empty() && !rest[start..end].contains('\n') {
+            if start > 0 {
+                let before = rest[..start].trim_end();
+
+                // Only whitespace before the block isn't worth a message.
+                if !before.is_empty() {
+                    chunks.push(before.to_string());
+                }
+
+                rest = &rest[start..];
+
+                continue;
+            }
+
+            let cut = limit - CLOSING_FENCE.len();
+
+            // Split the block's first line of code at a word if it has one,
+            // rather than at the opening fence's line.
+            let body = rest.find('\n').map(|newline| newline + 1).and_then(|body| {
+                let limit = cut.checked_sub(discord_len(&rest[..body]))?;
+                let (end, next) = split_point(&rest[body..], limit)?;
+
+                Some((body + end, body + next))
+            });
+
+            if let Some(point) = body {
+                end = point.0;
+                next = point.1;
+            } else if let Some((idx, _)) = rest.char_indices().nth(cut) {
+                end = idx;
+                next = idx;
+            }
+        }
+
+        let mut chunk = reopen + &rest[..end];
+        chunk.push_str(if chunk.ends_with('\n') { "```" } else { CLOSING_FENCE });
+        chunks.push(chunk);
+        rest = &rest[next..];
+
+        // The block may be closed at the very start of the next chunk, in
+        // which case the chunk's own closing fence is enough.
+        if rest.starts_with("```") {
+            rest = rest.trim_start_matches('`').trim_start();
+
+            reopen = String::new();
+        } else {
+            reopen = format!("```{}\n", language);
+        }
+    }
+
+    chunks
+}
+
+/// Finds where to split content so that the chunk fits within the given
+/// number of characters, or `None` if the content fits as-is.
+///
+/// Returns the end of the chunk and the start of the remaining content.
+fn split_point(content: &str, limit: usize) -> Option<(usize, usize)> {
+    let max = content.char_indices().nth(limit)?.0;
+    let window = &content[..max];
+
+    let separators = [
+        window.rfind("\n\n").map(|idx| (idx, idx + 2)),
+        window.rfind('\n').map(|idx| (idx, idx + 1)),
+        window.char_indices()
+            .rev()
+            .find(|&(_, c)| c.is_whitespace())
+            .map(|(idx, c)| (idx, idx + c.len_utf8())),
+    ];
+
+    for &separator in &separators {
+        if let Some((end, next)) = separator {
+            if end > 0 && tag_start(content, end).is_none() {
+                return Some((end, next));
+            }
+        }
+    }
+
+    Some(match tag_start(content, max) {
+        Some(start) if start > 0 => (start, start),
+        _ => (max, max),
+    })
+}
+
+/// If the given byte index is within a tag, such as a mention, returns the
+/// index at which the tag starts.
+pub fn tag_start(content: &str, idx: usize) -> Option<usize> {
+    const PREFIXES: &[&str] = &["<@", "<#", "<:", "<a:", "<t:", "</", "<id:"];
+
+    let start = content[..idx].rfind('<')?;
+    let end = start + content[start..].find('>')?;
+
+    if end < idx {
+        return None;
+    }
+
+    let tag = &content[start..=end];
+    let tag_like = PREFIXES.iter().any(|prefix| tag.starts_with(prefix))
+        && tag.len() <= 128
+        && !tag.contains('\n');
+
+    if tag_like {
+        Some(start)
+    } else {
+        None
+    }
+}
+
+/// Determines whether a chunk, made up of the fence re-opened from the
+/// previous chunk followed by the given content, ends within a code block.
+///
+/// If it does, returns the index within the content of the block's opening
+/// fence (or `0` if the block was re-opened), along with its language.
+fn open_fence(reopen: &str, content: &str) -> Option<(usize, String)> {
+    let mut idx = 0;
+
+    // A re-opened block is closed by the next fence, as by `code_at`.
+    if !reopen.is_empty() {
+        match content.find("```") {
+            Some(end) => idx = end + 3,
+            None => return Some((0, reopen[3..].trim().to_string())),
+        }
+    }
+
+    while let Some(offset) = content[idx..].find(&['`', '\\'][..]) {
+        let start = idx + offset;
+        let rest = &content[start..];
+
+        if let Some(after) = rest.strip_prefix('\\') {
+            let escaped = after.chars().next().filter(char::is_ascii_punctuation);
+            idx = start + 1 + escaped.map_or(0, char::len_utf8);
+
+            continue;
+        }
+
+        // Code spans and blocks which close within the content are skipped
+        // whole, so that backticks within them are code too.
+        if let Some((_, len)) = code_at(rest) {
+            idx = start + len;
+
+            continue;
+        }
+
+        let run = rest.len() - rest.trim_start_matches('`').len();
+
+        if run >= 3 {
+            return Some((start, fence_language(&rest[run..])));
+        }
+
+        idx = start + run;
+    }
+
+    None
+}
+
+/// Retrieves the language of a code block, given the content following its
+/// opening fence.
+fn fence_language(after: &str) -> String {
+    let line = match after.find('\n') {
+        Some(end) => &after[..end],
+        None => return String::new(),
+    };
+
+    let language = line.trim();
+
+    if language.len() <= 32 && !language.contains(char::is_whitespace) {
+        language.to_string()
+    } else {
+        String::new()
+    }
+}
+
+/// Shortens content to at most the given number of characters, ending it with
+/// an ellipsis (`…`). Characters are counted in unicode code points, as by
+/// [`MessageBuilder::len`].
+///
+/// The content is never cut within a tag, such as a mention, nor within an
+/// emoji made up of several characters, nor within the markers of formatting
+/// or code. Any formatting which the cut falls within, as parsed by
+/// [`content::parse`] from the whole content, such as bold text or a code
+/// block, is closed after the ellipsis.
+///
+/// [`MessageBuilder::len`]: struct.MessageBuilder.html#method.len
+/// [`content::parse`]: content/fn.parse.html
+pub fn truncate(content: &str, limit: usize) -> String {
+    if discord_len(content) <= limit {
+        return content.to_string();
+    } else if limit == 0 {
+        return String::new();
+    }
+
+    let tokens = content::parse(content);
+    let mut cut = content.char_indices().nth(limit - 1).map_or(content.len(), |(idx, _)| idx);
+
+    loop {
+        cut = marker_cut(&tokens, safe_cut(content, cut));
+
+        let closers = closers(&tokens, cut);
+        let length = discord_len(&content[..cut]) + 1 + discord_len(&closers);
+
+        if length <= limit || cut == 0 {
+            let mut truncated = content[..cut].to_string();
+            truncated.push('…');
+            truncated.push_str(&closers);
+
+            return truncated;
+        }
+
+        cut = content[..cut].char_indices().next_back().map_or(0, |(idx, _)| idx);
+    }
+}
+
+/// Moves a cut back so that it does not fall within a tag or an emoji.
+fn safe_cut(content: &str, mut cut: usize) -> usize {
+    if let Some(start) = tag_start(content, cut) {
+        cut = start;
+    }
+
+    loop {
+        let before = content[..cut].chars().next_back();
+        let at = content[cut..].chars().next();
+
+        let within = match (before, at) {
+            (None, _) | (_, None) => false,
+            (Some('\u{200D}'), _) => true,
+            (_, Some(c)) if is_extender(c) => true,
+            // Flags are pairs of regional indicators.
+            (_, Some(c)) if is_regional_indicator(c) => {
+                let preceding = content[..cut].chars().rev();
+
+                preceding.take_while(|&c| is_regional_indicator(c)).count() % 2 == 1
+            },
+            _ => false,
+        };
+
+        if !within {
+            return cut;
+        }
+
+        cut -= before.map_or(0, char::len_utf8);
+    }
+}
+
+/// Moves a cut back so that it does not fall within the markers around
+/// formatting or code, such as the `**` of bold text.
+fn marker_cut(tokens: &[Token], cut: usize) -> usize {
+    let token = match tokens.iter().find(|token| token.range.start < cut && cut < token.range.end) {
+        Some(token) => token,
+        None => return cut,
+    };
+
+    let (marker, children) = match token.kind {
+        TokenKind::Styled { style, ref children } => (style.marker().len(), &children[..]),
+        TokenKind::CodeSpan { backticks, .. } => (backticks, &[][..]),
+        TokenKind::CodeBlock { .. } => (3, &[][..]),
+        _ => return cut,
+    };
+
+    if cut < token.range.start + marker {
+        token.range.start
+    } else if cut > token.range.end - marker {
+        token.range.end - marker
+    } else {
+        marker_cut(children, cut)
+    }
+}
+
+/// Finds the formatting which a cut falls within, returning the markers
+/// which close it, innermost first.
+fn closers(tokens: &[Token], cut: usize) -> String {
+    let token = match tokens.iter().find(|token| token.range.start < cut && cut < token.range.end) {
+        Some(token) => token,
+        None => return String::new(),
+    };
+
+    match token.kind {
+        TokenKind::Styled { style, ref children } => closers(children, cut) + style.marker(),
+        TokenKind::CodeSpan { backticks, .. } => "``"[..backticks].to_string(),
+        TokenKind::CodeBlock { .. } => "\n```".to_string(),
+        _ => String::new(),
+    }
+}
+
+/// Whether a character extends the one before it into a single emoji or
+/// glyph, such as a skin tone modifier or combining mark.
+fn is_extender(c: char) -> bool {
+    matches!(c as u32,
+        0x0300..=0x036F
+        | 0x1AB0..=0x1AFF
+        | 0x20D0..=0x20FF
+        | 0xFE00..=0xFE0F
+        | 0x200D
+        | 0x1F3FB..=0x1F3FF
+        | 0xE0020..=0xE007F)
+}
+
+fn is_regional_indicator(c: char) -> bool {
+    matches!(c as u32, 0x1F1E6..=0x1F1FF)
+}
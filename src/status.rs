@@ -0,0 +1,68 @@
+use super::emoji::ReactionType;
+
+/// The icons prefixed to status lines, such as those pushed by
+/// [`MessageBuilder::push_success`].
+///
+/// The defaults are the unicode emoji `✅`, `⚠️`, and `❌`. Guilds with their
+/// own emoji can swap them in, and push status lines with
+/// [`MessageBuilder::push_success_with`] and the like, or with
+/// [`MessageBuilder::push_status`].
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity_utils::{MessageBuilder, ReactionType, StatusIcons};
+///
+/// let icons = StatusIcons {
+///     success: ReactionType::Custom {
+///         animated: false,
+///         id: 302516740095606785,
+///         name: "yes".to_string(),
+///     },
+///     ..StatusIcons::default()
+/// };
+///
+/// let content = MessageBuilder::new()
+///     .push_success_with(&icons, "Deployed")
+///     .push_warning_with(&icons, "Cache is cold")
+///     .build();
+///
+/// assert_eq!(content, "<:yes:302516740095606785> Deployed\n⚠\u{FE0F} Cache is cold\n");
+/// ```
+///
+/// [`MessageBuilder::push_status`]: struct.MessageBuilder.html#method.push_status
+/// [`MessageBuilder::push_success`]: struct.MessageBuilder.html#method.push_success
+/// [`MessageBuilder::push_success_with`]: struct.MessageBuilder.html#method.push_success_with
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct StatusIcons {
+    /// The icon for an action which succeeded.
+    pub success: ReactionType,
+    /// The icon for a warning.
+    pub warning: ReactionType,
+    /// The icon for an action which failed.
+    pub error: ReactionType,
+}
+
+impl StatusIcons {
+    /// Creates the default set of icons, the same as `StatusIcons::default`.
+    pub fn new() -> StatusIcons { StatusIcons::default() }
+}
+
+impl Default for StatusIcons {
+    fn default() -> StatusIcons {
+        StatusIcons {
+            success: ReactionType::from(SUCCESS),
+            warning: ReactionType::from(WARNING),
+            error: ReactionType::from(ERROR),
+        }
+    }
+}
+
+/// The default icon for an action which succeeded.
+pub const SUCCESS: &str = "✅";
+
+/// The default icon for a warning.
+pub const WARNING: &str = "⚠\u{FE0F}";
+
+/// The default icon for an action which failed.
+pub const ERROR: &str = "❌";
@@ -0,0 +1,231 @@
+/// The alignment of a column's cells within a [`TableBuilder`].
+///
+/// [`TableBuilder`]: struct.TableBuilder.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Alignment {
+    Left,
+    Center,
+    Right,
+}
+
+impl Default for Alignment {
+    /// Creates a default value for an `Alignment`, aligning to the left.
+    fn default() -> Alignment { Alignment::Left }
+}
+
+/// A builder for a monospaced table, padding each cell to the width of its
+/// column.
+///
+/// Widths are counted in terminal columns rather than bytes: most characters
+/// take one column, wide characters (such as CJK ideographs and most emoji)
+/// take two, and combining characters take none.
+///
+/// The table is usually pushed via [`MessageBuilder::push_table`], which
+/// renders it inside of a code block so that the columns line up.
+///
+/// # Examples
+///
+/// Build a leaderboard, with the score column aligned to the right:
+///
+/// ```rust
+/// use serenity_utils::{Alignment, TableBuilder};
+///
+/// let table = TableBuilder::new(&["Rank", "Name", "Score"])
+///     .align(2, Alignment::Right)
+///     .row(&["1", "Zeyla", "1200"])
+///     .row(&["2", "acdenisSK", "950"])
+///     .build();
+///
+/// let expected = "\
+/// Rank | Name      | Score
+/// -----+-----------+------
+/// 1    | Zeyla     |  1200
+/// 2    | acdenisSK |   950";
+///
+/// assert_eq!(table, expected);
+/// ```
+///
+/// [`MessageBuilder::push_table`]: struct.MessageBuilder.html#method.push_table
+#[derive(Clone, Debug, Default)]
+pub struct TableBuilder {
+    alignments: Vec<Alignment>,
+    headers: Vec<String>,
+    max_width: Option<usize>,
+    rows: Vec<Vec<String>>,
+}
+
+impl TableBuilder {
+    /// Creates a new table with the given column headers.
+    pub fn new<I, T>(headers: I) -> TableBuilder
+        where I: IntoIterator<Item = T>, T: AsRef<str> {
+        let headers = headers.into_iter()
+            .map(|header| header.as_ref().to_string())
+            .collect::<Vec<_>>();
+
+        TableBuilder {
+            alignments: vec![Alignment::default(); headers.len()],
+            headers,
+            ..TableBuilder::default()
+        }
+    }
+
+    /// Sets the alignment of the column at the given index.
+    ///
+    /// Indices past the last column are ignored.
+    pub fn align(mut self, column: usize, alignment: Alignment) -> Self {
+        if let Some(current) = self.alignments.get_mut(column) {
+            *current = alignment;
+        }
+
+        self
+    }
+
+    /// Sets the maximum width of any cell. Cells wider than this are
+    /// truncated, ending in an ellipsis (`…`).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_utils::TableBuilder;
+    ///
+    /// let table = TableBuilder::new(&["Name"])
+    ///     .max_width(6)
+    ///     .row(&["Alexander"])
+    ///     .build();
+    ///
+    /// assert_eq!(table, "Name\n------\nAlexa…");
+    /// ```
+    pub fn max_width(mut self, max_width: usize) -> Self {
+        self.max_width = Some(max_width);
+
+        self
+    }
+
+    /// Adds a row to the table.
+    ///
+    /// Rows with fewer cells than there are columns are padded with empty
+    /// cells, and any additional cells are ignored.
+    pub fn row<I, T>(mut self, cells: I) -> Self
+        where I: IntoIterator<Item = T>, T: AsRef<str> {
+        let mut row = cells.into_iter()
+            .take(self.headers.len())
+            .map(|cell| cell.as_ref().to_string())
+            .collect::<Vec<_>>();
+        row.resize(self.headers.len(), String::new());
+
+        self.rows.push(row);
+
+        self
+    }
+
+    /// Renders the table, with a separator line between the headers and the
+    /// rows.
+    pub fn build(&self) -> String {
+        let headers = self.truncated(&self.headers);
+        let rows = self.rows.iter().map(|row| self.truncated(row)).collect::<Vec<_>>();
+
+        let mut widths = headers.iter().map(|cell| str_width(cell)).collect::<Vec<_>>();
+
+        for row in &rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(str_width(cell));
+            }
+        }
+
+        let mut table = String::new();
+        self.push_row(&mut table, &headers, &widths);
+
+        let separator = widths.iter()
+            .map(|&width| "-".repeat(width))
+            .collect::<Vec<_>>()
+            .join("-+-");
+        table.push('\n');
+        table.push_str(&separator);
+
+        for row in &rows {
+            table.push('\n');
+            self.push_row(&mut table, row, &widths);
+        }
+
+        table
+    }
+
+    fn push_row(&self, table: &mut String, cells: &[String], widths: &[usize]) {
+        let start = table.len();
+
+        for (idx, cell) in cells.iter().enumerate() {
+            if idx > 0 {
+                table.push_str(" | ");
+            }
+
+            let padding = widths[idx] - str_width(cell);
+            let (left, right) = match self.alignments[idx] {
+                Alignment::Left => (0, padding),
+                Alignment::Center => (padding / 2, padding - padding / 2),
+                Alignment::Right => (padding, 0),
+            };
+
+            table.push_str(&" ".repeat(left));
+            table.push_str(cell);
+            table.push_str(&" ".repeat(right));
+        }
+
+        let trimmed = start + table[start..].trim_end().len();
+        table.truncate(trimmed);
+    }
+
+    fn truncated(&self, cells: &[String]) -> Vec<String> {
+        cells.iter()
+            .map(|cell| match self.max_width {
+                Some(max) if str_width(cell) > max => truncate(cell, max),
+                _ => cell.clone(),
+            })
+            .collect()
+    }
+}
+
+/// Truncates a cell to fit within the given width, including the ellipsis.
+fn truncate(cell: &str, max: usize) -> String {
+    let mut truncated = String::new();
+    let mut width = 0;
+
+    for c in cell.chars() {
+        let next = width + char_width(c);
+
+        if next + 1 > max {
+            break;
+        }
+
+        truncated.push(c);
+        width = next;
+    }
+
+    if max > 0 {
+        truncated.push('…');
+    }
+
+    truncated
+}
+
+fn str_width(s: &str) -> usize { s.chars().map(char_width).sum() }
+
+/// An approximation of the number of columns a character takes up in a
+/// monospaced font.
+fn char_width(c: char) -> usize {
+    match c as u32 {
+        // Combining marks, zero-width characters, and variation selectors.
+        0x0300..=0x036F | 0x200B..=0x200F | 0x20D0..=0x20FF | 0xFE00..=0xFE0F => 0,
+        // Hangul Jamo, CJK, Hangul syllables, fullwidth forms, and emoji.
+        0x1100..=0x115F
+        | 0x2E80..=0xA4CF
+        | 0xAC00..=0xD7A3
+        | 0xF900..=0xFAFF
+        | 0xFE30..=0xFE4F
+        | 0xFF00..=0xFF60
+        | 0xFFE0..=0xFFE6
+        | 0x1F300..=0x1F64F
+        | 0x1F900..=0x1F9FF
+        | 0x20000..=0x3FFFD => 2,
+        _ => 1,
+    }
+}
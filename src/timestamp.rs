@@ -0,0 +1,234 @@
+use std::fmt::{Display, Formatter, Result as FmtResult, Write as FmtWrite};
+use std::ops::Range;
+use std::time::{SystemTime, UNIX_EPOCH};
+use super::parse_timestamp_tag;
+
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, TimeZone};
+
+/// The style in which a timestamp tag is displayed by Discord's client.
+///
+/// Each viewer sees the timestamp in their own locale and timezone. The
+/// examples below are as viewed in the `en-US` locale.
+///
+/// The `Display` implementation formats the style as the letter used for it
+/// in a timestamp tag.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity_utils::TimestampStyle;
+///
+/// assert_eq!(TimestampStyle::Relative.to_string(), "R");
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum TimestampStyle {
+    /// A short time, such as `4:20 PM`.
+    ShortTime,
+    /// A long time, such as `4:20:30 PM`.
+    LongTime,
+    /// A short date, such as `11/28/2018`.
+    ShortDate,
+    /// A long date, such as `November 28, 2018`.
+    LongDate,
+    /// A short date and time, such as `November 28, 2018 4:20 PM`.
+    ShortDateTime,
+    /// A long date and time, such as `Wednesday, November 28, 2018 4:20 PM`.
+    LongDateTime,
+    /// The time relative to now, such as `3 hours ago`.
+    Relative,
+}
+
+impl TimestampStyle {
+    /// The letter used for the style in a timestamp tag.
+    pub fn letter(&self) -> char {
+        use self::TimestampStyle::*;
+
+        match *self {
+            ShortTime => 't',
+            LongTime => 'T',
+            ShortDate => 'd',
+            LongDate => 'D',
+            ShortDateTime => 'f',
+            LongDateTime => 'F',
+            Relative => 'R',
+        }
+    }
+
+    /// Retrieves the style with the given letter within a timestamp tag.
+    pub fn from_letter(letter: char) -> Option<TimestampStyle> {
+        use self::TimestampStyle::*;
+
+        match letter {
+            't' => Some(ShortTime),
+            'T' => Some(LongTime),
+            'd' => Some(ShortDate),
+            'D' => Some(LongDate),
+            'f' => Some(ShortDateTime),
+            'F' => Some(LongDateTime),
+            'R' => Some(Relative),
+            _ => None,
+        }
+    }
+}
+
+impl Default for TimestampStyle {
+    /// Creates a default value for a `TimestampStyle`, which is the style
+    /// Discord uses for tags without one: `ShortDateTime`.
+    fn default() -> TimestampStyle { TimestampStyle::ShortDateTime }
+}
+
+impl Display for TimestampStyle {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult { f.write_char(self.letter()) }
+}
+
+/// Finds every timestamp tag within content, such as `<t:1700000000:R>`.
+///
+/// Each tag is yielded with its byte range within the content, its time in
+/// seconds since the Unix epoch, and its style, as by
+/// [`parse_timestamp_tag`]. Text which only looks like a tag, such as
+/// `<t:soon>`, is skipped.
+///
+/// # Examples
+///
+/// Redact every timestamp in a message:
+///
+/// ```rust
+/// use serenity_utils::{find_timestamp_tags, TimestampStyle};
+///
+/// let content = "Starts <t:1700000000:R>, ends <t:1700003600>.";
+/// let tags = find_timestamp_tags(content).collect::<Vec<_>>();
+///
+/// assert_eq!(tags, vec![
+///     (7..23, 1700000000, TimestampStyle::Relative),
+///     (30..44, 1700003600, TimestampStyle::ShortDateTime),
+/// ]);
+///
+/// let mut redacted = content.to_string();
+///
+/// for (range, _, _) in tags.into_iter().rev() {
+///     redacted.replace_range(range, "[time]");
+/// }
+///
+/// assert_eq!(redacted, "Starts [time], ends [time].");
+/// ```
+///
+/// [`parse_timestamp_tag`]: fn.parse_timestamp_tag.html
+pub fn find_timestamp_tags(content: &str) -> TimestampTags<'_> {
+    TimestampTags {
+        content,
+        offset: 0,
+    }
+}
+
+/// An iterator over the timestamp tags within content, as returned by
+/// [`find_timestamp_tags`].
+///
+/// [`find_timestamp_tags`]: fn.find_timestamp_tags.html
+#[derive(Clone, Debug)]
+pub struct TimestampTags<'a> {
+    content: &'a str,
+    offset: usize,
+}
+
+impl<'a> Iterator for TimestampTags<'a> {
+    type Item = (Range<usize>, i64, TimestampStyle);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(idx) = self.content[self.offset..].find("<t:") {
+            let start = self.offset + idx;
+            // Searching on from the next byte finds a tag which starts within
+            // text that only looked like one.
+            self.offset = start + 1;
+
+            let end = match self.content[start..].find('>') {
+                Some(idx) => start + idx + 1,
+                None => return None,
+            };
+
+            if let Some((timestamp, style)) = parse_timestamp_tag(&self.content[start..end]) {
+                self.offset = end;
+
+                return Some((start..end, timestamp, style));
+            }
+        }
+
+        None
+    }
+}
+
+/// A value which can be converted into a Unix timestamp, in seconds, for use
+/// in a timestamp tag.
+///
+/// Any sub-second precision is truncated towards zero, and values too large
+/// to be represented are clamped.
+///
+/// This is implemented for integers holding seconds since the Unix epoch,
+/// `SystemTime`, and, with the `chrono` feature enabled, chrono's `DateTime`.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity_utils::IntoTimestamp;
+/// use std::time::{Duration, UNIX_EPOCH};
+///
+/// let time = UNIX_EPOCH + Duration::from_millis(1_700_000_000_999);
+///
+/// assert_eq!(time.into_timestamp(), 1_700_000_000);
+/// ```
+pub trait IntoTimestamp {
+    /// Converts the value into seconds since the Unix epoch.
+    fn into_timestamp(self) -> i64;
+}
+
+impl IntoTimestamp for i64 {
+    fn into_timestamp(self) -> i64 { self }
+}
+
+impl IntoTimestamp for i32 {
+    fn into_timestamp(self) -> i64 { i64::from(self) }
+}
+
+impl IntoTimestamp for u32 {
+    fn into_timestamp(self) -> i64 { i64::from(self) }
+}
+
+impl IntoTimestamp for u64 {
+    fn into_timestamp(self) -> i64 { clamp_secs(self) }
+}
+
+impl IntoTimestamp for SystemTime {
+    /// Converts the time into seconds since the Unix epoch.
+    ///
+    /// Times before the epoch produce negative timestamps, rather than
+    /// panicking.
+    fn into_timestamp(self) -> i64 {
+        match self.duration_since(UNIX_EPOCH) {
+            Ok(since) => clamp_secs(since.as_secs()),
+            Err(why) => -clamp_secs(why.duration().as_secs()),
+        }
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: TimeZone> IntoTimestamp for DateTime<Tz> {
+    fn into_timestamp(self) -> i64 {
+        let secs = self.timestamp();
+
+        // chrono floors negative timestamps, so round them towards zero to
+        // match the truncation of the other implementations.
+        if secs < 0 && self.timestamp_subsec_nanos() > 0 {
+            secs + 1
+        } else {
+            secs
+        }
+    }
+}
+
+fn clamp_secs(secs: u64) -> i64 {
+    if secs > i64::MAX as u64 {
+        i64::MAX
+    } else {
+        secs as i64
+    }
+}
@@ -0,0 +1,102 @@
+extern crate serenity_utils;
+
+use serenity_utils::{GuildNavigation, Mention, MessageBuilder, ReactionType, TimestampStyle};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+/// Counts the allocations made on the current thread while counting is
+/// enabled, so that other tests' threads don't interfere.
+struct CountingAllocator;
+
+thread_local! {
+    static COUNTING: Cell<bool> = const { Cell::new(false) };
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        if COUNTING.with(Cell::get) {
+            ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        }
+
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) { System.dealloc(ptr, layout) }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn count_allocations<F: FnOnce() -> MessageBuilder>(f: F) -> (MessageBuilder, usize) {
+    ALLOCATIONS.with(|count| count.set(0));
+    COUNTING.with(|counting| counting.set(true));
+    let builder = f();
+    COUNTING.with(|counting| counting.set(false));
+
+    (builder, ALLOCATIONS.with(Cell::get))
+}
+
+#[test]
+fn id_pushes_do_not_allocate() {
+    let emoji = ReactionType::Custom {
+        animated: true,
+        id: 394374184616493057,
+        name: "ferris".to_string(),
+    };
+    let builder = MessageBuilder::with_capacity(4096);
+
+    let (builder, allocations) = count_allocations(move || {
+        let mut builder = builder.emoji(emoji);
+
+        for id in 0..50u64 {
+            builder = builder
+                .user(114941315417899012 + id)
+                .role(81384788765712384 + id)
+                .channel(81384788765712384 - id)
+                .push_timestamp(1700000000 + id as i64, TimestampStyle::Relative);
+        }
+
+        builder
+    });
+
+    assert_eq!(allocations, 0);
+
+    let content = builder.build();
+
+    assert!(content.starts_with(
+        "<a:ferris:394374184616493057>\
+         <@114941315417899012><@&81384788765712384><#81384788765712384><t:1700000000:R>\
+         <@114941315417899013><@&81384788765712385><#81384788765712383><t:1700000001:R>",
+    ));
+    assert!(content.ends_with(
+        "<@114941315417899061><@&81384788765712433><#81384788765712335><t:1700000049:R>",
+    ));
+}
+
+#[test]
+fn mentions_do_not_allocate() {
+    let mentions = (0..50u64)
+        .flat_map(|id| vec![Mention::User(id), Mention::Role(id), Mention::Channel(id)])
+        .collect::<Vec<_>>();
+    let emoji = ReactionType::from("👋");
+    let builder = MessageBuilder::with_capacity(4096);
+
+    let (builder, allocations) = count_allocations(|| {
+        let mut builder = builder.mention_join(&mentions, " ");
+
+        for _ in 0..50 {
+            builder = builder.mention(&emoji).mention(GuildNavigation::Guide);
+        }
+
+        builder
+    });
+
+    assert_eq!(allocations, 0);
+
+    let content = builder.build();
+
+    assert!(content.starts_with("<@0> <@&0> <#0> <@1> <@&1> <#1>"));
+    assert!(content.contains("<@49> <@&49> <#49>👋<id:guide>👋"));
+    assert_eq!(content.matches("👋<id:guide>").count(), 50);
+}
@@ -0,0 +1,19 @@
+extern crate serenity_utils;
+
+use serenity_utils::{AnsiBuilder, AnsiColour, AnsiStyle};
+
+#[test]
+fn escape_bytes() {
+    let text = AnsiBuilder::new()
+        .push_styled("a", &[AnsiStyle::Underline, AnsiStyle::Background(AnsiColour::Blue)])
+        .push_styled("b", &[AnsiStyle::Foreground(AnsiColour::Black)])
+        .push_styled("c", &[AnsiStyle::Foreground(AnsiColour::White)])
+        .push_styled("d", &[])
+        .push_styled("", &[AnsiStyle::Bold])
+        .build();
+
+    assert_eq!(
+        text.as_bytes(),
+        &b"\x1b[4;44ma\x1b[0m\x1b[30mb\x1b[0m\x1b[37mc\x1b[0md"[..],
+    );
+}
@@ -0,0 +1,362 @@
+extern crate serenity_utils;
+
+use serenity_utils::{parse_username, ArgError, Args, Delimiter};
+use std::error::Error;
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+fn collect<'a>(mut args: Args<'a>) -> Vec<&'a str> {
+    let mut all = Vec::new();
+
+    while let Some(arg) = args.current() {
+        all.push(arg);
+        args.advance();
+    }
+
+    all
+}
+
+#[test]
+fn splitting() {
+    let args = Args::new("a b  c   d", &[' '.into()]);
+
+    assert_eq!(args.len(), 4);
+    assert_eq!(collect(args), ["a", "b", "c", "d"]);
+    assert_eq!(collect(Args::new("  a  b  ", &[' '.into()])), ["a", "b"]);
+    assert_eq!(collect(Args::new("a", &[' '.into()])), ["a"]);
+    assert_eq!(collect(Args::new("a b", &[])), ["a b"]);
+}
+
+#[test]
+fn multiple_delimiters() {
+    let delimiters = [", ".into(), ','.into(), Delimiter::from(' ')];
+
+    assert_eq!(collect(Args::new("a, b,c d,, e", &delimiters)), ["a", "b", "c", "d", "e"]);
+    assert_eq!(collect(Args::new("ねこ—いぬ—とり", &['—'.into()])), ["ねこ", "いぬ", "とり"]);
+    assert_eq!(collect(Args::new("a::b:c", &["::".into()])), ["a", "b:c"]);
+    assert_eq!(collect(Args::new("a b", &["".into(), ' '.into()])), ["a", "b"]);
+}
+
+#[test]
+fn strict_splitting() {
+    let delimiters = [','.into()];
+
+    assert_eq!(collect(Args::strict("a,,b", &delimiters)), ["a", "", "b"]);
+    assert_eq!(collect(Args::strict(",a,", &delimiters)), ["", "a", ""]);
+    assert_eq!(collect(Args::strict(",", &delimiters)), ["", ""]);
+    assert_eq!(collect(Args::strict("a", &delimiters)), ["a"]);
+    assert!(Args::strict("", &delimiters).is_empty());
+}
+
+#[test]
+fn empty_content() {
+    for content in &["", "   "] {
+        let mut args = Args::new(content, &[' '.into()]);
+
+        assert!(args.is_empty());
+        assert_eq!(args.remaining(), 0);
+        assert_eq!(args.current(), None);
+        assert_eq!(args.rest(), "");
+        assert_eq!(args.advance().current(), None);
+    }
+}
+
+#[test]
+fn remaining_and_rest() {
+    let mut args = Args::new("  ban <@1>  7 days ", &[' '.into()]);
+
+    assert_eq!(args.remaining(), 4);
+    assert_eq!(args.rest(), "ban <@1>  7 days ");
+
+    args.advance();
+    assert_eq!(args.remaining(), 3);
+    assert_eq!(args.rest(), "<@1>  7 days ");
+
+    args.advance().advance().advance();
+    assert_eq!(args.remaining(), 0);
+    assert_eq!(args.rest(), "");
+
+    args.advance();
+    assert_eq!(args.remaining(), 0);
+
+    args.rewind();
+    assert_eq!(args.current(), Some("ban"));
+    assert_eq!(args.len(), 4);
+    assert_eq!(args.message(), "  ban <@1>  7 days ");
+}
+
+fn collect_quoted(mut args: Args) -> Vec<String> {
+    let mut all = Vec::new();
+
+    while let Some(arg) = args.current_quoted() {
+        all.push(arg.into_owned());
+        args.advance();
+    }
+
+    all
+}
+
+#[test]
+fn quoted_arguments() {
+    let args = Args::new(r#"say "hello there"  "" friend"#, &[' '.into()]);
+
+    assert_eq!(args.len(), 4);
+    assert_eq!(collect(args.clone()), ["say", r#""hello there""#, r#""""#, "friend"]);
+    assert_eq!(collect_quoted(args), ["say", "hello there", "", "friend"]);
+}
+
+#[test]
+fn smart_quotes() {
+    let args = Args::new("“hello there” \"mixed” “mixed again\"", &[' '.into()]);
+
+    assert_eq!(collect_quoted(args), ["hello there", "mixed", "mixed again"]);
+}
+
+#[test]
+fn escaped_quotes() {
+    let args = Args::new(r#""say \"hi\" now" "a\\b" "end\”""#, &[' '.into()]);
+
+    assert_eq!(collect_quoted(args.clone()), [r#"say "hi" now"#, r"a\\b", "end”"]);
+    assert_eq!(args.current(), Some(r#""say \"hi\" now""#));
+}
+
+#[test]
+fn unterminated_quotes() {
+    let mut args = Args::new(r#"one "two three \" four"#, &[' '.into()]);
+
+    assert_eq!(args.len(), 2);
+    assert_eq!(args.advance().current(), Some(r#""two three \" four"#));
+    assert_eq!(args.current_quoted().unwrap(), r#"two three " four"#);
+
+    assert_eq!(collect_quoted(Args::new("\"", &[' '.into()])), [""]);
+    assert_eq!(collect_quoted(Args::new("a “b c", &[' '.into()])), ["a", "b c"]);
+}
+
+#[test]
+fn nested_looking_quotes() {
+    let args = Args::new(r#""say "hi" now" x"#, &[' '.into()]);
+
+    assert_eq!(collect(args.clone()), [r#""say ""#, r#"hi""#, r#"now""#, "x"]);
+    assert_eq!(collect_quoted(args), ["say ", r#"hi""#, r#"now""#, "x"]);
+
+    let args = Args::new(r#"it's 5" long"#, &[' '.into()]);
+    assert_eq!(collect_quoted(args), ["it's", r#"5""#, "long"]);
+}
+
+#[test]
+fn quote_followed_by_delimiter() {
+    let delimiters = [','.into()];
+
+    assert_eq!(collect_quoted(Args::new(r#""a,b",c"#, &delimiters)), ["a,b", "c"]);
+    assert_eq!(collect_quoted(Args::strict(r#""a,b",c"#, &delimiters)), ["a,b", "c"]);
+    assert_eq!(collect_quoted(Args::strict(r#""a",,"b""#, &delimiters)), ["a", "", "b"]);
+    assert_eq!(collect_quoted(Args::strict(r#""a","#, &delimiters)), ["a", ""]);
+    assert_eq!(collect_quoted(Args::strict(r#""a""#, &delimiters)), ["a"]);
+    assert_eq!(collect_quoted(Args::new(r#""a"b c"#, &[' '.into()])), ["a", "b", "c"]);
+}
+
+/// A user Id, parsed from a mention as a command framework would.
+#[derive(Debug, PartialEq)]
+struct UserId(u64);
+
+impl FromStr for UserId {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<UserId, &'static str> {
+        parse_username(s).or_else(|| s.parse().ok()).map(UserId).ok_or("Invalid user")
+    }
+}
+
+#[test]
+fn typed_arguments() {
+    let mut args = Args::new("<@!12345> 7 spamming", &[' '.into()]);
+
+    assert_eq!(args.single::<UserId>(), Ok(UserId(12_345)));
+    assert_eq!(args.single::<u32>(), Ok(7));
+    assert_eq!(args.single::<String>(), Ok("spamming".to_string()));
+    assert_eq!(args.single::<String>(), Err(ArgError::Eos));
+    assert_eq!(args.single_quoted::<String>(), Err(ArgError::Eos));
+}
+
+#[test]
+fn failed_parses_do_not_advance() {
+    let mut args = Args::new("seven 7", &[' '.into()]);
+
+    match args.single::<u32>() {
+        Err(ArgError::Parse(err)) => assert_eq!(err, "seven".parse::<u32>().unwrap_err()),
+        other => panic!("{:?}", other),
+    }
+
+    assert_eq!(args.single::<UserId>(), Err(ArgError::Parse("Invalid user")));
+    assert_eq!(args.current(), Some("seven"));
+    assert_eq!(args.single::<String>(), Ok("seven".to_string()));
+    assert_eq!(args.single::<u32>(), Ok(7));
+}
+
+#[test]
+fn typed_quoted_arguments() {
+    let mut args = Args::new(r#""<@12345>" "2 words" "3""#, &[' '.into()]);
+
+    assert_eq!(args.single::<UserId>(), Err(ArgError::Parse("Invalid user")));
+    assert_eq!(args.single_quoted::<UserId>(), Ok(UserId(12_345)));
+    assert_eq!(args.single::<String>(), Ok(r#""2 words""#.to_string()));
+    assert!(args.single::<u8>().is_err());
+    assert_eq!(args.single_quoted::<u8>(), Ok(3));
+}
+
+#[test]
+fn arg_errors() {
+    let eos: ArgError<ParseIntError> = ArgError::Eos;
+    let parse = "x".parse::<u8>().map_err(ArgError::Parse).unwrap_err();
+
+    assert_eq!(eos.to_string(), "No arguments left");
+    assert_eq!(parse.to_string(), "Failed to parse argument: invalid digit found in string");
+    assert!(eos.source().is_none());
+    assert!(parse.source().is_some());
+}
+
+#[test]
+fn iterating() {
+    let mut args = Args::new("1 2 x 3", &[' '.into()]);
+    args.advance();
+
+    let parsed = args.iter::<u8>().collect::<Vec<_>>();
+
+    assert_eq!(parsed.len(), 3);
+    assert_eq!(parsed[0], Ok(2));
+    assert!(parsed[1].is_err());
+    assert_eq!(parsed[2], Ok(3));
+    assert_eq!(args.remaining(), 0);
+    assert_eq!(args.iter::<u8>().next(), None);
+
+    let mut args = Args::new(r#"1 "2" "a b""#, &[' '.into()]);
+    assert_eq!(args.iter::<String>().size_hint(), (3, Some(3)));
+
+    let all = args.iter::<String>().quoted().collect::<Result<Vec<_>, _>>().unwrap();
+    assert_eq!(all, ["1", "2", "a b"]);
+}
+
+#[test]
+fn multiple_arguments() {
+    let mut args = Args::new("roll 1 2 3", &[' '.into()]);
+
+    assert_eq!(args.multiple::<u8>().map_err(|err| err.index), Err(0));
+    assert_eq!(args.advance().multiple::<u8>(), Ok(vec![1, 2, 3]));
+    assert_eq!(args.remaining(), 0);
+    assert_eq!(args.multiple::<u8>(), Ok(vec![]));
+}
+
+#[test]
+fn multiple_fails_fast() {
+    let mut args = Args::new("roll 1 x 2 y", &[' '.into()]);
+    args.advance();
+
+    let err = args.multiple::<u8>().unwrap_err();
+
+    assert_eq!(err.index, 2);
+    assert_eq!(err.error, "x".parse::<u8>().unwrap_err());
+    assert_eq!(err.to_string(), "Failed to parse argument 2: invalid digit found in string");
+    assert_eq!(args.current(), Some("1"));
+    assert_eq!(args.remaining(), 4);
+}
+
+#[test]
+fn multiple_quoted_arguments() {
+    let mut args = Args::new(r#""1" 2 “3”"#, &[' '.into()]);
+
+    assert_eq!(args.multiple::<u8>().map_err(|err| err.index), Err(0));
+    assert_eq!(args.multiple_quoted::<u8>(), Ok(vec![1, 2, 3]));
+}
+
+#[test]
+fn rest_after_quotes() {
+    let mut args = Args::new(r#""role name" rest of it"#, &[' '.into()]);
+    args.advance();
+
+    assert_eq!(args.rest(), "rest of it");
+
+    let mut args = Args::new(r#""a b","c d" , e"#, &[','.into(), ' '.into()]);
+    assert_eq!(args.single_quoted::<String>(), Ok("a b".to_string()));
+    assert_eq!(args.rest(), r#""c d" , e"#);
+    assert_eq!(args.single_quoted::<String>(), Ok("c d".to_string()));
+    assert_eq!(args.rest(), "e");
+
+    let mut args = Args::new(r#""ab"cd"#, &[' '.into()]);
+    args.advance();
+    assert_eq!(args.rest(), "cd");
+}
+
+#[derive(Debug, PartialEq)]
+struct Flag(&'static str);
+
+impl FromStr for Flag {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Flag, ()> {
+        match s {
+            "--silent" => Ok(Flag("silent")),
+            "--force" => Ok(Flag("force")),
+            _ => Err(()),
+        }
+    }
+}
+
+#[test]
+fn finding() {
+    let mut args = Args::new("50 --silent spam --force", &[' '.into()]);
+
+    assert_eq!(args.single::<u32>(), Ok(50));
+    assert_eq!(args.find::<Flag>(), Some(Flag("silent")));
+    assert_eq!(args.len(), 3);
+    assert_eq!(args.remaining(), 2);
+    assert_eq!(args.current(), Some("spam"));
+    assert_eq!(args.find::<Flag>(), Some(Flag("force")));
+    assert_eq!(args.find::<Flag>(), None);
+    assert_eq!(args.single::<String>(), Ok("spam".to_string()));
+    assert_eq!(args.single::<String>(), Err(ArgError::Eos));
+}
+
+#[test]
+fn finding_skips_consumed_arguments() {
+    let mut args = Args::new("--force 1 2 --force", &[' '.into()]);
+    args.advance();
+
+    assert_eq!(args.find::<u8>(), Some(1));
+    assert_eq!(args.current(), Some("2"));
+    assert_eq!(args.find::<Flag>(), Some(Flag("force")));
+    assert_eq!(args.remaining(), 1);
+
+    args.rewind();
+    assert_eq!(args.multiple::<String>().unwrap(), ["--force", "2"]);
+}
+
+#[test]
+fn interleaved_find_and_single() {
+    let mut args = Args::new("--silent a 1 b --force c 2", &[' '.into()]);
+
+    assert_eq!(args.find::<u8>(), Some(1));
+    assert_eq!(args.single::<Flag>(), Ok(Flag("silent")));
+    assert_eq!(args.single::<String>(), Ok("a".to_string()));
+    assert_eq!(args.find::<Flag>(), Some(Flag("force")));
+    assert_eq!(args.single::<String>(), Ok("b".to_string()));
+    assert_eq!(args.find::<u8>(), Some(2));
+    assert_eq!(args.single::<String>(), Ok("c".to_string()));
+    assert_eq!(args.remaining(), 0);
+    assert_eq!(args.find::<String>(), None);
+
+    args.rewind();
+    assert_eq!(args.multiple::<String>().unwrap(), ["--silent", "a", "b", "c"]);
+}
+
+#[test]
+fn finding_without_removing() {
+    let mut args = Args::new("a --force b", &[' '.into()]);
+
+    assert_eq!(args.find_n::<Flag>(), Some(Flag("force")));
+    assert_eq!(args.find_n::<Flag>(), Some(Flag("force")));
+    assert_eq!(args.find_n::<u8>(), None);
+    assert_eq!(args.len(), 3);
+    assert_eq!(args.single::<String>(), Ok("a".to_string()));
+    assert_eq!(args.single::<Flag>(), Ok(Flag("force")));
+    assert_eq!(args.find_n::<Flag>(), None);
+}
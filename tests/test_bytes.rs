@@ -0,0 +1,75 @@
+extern crate serenity_utils;
+
+use serenity_utils::{ByteStyle, MessageBuilder};
+
+fn bytes(bytes: u64, binary: bool, decimals: usize) -> String {
+    let style = ByteStyle {
+        binary,
+        decimals,
+    };
+
+    MessageBuilder::new().push_bytes_styled(bytes, &style).build()
+}
+
+#[test]
+fn binary_boundaries() {
+    const KIB: u64 = 1 << 10;
+    const MIB: u64 = 1 << 20;
+    const GIB: u64 = 1 << 30;
+
+    let cases = [
+        (0, "0 B"),
+        (1, "1 B"),
+        (KIB - 1, "1023 B"),
+        (KIB, "1.0 KiB"),
+        (KIB + 51, "1.0 KiB"),
+        (KIB + 52, "1.1 KiB"),
+        (MIB - 52, "1023.9 KiB"),
+        (MIB - 51, "1.0 MiB"),
+        (MIB - 1, "1.0 MiB"),
+        (MIB, "1.0 MiB"),
+        (3_565_158, "3.4 MiB"),
+        (GIB - 1, "1.0 GiB"),
+        (GIB + 1, "1.0 GiB"),
+        (1 << 40, "1.0 TiB"),
+        (1 << 50, "1.0 PiB"),
+        (1 << 60, "1.0 EiB"),
+        (u64::MAX, "16.0 EiB"),
+    ];
+
+    for &(size, expected) in &cases {
+        assert_eq!(MessageBuilder::new().push_bytes(size).build(), expected, "{} bytes", size);
+    }
+}
+
+#[test]
+fn decimal_boundaries() {
+    let cases = [
+        (0, "0 B"),
+        (999, "999 B"),
+        (1_000, "1.00 KB"),
+        (1_004, "1.00 KB"),
+        (1_005, "1.01 KB"),
+        (999_994, "999.99 KB"),
+        (999_995, "1.00 MB"),
+        (1_000_001, "1.00 MB"),
+        (999_999_999, "1.00 GB"),
+        (1_000_000_000_000, "1.00 TB"),
+        (u64::MAX, "18.45 EB"),
+    ];
+
+    for &(size, expected) in &cases {
+        assert_eq!(bytes(size, false, 2), expected, "{} bytes", size);
+    }
+}
+
+#[test]
+fn decimal_places() {
+    assert_eq!(bytes(1_536, true, 0), "2 KiB");
+    assert_eq!(bytes(1_535, true, 0), "1 KiB");
+    assert_eq!(bytes(1_023, true, 3), "1023 B");
+    assert_eq!(bytes(1 << 20, true, 3), "1.000 MiB");
+    assert_eq!(bytes(1 << 20, true, 40), format!("1.{} MiB", "0".repeat(18)));
+    assert_eq!(bytes(u64::MAX, true, 18), "15.999999999999999999 EiB");
+    assert_eq!(ByteStyle::new(), ByteStyle::default());
+}
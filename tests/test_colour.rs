@@ -0,0 +1,135 @@
+extern crate serenity_utils;
+
+use serenity_utils::{Colour, ParseColourError};
+
+#[test]
+fn hex_forms() {
+    for code in &["#7289DA", "7289DA", "0x7289DA", "0X7289da", "#7289da"] {
+        assert_eq!(Colour::hex(code), Ok(Colour::new(0x7289DA)), "{}", code);
+    }
+
+    assert_eq!(Colour::hex("#FF"), Ok(Colour::new(0xFF)));
+    assert_eq!(Colour::hex("FFFFFF"), Ok(Colour::new(0xFFFFFF)));
+    assert_eq!("#000000".parse::<Colour>(), Ok(Colour::default()));
+}
+
+#[test]
+fn hex_short_input() {
+    for code in &["", "#", "0x"] {
+        assert_eq!(Colour::hex(code), Err(ParseColourError::Empty), "{:?}", code);
+    }
+}
+
+#[test]
+fn hex_invalid_characters() {
+    assert_eq!(Colour::hex("#ZZZZZZ"), Err(ParseColourError::InvalidCharacter('Z')));
+    assert_eq!(Colour::hex("# 7289DA"), Err(ParseColourError::InvalidCharacter(' ')));
+    assert_eq!(Colour::hex("##7289DA"), Err(ParseColourError::InvalidCharacter('#')));
+    assert_eq!(Colour::hex("+7289DA"), Err(ParseColourError::InvalidCharacter('+')));
+    assert_eq!(Colour::hex("72é9DA"), Err(ParseColourError::InvalidCharacter('é')));
+}
+
+#[test]
+fn hex_too_large() {
+    assert_eq!(Colour::hex("#1000000"), Err(ParseColourError::TooLarge));
+    assert_eq!(Colour::hex("FFFFFFFF"), Err(ParseColourError::TooLarge));
+    assert_eq!(Colour::hex("FFFFFFFFFFFFFFFFFFFF"), Err(ParseColourError::TooLarge));
+    assert_eq!(Colour::hex("#0FFFFFF"), Ok(Colour::new(0xFFFFFF)));
+}
+
+#[test]
+fn integer_conversions() {
+    let colour = Colour::from_rgb(17, 128, 106);
+
+    assert_eq!(u32::from(colour), 0x11806A);
+    assert_eq!(Colour::from(0x11806Au32), colour);
+    assert_eq!(colour.tuple(), (17, 128, 106));
+
+    let value: u32 = colour.into();
+
+    assert_eq!(value, colour.0);
+}
+
+#[test]
+fn constants() {
+    assert_eq!(Colour::BLURPLE.0, 0x7289DA);
+    assert_eq!(Colour::DARK_RED.0, 0x992D22);
+    assert_eq!(Colour::RED.0, 0xE74C3C);
+    assert_eq!(Colour::ORANGE.0, 0xE67E22);
+    assert_eq!(Colour::GOLD.0, 0xF1C40F);
+    assert_eq!(Colour::TEAL.0, 0x1ABC9C);
+    assert_eq!(Colour::DARK_PURPLE.0, 0x71368A);
+    assert_eq!(Colour::BLACK.0, 0x000000);
+    assert_eq!(Colour::WHITE.0, 0xFFFFFF);
+
+    assert_eq!(Colour::dark_red(), Colour::DARK_RED);
+    assert_eq!(Colour::white(), Colour::WHITE);
+}
+
+#[test]
+fn constant_names() {
+    assert_eq!(Colour::BLURPLE.name(), Some("BLURPLE"));
+    assert_eq!(Colour::from_rgb(153, 45, 34).name(), Some("DARK_RED"));
+    assert_eq!(Colour::default().name(), Some("BLACK"));
+    assert_eq!(Colour::hex("#FFFFFF").unwrap().name(), Some("WHITE"));
+    assert_eq!(Colour::new(0x7289DB).name(), None);
+    assert_eq!(Colour::new(0x1000000).name(), None);
+}
+
+#[test]
+fn hsl_degenerate_points() {
+    assert_eq!(Colour::BLACK.to_hsl(), (0.0, 0.0, 0.0));
+    assert_eq!(Colour::WHITE.to_hsl(), (0.0, 0.0, 1.0));
+    assert_eq!(Colour::from_rgb(128, 128, 128).to_hsl(), (0.0, 0.0, 256.0 / 510.0));
+    assert_eq!(Colour::from_rgb(255, 0, 0).to_hsl(), (0.0, 1.0, 0.5));
+    assert_eq!(Colour::from_rgb(0, 255, 0).to_hsl(), (120.0, 1.0, 0.5));
+    assert_eq!(Colour::from_rgb(0, 0, 255).to_hsl(), (240.0, 1.0, 0.5));
+    assert_eq!(Colour::from_rgb(255, 0, 255).to_hsl(), (300.0, 1.0, 0.5));
+
+    assert_eq!(Colour::from_hsl(0.0, 0.0, 0.0), Colour::BLACK);
+    assert_eq!(Colour::from_hsl(0.0, 1.0, 1.0), Colour::WHITE);
+    assert_eq!(Colour::from_hsl(123.0, 0.0, 0.5), Colour::from_rgb(128, 128, 128));
+    assert_eq!(Colour::from_hsl(0.0, 1.0, 0.5), Colour::from_rgb(255, 0, 0));
+    assert_eq!(Colour::from_hsl(120.0, 1.0, 0.5), Colour::from_rgb(0, 255, 0));
+    assert_eq!(Colour::from_hsl(240.0, 1.0, 0.5), Colour::from_rgb(0, 0, 255));
+    assert_eq!(Colour::from_hsl(360.0, 1.0, 0.5), Colour::from_rgb(255, 0, 0));
+    assert_eq!(Colour::from_hsl(-120.0, 1.0, 0.5), Colour::from_rgb(0, 0, 255));
+    assert_eq!(Colour::from_hsl(0.0, 5.0, -1.0), Colour::BLACK);
+}
+
+#[test]
+fn hsl_round_trip() {
+    let steps = (0..52).map(|x| x * 5).chain(Some(255));
+
+    for r in steps.clone() {
+        for g in steps.clone() {
+            for b in steps.clone() {
+                let colour = Colour::from_rgb(r, g, b);
+                let (h, s, l) = colour.to_hsl();
+                let back = Colour::from_hsl(h, s, l);
+
+                assert!((0.0..360.0).contains(&h), "{:?} {}", colour, h);
+                assert!((0.0..=1.0).contains(&s), "{:?} {}", colour, s);
+                assert!((i16::from(back.r()) - i16::from(r)).abs() <= 1, "{:?} {:?}", colour, back);
+                assert!((i16::from(back.g()) - i16::from(g)).abs() <= 1, "{:?} {:?}", colour, back);
+                assert!((i16::from(back.b()) - i16::from(b)).abs() <= 1, "{:?} {:?}", colour, back);
+            }
+        }
+    }
+}
+
+#[test]
+fn lighten_and_darken() {
+    let colour = Colour::from_rgb(255, 0, 0);
+
+    assert_eq!(colour.lighten(0.25), Colour::from_rgb(255, 128, 128));
+    assert_eq!(colour.darken(0.25), Colour::from_rgb(128, 0, 0));
+    assert_eq!(colour.lighten(0.0), colour);
+    assert_eq!(colour.lighten(0.5), Colour::WHITE);
+    assert_eq!(colour.lighten(10.0), Colour::WHITE);
+    assert_eq!(colour.darken(10.0), Colour::BLACK);
+    assert_eq!(Colour::WHITE.lighten(0.5), Colour::WHITE);
+    assert_eq!(Colour::BLACK.darken(0.5), Colour::BLACK);
+    assert_eq!(Colour::BLACK.lighten(0.5), Colour::from_rgb(128, 128, 128));
+    assert_eq!(colour.lighten(-0.25), colour.darken(0.25));
+}
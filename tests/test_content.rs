@@ -0,0 +1,238 @@
+extern crate serenity_utils;
+
+use serenity_utils::content::{self, Style, Token, TokenKind};
+use std::slice;
+
+/// Describes tokens compactly, so that the corpus below stays readable.
+fn show(tokens: &[Token]) -> String {
+    let shown = tokens.iter().map(|token| match token.kind {
+        TokenKind::Text(ref text) => format!("{:?}", text),
+        TokenKind::UserMention { id, nickname: false } => format!("user({})", id),
+        TokenKind::UserMention { id, nickname: true } => format!("nick({})", id),
+        TokenKind::ChannelMention(id) => format!("channel({})", id),
+        TokenKind::RoleMention(id) => format!("role({})", id),
+        TokenKind::CustomEmoji(ref emoji) => {
+            format!("emoji({}, {}, {})", emoji.name, emoji.id, emoji.animated)
+        },
+        TokenKind::Timestamp { timestamp, style: Some(style) } => {
+            format!("time({}, {})", timestamp, style)
+        },
+        TokenKind::Timestamp { timestamp, style: None } => format!("time({})", timestamp),
+        TokenKind::CommandMention { id, ref name } => format!("command({}, {})", name, id),
+        TokenKind::CodeSpan { ref code, backticks: 1 } => format!("code({:?})", code),
+        TokenKind::CodeSpan { ref code, backticks } => format!("code{}({:?})", backticks, code),
+        TokenKind::CodeBlock { ref lang, ref body } => format!("block({:?}, {:?})", lang, body),
+        TokenKind::Styled { style, ref children } => {
+            let name = match style {
+                Style::Bold => "bold",
+                Style::Italic => "italic",
+                Style::ItalicUnderscore => "italic_",
+                Style::Underline => "underline",
+                Style::Strikethrough => "strike",
+                Style::Spoiler => "spoiler",
+            };
+
+            format!("{}[{}]", name, show(children))
+        },
+    });
+
+    shown.collect::<Vec<_>>().join(" ")
+}
+
+/// Checks that the tokens cover the given range of the content in order, and
+/// that text is exactly as written.
+fn check_ranges(content: &str, tokens: &[Token], mut start: usize, end: usize) {
+    for token in tokens {
+        assert_eq!(token.range.start, start, "{:?} in {:?}", token, content);
+
+        match token.kind {
+            TokenKind::Text(ref text) => assert_eq!(&content[token.range.clone()], text),
+            TokenKind::Styled { style, ref children } => {
+                let marker = style.marker().len();
+
+                check_ranges(content, children, start + marker, token.range.end - marker);
+            },
+            _ => {},
+        }
+
+        start = token.range.end;
+    }
+
+    assert_eq!(start, end, "{:?}", content);
+}
+
+const CORPUS: &[(&str, &str)] = &[
+    ("", ""),
+    ("plain text", r#""plain text""#),
+    ("hi <@114941315417899012>!", r#""hi " user(114941315417899012) "!""#),
+    ("<@!114941315417899012><#81384788765712384><@&136107769680887808>",
+        "nick(114941315417899012) channel(81384788765712384) role(136107769680887808)"),
+    ("<:rust:302516740095606785> <a:blob:271629243716468736>",
+        r#"emoji(rust, 302516740095606785, false) " " emoji(blob, 271629243716468736, true)"#),
+    ("<t:1700000000:R> <t:-5>", r#"time(1700000000, R) " " time(-5)"#),
+    ("</ban user:123456789>", "command(ban user, 123456789)"),
+    // Malformed tags are text.
+    ("<@0123> <@abc> <t:1:Q> <:name:> <@123", r#""<@0123> <@abc> <t:1:Q> <:name:> <@123""#),
+    ("a < b > <@1>", r#""a < b > " user(1)"#),
+    ("<id:customize>", r#""<id:customize>""#),
+    ("x <<@1>>", r#""x <" user(1) ">""#),
+    // Code.
+    ("run `cargo test`", r#""run " code("cargo test")"#),
+    ("`` `tick` ``", r#"code2(" `tick` ")"#),
+    ("`a``b`", r#"code("a``b")"#),
+    ("``a``", r#"code2("a")"#),
+    ("`**not bold** <@1>`", r#"code("**not bold** <@1>")"#),
+    ("```rust\nfn main() {}\n```", r#"block(Some("rust"), "fn main() {}\n")"#),
+    ("```\nplain\n```", r#"block(None, "\nplain\n")"#),
+    ("```one line```", r#"block(None, "one line")"#),
+    ("```not a lang\nx```", r#"block(None, "not a lang\nx")"#),
+    ("```unclosed", r#""```unclosed""#),
+    ("`unclosed", r#""`unclosed""#),
+    ("`` double `", r#""`` double `""#),
+    ("``", r#""``""#),
+    // Styles.
+    ("**bold** *it* _it_ __under__",
+        r#"bold["bold"] " " italic["it"] " " italic_["it"] " " underline["under"]"#),
+    ("~~strike~~ ||spoiler||", r#"strike["strike"] " " spoiler["spoiler"]"#),
+    ("***both***", r#"bold[italic["both"]]"#),
+    ("**bold *and italic***", r#"bold["bold " italic["and italic"]]"#),
+    ("__*nested* <@1>__", r#"underline[italic["nested"] " " user(1)]"#),
+    ("||spoiler `code` ||", r#"spoiler["spoiler " code("code") " "]"#),
+    // Markers which don't open or close anything are text.
+    ("2 * 3 * 4", r#""2 * 3 * 4""#),
+    ("snake_case_name", r#""snake_case_name""#),
+    ("**unclosed *italic*", r#""**unclosed " italic["italic"]"#),
+    ("****", r#""****""#),
+    ("~ | ~~~", r#""~ | ~~~""#),
+    ("**a __b** c__", r#"bold["a __b"] " c__""#),
+    // Escapes.
+    ("\\*not italic\\*", r#""\\*not italic\\*""#),
+    ("\\<@1> \\`code\\`", r#""\\<@1> \\`code\\`""#),
+    ("trailing \\", r#""trailing \\""#),
+    // Unicode.
+    ("**ünïcødé** 👍🏽 <@1>", r#"bold["ünïcødé"] " 👍🏽 " user(1)"#),
+];
+
+#[test]
+fn corpus() {
+    for &(content, expected) in CORPUS {
+        let tokens = content::parse(content);
+
+        assert_eq!(show(&tokens), expected, "{:?}", content);
+        check_ranges(content, &tokens, 0, content.len());
+    }
+}
+
+#[test]
+fn round_trip() {
+    let inputs = CORPUS.iter().map(|&(content, _)| content);
+
+    for content in inputs.chain(vec!["`a``b`", "``a``", "<@!114941315417899012>"]) {
+        assert_eq!(content::render(&content::parse(content)), content);
+    }
+}
+
+#[test]
+fn render_changed_tokens() {
+    let mut tokens = content::parse("**<@1>** `x` ```py\nprint()```");
+
+    tokens.retain(|token| !matches!(token.kind, TokenKind::CodeBlock { .. }));
+    tokens[2] = Token {
+        kind: TokenKind::CodeSpan {
+            code: "a`b".to_string(),
+            backticks: 1,
+        },
+        range: 0..0,
+    };
+    tokens.push(Token {
+        kind: TokenKind::CodeBlock {
+            lang: Some("rust".to_string()),
+            body: "fn main() {}\n".to_string(),
+        },
+        range: 0..0,
+    });
+
+    assert_eq!(content::render(&tokens), "**<@1>** ``a`b`` ```rust\nfn main() {}\n```");
+
+    // A fence which would close early is replaced.
+    let span = |code: &str, backticks| Token {
+        kind: TokenKind::CodeSpan {
+            code: code.to_string(),
+            backticks,
+        },
+        range: 0..0,
+    };
+    assert_eq!(content::render(&[span("a``b", 2)]), "`a``b`");
+    assert_eq!(content::render(&[span("plain", 0)]), "`plain`");
+    assert_eq!(content::render(&[span("plain", 2)]), "``plain``");
+}
+
+#[test]
+fn map_tokens() {
+    let content = "**<@1>: see <#2>** <t:1700000000:R> ||<@&3>||";
+
+    let dropped = content::map_tokens(content, |token| match token.kind {
+        TokenKind::UserMention { .. } | TokenKind::RoleMention(_) => None,
+        _ => Some(token),
+    });
+    assert_eq!(dropped, "**: see <#2>** <t:1700000000:R> ||||");
+
+    // Styles are passed through after their contents.
+    let mut seen = vec![];
+    let unchanged = content::map_tokens("__a *b*__", |token| {
+        seen.push(content::render(slice::from_ref(&token)));
+
+        Some(token)
+    });
+    assert_eq!(unchanged, "__a *b*__");
+    assert_eq!(seen, ["a ", "b", "*b*", "__a *b*__"]);
+}
+
+#[test]
+fn ranges() {
+    let tokens = content::parse("a **<@1>** `b`");
+
+    let ranges = tokens.iter().map(|token| token.range.clone()).collect::<Vec<_>>();
+    assert_eq!(ranges, [0..2, 2..10, 10..11, 11..14]);
+
+    match tokens[1].kind {
+        TokenKind::Styled { ref children, .. } => assert_eq!(children[0].range, 4..8),
+        _ => panic!("expected bold"),
+    }
+}
+
+#[test]
+fn unclosed_markers() {
+    assert!(content::unclosed_markers("").is_empty());
+    assert!(content::unclosed_markers("***x*** _a_ `b` ```c```").is_empty());
+    assert!(content::unclosed_markers("snake_case, 2 * 3 = 6, a * b, x_").is_empty());
+    assert!(content::unclosed_markers(r"\*\* `**` ```\n~~\n```").is_empty());
+
+    assert_eq!(content::unclosed_markers("**a *b**"), [(0, "**")]);
+    assert_eq!(content::unclosed_markers("**a ~~b**"), [(4, "~~")]);
+    assert_eq!(content::unclosed_markers("a ~~b __c"), [(2, "~~"), (6, "__")]);
+    assert_eq!(content::unclosed_markers("```rust\nfn main() {}"), [(0, "```")]);
+    assert_eq!(content::unclosed_markers("a ``b"), [(2, "``")]);
+}
+
+#[test]
+fn never_panics_and_round_trips() {
+    let pieces = [
+        "*", "**", "_", "__", "~~", "||", "`", "```", "\\", "<", ">", "<@", "1", "é", "\n", " ",
+    ];
+
+    // Every sequence of up to three pieces, which covers every boundary
+    // between markers, tags, and multi-byte characters.
+    for a in &pieces {
+        for b in &pieces {
+            for c in &pieces {
+                let content = format!("{}{}{}", a, b, c);
+
+                let tokens = content::parse(&content);
+
+                check_ranges(&content, &tokens, 0, content.len());
+                assert_eq!(content::render(&tokens), content);
+            }
+        }
+    }
+}
@@ -0,0 +1,157 @@
+extern crate serenity_utils;
+
+use serenity_utils::{content_safe, ContentSafeOptions, ResolvedUser};
+
+fn options() -> ContentSafeOptions {
+    ContentSafeOptions::new()
+        .user(1, "alice")
+        .role(2, "Mods")
+        .channel(3, "general")
+}
+
+#[test]
+fn replaces_mentions() {
+    let content = "<@1> <@!1> <@&2> <#3> <@4> <@&5> <#6>";
+
+    assert_eq!(
+        content_safe(content, &options()),
+        "@alice @alice @Mods #general @invalid-user @deleted-role #deleted-channel",
+    );
+}
+
+#[test]
+fn defuses_mass_mentions() {
+    let content = "@everyone @here @Everyone x@here @hero";
+
+    assert_eq!(
+        content_safe(content, &options()),
+        "@\u{200B}everyone @\u{200B}here @\u{200B}Everyone x@\u{200B}here @hero",
+    );
+}
+
+#[test]
+fn defuses_names() {
+    let options = ContentSafeOptions::new()
+        .user(1, "everyone")
+        .role(2, "<@3> and @here");
+
+    assert_eq!(
+        content_safe("<@1> <@&2>", &options),
+        "@\u{200B}everyone @<\u{200B}@3> and @\u{200B}here",
+    );
+}
+
+#[test]
+fn leaves_code_untouched() {
+    let content = "<@1> `<@1> @everyone` ``a ` <@1>`` ```\n<@&2>\n@here\n``` <#3>";
+
+    assert_eq!(
+        content_safe(content, &options()),
+        "@alice `<@1> @everyone` ``a ` <@1>`` ```\n<@&2>\n@here\n``` #general",
+    );
+}
+
+#[test]
+fn escaped_backticks_are_not_code() {
+    assert_eq!(content_safe("\\`<@1>` hi", &options()), "\\`@alice` hi");
+    assert_eq!(
+        content_safe("\\``<@1>` \\\\`<@1>`", &options()),
+        "\\``<@1>` \\\\`<@1>`",
+    );
+    assert_eq!(content_safe("\\<@1> \\@everyone", &options()), "\\@alice \\@\u{200B}everyone");
+}
+
+#[test]
+fn unclosed_code_is_not_code() {
+    assert_eq!(content_safe("`<@1>", &options()), "`@alice");
+    assert_eq!(content_safe("```<@1>``", &options()), "```@alice``");
+}
+
+#[test]
+fn leaves_other_tags_alone() {
+    let content = "<:cat:12> <t:100:R> <@abc> <#> <@1 <id:guide> </ping:7>";
+
+    assert_eq!(content_safe(content, &options()), content);
+}
+
+#[test]
+fn option_combinations() {
+    let content = "<@1> <@&2> <#3> @everyone @here";
+    let cases = vec![
+        (options(), "@alice @Mods #general @\u{200B}everyone @\u{200B}here"),
+        (options().clean_user(false), "<@1> @Mods #general @\u{200B}everyone @\u{200B}here"),
+        (options().clean_role(false), "@alice <@&2> #general @\u{200B}everyone @\u{200B}here"),
+        (options().clean_channel(false), "@alice @Mods <#3> @\u{200B}everyone @\u{200B}here"),
+        (options().clean_everyone(false), "@alice @Mods #general @everyone @\u{200B}here"),
+        (options().clean_here(false), "@alice @Mods #general @\u{200B}everyone @here"),
+        (
+            options()
+                .clean_user(false)
+                .clean_role(false)
+                .clean_channel(false)
+                .clean_everyone(false)
+                .clean_here(false),
+            content,
+        ),
+    ];
+
+    for (options, expected) in cases {
+        assert_eq!(content_safe(content, &options), expected, "{:?}", options);
+    }
+}
+
+#[test]
+fn user_names() {
+    let user = ResolvedUser {
+        discriminator: Some(42),
+        global_name: Some("Alice".to_string()),
+        name: "alice".to_string(),
+        nickname: Some("Al".to_string()),
+    };
+    let plain = ResolvedUser {
+        discriminator: Some(0),
+        ..ResolvedUser::from("bob")
+    };
+    let options = ContentSafeOptions::new().user(1, user).user(2, plain);
+    let content = "<@1> <@2>";
+
+    assert_eq!(content_safe(content, &options), "@Al @bob");
+    assert_eq!(content_safe(content, &options.clone().show_nickname(false)), "@Alice @bob");
+    assert_eq!(content_safe(content, &options.clone().show_discriminator(true)), "@Al#0042 @bob");
+    assert_eq!(
+        content_safe(content, &options.show_nickname(false).show_discriminator(true)),
+        "@Alice#0042 @bob",
+    );
+}
+
+#[test]
+fn agrees_with_content_parse_on_code() {
+    use serenity_utils::content::{self, TokenKind};
+
+    let pieces = ["`", "``", "```", "\\", "a", "\n", " <@1>"];
+    let mut contents = vec![];
+    let mut level = vec![String::new()];
+
+    // Every sequence of up to five pieces.
+    for _ in 0..5 {
+        level = level
+            .iter()
+            .flat_map(|content| pieces.iter().map(move |piece| format!("{}{}", content, piece)))
+            .collect();
+        contents.extend(level.iter().cloned());
+    }
+
+    for content in &contents {
+        let in_code = content::parse(content)
+            .iter()
+            .map(|token| match token.kind {
+                TokenKind::CodeSpan { ref code, .. } => code.matches("<@1>").count(),
+                TokenKind::CodeBlock { ref body, .. } => body.matches("<@1>").count(),
+                _ => 0,
+            })
+            .sum::<usize>();
+
+        let kept = content_safe(content, &options()).matches("<@1>").count();
+        assert_eq!(kept, in_code, "{:?}", content);
+    }
+}
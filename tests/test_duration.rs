@@ -0,0 +1,75 @@
+extern crate serenity_utils;
+
+use serenity_utils::{DurationStyle, MessageBuilder};
+use std::time::Duration;
+
+fn compact(secs: u64) -> String {
+    MessageBuilder::new().push_duration(Duration::from_secs(secs)).build()
+}
+
+fn long(duration: Duration, max_components: usize) -> String {
+    let style = DurationStyle {
+        long: true,
+        max_components,
+    };
+
+    MessageBuilder::new().push_duration_styled(duration, &style).build()
+}
+
+#[test]
+fn unit_boundaries() {
+    let cases = [
+        (0, "0s"),
+        (1, "1s"),
+        (59, "59s"),
+        (60, "1m"),
+        (61, "1m 1s"),
+        (3_599, "59m 59s"),
+        (3_600, "1h"),
+        (3_601, "1h 1s"),
+        (86_399, "23h 59m 59s"),
+        (86_400, "1d"),
+        (90_061, "1d 1h 1m 1s"),
+        (188_000, "2d 4h 13m 20s"),
+        (86_400 * 400, "400d"),
+    ];
+
+    for &(secs, expected) in &cases {
+        assert_eq!(compact(secs), expected, "{} seconds", secs);
+    }
+}
+
+#[test]
+fn long_units() {
+    assert_eq!(long(Duration::from_secs(0), 4), "0 seconds");
+    assert_eq!(long(Duration::from_secs(1), 4), "1 second");
+    assert_eq!(long(Duration::from_secs(190), 4), "3 minutes, 10 seconds");
+    assert_eq!(long(Duration::from_secs(90_061), 4), "1 day, 1 hour, 1 minute, 1 second");
+    assert_eq!(long(Duration::from_secs(2 * 86_400 + 7_200), 4), "2 days, 2 hours");
+}
+
+#[test]
+fn max_components() {
+    assert_eq!(long(Duration::from_secs(90_061), 2), "1 day, 1 hour");
+    assert_eq!(long(Duration::from_secs(90_061), 1), "1 day");
+    assert_eq!(long(Duration::from_secs(90_061), 0), "1 day");
+    assert_eq!(long(Duration::from_secs(86_401), 2), "1 day, 1 second");
+    assert_eq!(long(Duration::from_secs(0), 0), "0 seconds");
+
+    let style = DurationStyle {
+        max_components: 2,
+        ..DurationStyle::default()
+    };
+    let content = MessageBuilder::new()
+        .push_duration_styled(Duration::from_secs(90_061), &style)
+        .build();
+    assert_eq!(content, "1d 1h");
+}
+
+#[test]
+fn sub_second_durations() {
+    assert_eq!(MessageBuilder::new().push_duration(Duration::from_millis(999)).build(), "0s");
+    assert_eq!(MessageBuilder::new().push_duration(Duration::from_millis(1_999)).build(), "1s");
+    assert_eq!(long(Duration::from_nanos(1), 4), "0 seconds");
+    assert_eq!(DurationStyle::new(), DurationStyle::default());
+}
@@ -0,0 +1,121 @@
+extern crate serenity_utils;
+
+use serenity_utils::{read_image, Error};
+use std::env;
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::PathBuf;
+use std::process;
+
+// 1x1 images, the smallest that are still valid files.
+const PNG: &[u8] = b"\
+    \x89\x50\x4E\x47\x0D\x0A\x1A\x0A\x00\x00\x00\x0D\x49\x48\x44\x52\x00\x00\x00\x01\x00\x00\
+    \x00\x01\x08\x00\x00\x00\x00\x3A\x7E\x9B\x55\x00\x00\x00\x0A\x49\x44\x41\x54\x78\x9C\x63\
+    \x60\x00\x00\x00\x02\x00\x01\x48\xAF\xA4\x71\x00\x00\x00\x00\x49\x45\x4E\x44\xAE\x42\x60\
+    \x82";
+const JPEG: &[u8] = b"\
+    \xFF\xD8\xFF\xE0\x00\x10\x4A\x46\x49\x46\x00\x01\x01\x01\x00\x48\x00\x48\x00\x00\xFF\xDB\
+    \x00\x43\x00\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\
+    \xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\
+    \xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\
+    \xFF\xFF\xC2\x00\x0B\x08\x00\x01\x00\x01\x01\x01\x11\x00\xFF\xC4\x00\x14\x10\x01\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\xFF\xDA\x00\x08\x01\x01\x00\x01\
+    \x3F\x10";
+const GIF: &[u8] = b"\
+    \x47\x49\x46\x38\x39\x61\x01\x00\x01\x00\x80\x00\x00\x00\x00\x00\xFF\xFF\xFF\x21\xF9\x04\
+    \x01\x00\x00\x00\x00\x2C\x00\x00\x00\x00\x01\x00\x01\x00\x00\x02\x02\x44\x01\x00\x3B";
+const WEBP: &[u8] = b"\
+    \x52\x49\x46\x46\x1A\x00\x00\x00\x57\x45\x42\x50\x56\x50\x38\x4C\x0D\x00\x00\x00\x2F\x00\
+    \x00\x00\x10\x07\x10\x11\x11\x88\x88\xFE\x07\x00";
+
+fn fixture(name: &str, contents: &[u8]) -> PathBuf {
+    let path = env::temp_dir().join(format!("serenity_utils_{}_{}", process::id(), name));
+    File::create(&path).and_then(|mut file| file.write_all(contents)).unwrap();
+
+    path
+}
+
+fn read(name: &str, contents: &[u8]) -> Result<String, Error> {
+    let path = fixture(name, contents);
+    let image = read_image(&path);
+    fs::remove_file(path).unwrap();
+
+    image
+}
+
+#[test]
+fn sniffs_formats() {
+    let expected = [
+        ("data:image/png;base64,\
+          iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAAAAAA6fptVAAAACklEQVR4nGNgAAAAAgABSK+kcQAAAABJ\
+          RU5ErkJggg==", PNG),
+        ("data:image/jpeg;base64,\
+          /9j/4AAQSkZJRgABAQEASABIAAD/2wBDAP//////////////////////////////////////////////\
+          ////////////////////////////////////////wgALCAABAAEBAREA/8QAFBABAAAAAAAAAAAAAAAA\
+          AAAAAP/aAAgBAQABPxA=", JPEG),
+        ("data:image/gif;base64,R0lGODlhAQABAIAAAAAAAP///yH5BAEAAAAALAAAAAABAAEAAAICRAEAOw==", GIF),
+        ("data:image/webp;base64,UklGRhoAAABXRUJQVlA4TA0AAAAvAAAAEAcQERGIiP4HAA==", WEBP),
+    ];
+
+    // The names have the wrong extensions, which the contents take priority over.
+    for (i, &(uri, contents)) in expected.iter().enumerate() {
+        let name = format!("sniff_{}.txt", i);
+        assert_eq!(read(&name, contents).unwrap(), uri, "{}", name);
+    }
+
+    assert_eq!(read("sniff.png", JPEG).unwrap(), expected[1].0);
+    assert_eq!(read("sniff", &GIF[..7]).unwrap(), "data:image/gif;base64,R0lGODlhAQ==");
+}
+
+#[test]
+fn falls_back_to_extension() {
+    let expected = [
+        ("fallback.png", "png"),
+        ("fallback.PNG", "png"),
+        ("fallback.jpg", "jpeg"),
+        ("fallback.jpeg", "jpeg"),
+        ("fallback.gif", "gif"),
+        ("fallback.webp", "webp"),
+    ];
+
+    for &(name, format) in &expected {
+        let uri = format!("data:image/{};base64,AAEC", format);
+        assert_eq!(read(name, &[0, 1, 2]).unwrap(), uri, "{}", name);
+    }
+
+    assert_eq!(read("empty.png", &[]).unwrap(), "data:image/png;base64,");
+}
+
+#[test]
+fn unsupported_formats() {
+    let files = [
+        ("unsupported.bmp", &b"BM\x00\x00"[..]),
+        ("unsupported.txt", b"GIF88a"),
+        ("unsupported", b"RIFF\x00\x00\x00\x00WAVE"),
+        ("unsupported.", b""),
+    ];
+
+    for &(name, contents) in &files {
+        match read(name, contents) {
+            Err(Error::UnsupportedImage) => {},
+            other => panic!("{}: {:?}", name, other),
+        }
+    }
+
+    assert_eq!(Error::UnsupportedImage.to_string(), "Image isn't a PNG, JPEG, GIF, or WebP");
+}
+
+#[test]
+fn unreadable_paths() {
+    let missing = env::temp_dir().join("serenity_utils_missing").join("cat.png");
+
+    match read_image(&missing) {
+        Err(Error::Io(_)) => {},
+        other => panic!("{:?}", other),
+    }
+
+    match read_image(env::temp_dir()) {
+        Err(Error::Io(_)) => {},
+        other => panic!("{:?}", other),
+    }
+}
@@ -0,0 +1,61 @@
+extern crate serde_json;
+extern crate serenity_utils;
+
+use serde_json::{Map, Value};
+use serenity_utils::{hashmap_to_json_map, json_map};
+use std::collections::HashMap;
+
+fn nested() -> Value {
+    let author = json_map(vec![("name", "cat"), ("url", "https://example.com")]);
+    let fields = vec![
+        Value::from(json_map(vec![("name", Value::from("a")), ("inline", Value::from(true))])),
+        Value::from(json_map(vec![("name", Value::from("b")), ("inline", Value::from(false))])),
+    ];
+
+    Value::from(json_map(vec![
+        ("author", Value::from(author)),
+        ("fields", Value::from(fields)),
+        ("colour", Value::from(0x7289DA)),
+        ("footer", Value::Null),
+    ]))
+}
+
+#[test]
+fn hashmap_conversion() {
+    let mut map = HashMap::new();
+    map.insert(1, Value::from("one"));
+    map.insert(2, nested());
+
+    let json = hashmap_to_json_map(map);
+
+    assert_eq!(json.len(), 2);
+    assert_eq!(json["1"], Value::from("one"));
+    assert_eq!(json["2"], nested());
+    assert_eq!(json["2"]["fields"][1]["inline"], Value::from(false));
+    assert_eq!(hashmap_to_json_map(HashMap::<String, Value>::new()), Map::new());
+}
+
+#[test]
+fn pairs_conversion() {
+    let body = json_map(vec![("embed", nested()), ("content", Value::from("hi"))]);
+
+    assert_eq!(body.len(), 2);
+    assert_eq!(body["embed"]["author"]["name"], Value::from("cat"));
+    assert_eq!(body["embed"]["fields"][0]["name"], Value::from("a"));
+    assert_eq!(body["embed"]["footer"], Value::Null);
+
+    let owned = json_map(vec![("content".to_string(), "hi".to_string())]);
+    assert_eq!(owned["content"], body["content"]);
+
+    let mut pairs = HashMap::new();
+    pairs.insert("tts", true);
+    assert_eq!(json_map(pairs)["tts"], Value::from(true));
+}
+
+#[test]
+fn duplicate_keys() {
+    let json = json_map(vec![("a", 1), ("b", 2), ("a", 3)]);
+
+    assert_eq!(json.len(), 2);
+    assert_eq!(json["a"], Value::from(3));
+}
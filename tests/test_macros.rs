@@ -0,0 +1,67 @@
+#[macro_use]
+extern crate serenity_utils;
+
+use serenity_utils::MessageBuilder;
+
+#[test]
+fn msg_matches_builder_chain() {
+    let (user, channel, role) = (114941315417899012, 81384788765712384, 136107769680887808);
+
+    let content = msg!("Welcome ", @user, " to ", #channel, "! ", bold("Read the rules."));
+    let expected = MessageBuilder::new()
+        .push("Welcome ")
+        .user(user)
+        .push(" to ")
+        .channel(channel)
+        .push("! ")
+        .push_bold("Read the rules.");
+    assert_eq!(content.build(), expected.build());
+
+    let content = msg!(&role, " ", 3, " of ", 4.5);
+    let expected = MessageBuilder::new().role(role).push(" ").push(3).push(" of ").push(4.5);
+    assert_eq!(content.build(), expected.build());
+}
+
+#[test]
+fn msg_styles() {
+    let name = String::from("a_b");
+
+    let content = msg!(
+        italic("i"),
+        " ",
+        underline(name),
+        " ",
+        strike("s"),
+        " ",
+        spoiler("p"),
+        " ",
+        mono("m`"),
+        " ",
+        safe(&name),
+    );
+    let expected = MessageBuilder::new()
+        .push_italic("i")
+        .push(" ")
+        .push_underline(&name)
+        .push(" ")
+        .push_strike("s")
+        .push(" ")
+        .push_spoiler("p")
+        .push(" ")
+        .push_mono("m`")
+        .push(" ")
+        .push_safe(&name);
+    assert_eq!(content.build(), expected.build());
+}
+
+#[test]
+fn msg_expressions() {
+    let ids = [114941315417899012, 81384788765712384];
+    let name = "Zeyla";
+
+    let content = msg!(@ids[0], " and ", @ids[1], ", hi ", (&name), format!(" ({})", ids.len()));
+    assert_eq!(content, "<@114941315417899012> and <@81384788765712384>, hi Zeyla (2)");
+
+    assert!(msg!().is_empty());
+    assert_eq!(msg!(bold("a"),), "**a**");
+}
@@ -0,0 +1,87 @@
+extern crate serenity_utils;
+
+use serenity_utils::{remove_markdown, remove_markdown_with_urls, MessageBuilder};
+
+#[test]
+fn removes_styles() {
+    let content = "**bold** *italic* _italic_ __underline__";
+    assert_eq!(remove_markdown(content), "bold italic italic underline");
+
+    let content = "~~strike~~ ||spoiler|| ***both*** __*nested*__";
+    assert_eq!(remove_markdown(content), "strike spoiler both nested");
+
+    assert_eq!(remove_markdown("**bold *and italic* text**"), "bold and italic text");
+}
+
+#[test]
+fn leaves_unclosed_and_literal_markers() {
+    assert_eq!(remove_markdown("2 * 3 * 4"), "2 * 3 * 4");
+    assert_eq!(remove_markdown("snake_case_name"), "snake_case_name");
+    assert_eq!(remove_markdown("**unclosed"), "**unclosed");
+    assert_eq!(remove_markdown("****"), "****");
+    assert_eq!(remove_markdown("a ~ b | c"), "a ~ b | c");
+    assert_eq!(remove_markdown("`` and ```"), "`` and ```");
+}
+
+#[test]
+fn unwraps_code() {
+    assert_eq!(remove_markdown("run `cargo test`"), "run cargo test");
+    assert_eq!(remove_markdown("`` `tick` ``"), "`tick`");
+    assert_eq!(remove_markdown("```rust\nfn main() {}\n```"), "fn main() {}");
+    assert_eq!(remove_markdown("```\n**not bold**\n```"), "**not bold**");
+    assert_eq!(remove_markdown("```one line```"), "one line");
+    assert_eq!(remove_markdown("`*a* \\* [x](https://a.b)`"), "*a* \\* [x](https://a.b)");
+}
+
+#[test]
+fn strips_line_prefixes() {
+    assert_eq!(remove_markdown("> quoted\nnot quoted\n> again"), "quoted\nnot quoted\nagain");
+    assert_eq!(remove_markdown(">>> all\nof this"), "all\nof this");
+    assert_eq!(remove_markdown("# Title\n## Sub\n### Third\n-# small"), "Title\nSub\nThird\nsmall");
+    assert_eq!(remove_markdown("> # Quoted title"), "Quoted title");
+    assert_eq!(remove_markdown("not # a header\n#hashtag"), "not # a header\n#hashtag");
+    assert_eq!(remove_markdown("a > b"), "a > b");
+}
+
+#[test]
+fn unescapes() {
+    assert_eq!(remove_markdown("\\*not italic\\*"), "*not italic*");
+    assert_eq!(remove_markdown("\\_\\_init\\_\\_ \\\\ \\n"), "__init__ \\ \\n");
+    assert_eq!(remove_markdown("\\> not quoted"), "> not quoted");
+}
+
+#[test]
+fn collapses_links() {
+    let content = "see [the **docs**](https://example.com/docs) and [x](<https://e.x>)";
+
+    assert_eq!(remove_markdown(content), "see the docs and x");
+    assert_eq!(
+        remove_markdown_with_urls(content),
+        "see the docs (https://example.com/docs) and x (https://e.x)",
+    );
+
+    let content = "[not](a link) [x](javascript:y)";
+    assert_eq!(remove_markdown(content), content);
+}
+
+#[test]
+fn leaves_tags() {
+    let content = "<@114941315417899012> at <t:1462015105:R> <:rust:123>";
+
+    assert_eq!(remove_markdown(content), content);
+}
+
+#[test]
+fn inverts_builder() {
+    let content = MessageBuilder::new()
+        .push_bold("Hello")
+        .push(" ")
+        .push_safe("a*b")
+        .push(" ")
+        .push_mono("x")
+        .push("\n")
+        .push_codeblock("let *y* = 1;", Some("rust"))
+        .build();
+
+    assert_eq!(remove_markdown(&content), "Hello a*b x\nlet *y* = 1;");
+}
@@ -0,0 +1,1523 @@
+extern crate serenity_utils;
+
+use serenity_utils::content;
+use serenity_utils::{
+    emoji_shortcode,
+    ContentFull,
+    parse_mention,
+    GuildNavigation,
+    MessageBuilder,
+    MESSAGE_CODE_LIMIT,
+    ReactionType,
+    SegmentKind,
+    StaleCheckpoint,
+    StatusIcons,
+    Styles,
+    UnknownEmoji,
+};
+
+#[test]
+fn push_bold_italic() {
+    let content = MessageBuilder::new()
+        .push("a ")
+        .push_bold("b")
+        .push(" ")
+        .push_italic("c")
+        .build();
+    assert_eq!(content, "a **b** *c*");
+}
+
+#[test]
+fn empty_styles_emit_nothing() {
+    let content = MessageBuilder::new().push_bold("").push_italic("").build();
+    assert!(content.is_empty());
+}
+
+#[test]
+fn push_underline_strike() {
+    let content = MessageBuilder::new()
+        .push_underline("a")
+        .push(" ")
+        .push_strike("b")
+        .push_strike("")
+        .build();
+    assert_eq!(content, "__a__ ~~b~~");
+}
+
+#[test]
+fn adjacent_markers_are_separated() {
+    let content = MessageBuilder::new()
+        .push_bold("a")
+        .push_italic("b")
+        .push_strike("c")
+        .build();
+    assert_eq!(content, "**a**\u{200B}*b*~~c~~");
+}
+
+#[test]
+fn spoiler_cannot_be_terminated_early() {
+    let content = MessageBuilder::new().push_spoiler("|a|||b|").build();
+    assert_eq!(content, "||\u{200B}|a|\u{200B}|\u{200B}|b|\u{200B}||");
+    assert!(!content[2..content.len() - 2].contains("||"));
+}
+
+#[test]
+fn mono_with_backticks() {
+    assert_eq!(MessageBuilder::new().push_mono("id").build(), "`id`");
+    assert_eq!(MessageBuilder::new().push_mono("a`b").build(), "``a`b``");
+    assert_eq!(MessageBuilder::new().push_mono("`").build(), "`` ` ``");
+    assert!(MessageBuilder::new().push_mono("").build().is_empty());
+
+    // Runs of two or more backticks take a single backtick as the fence,
+    // unless there are single backticks too.
+    assert_eq!(MessageBuilder::new().push_mono("a``b").build(), "`a``b`");
+    assert_eq!(MessageBuilder::new().push_mono("``").build(), "` `` `");
+    assert_eq!(MessageBuilder::new().push_mono("a`b``c").build(), "`aˋbˋˋc`");
+
+    for code in &["a``b", "``", "a`b``c", "x ``` y", "`a`"] {
+        let content = MessageBuilder::new().push_mono(code).build();
+        let tokens = content::parse(&content);
+
+        assert_eq!(tokens.len(), 1, "{:?}", content);
+        assert!(matches!(tokens[0].kind, content::TokenKind::CodeSpan { .. }), "{:?}", content);
+    }
+}
+
+#[test]
+fn codeblock_layout() {
+    let content = MessageBuilder::new().push_codeblock("a\nb", Some("py")).build();
+    assert_eq!(content, "```py\na\nb\n```");
+
+    let content = MessageBuilder::new().push_codeblock("c\n", Some("")).build();
+    assert_eq!(content, "```\nc\n```");
+
+    assert!(MessageBuilder::new().push_codeblock("", None).build().is_empty());
+}
+
+#[test]
+fn codeblock_defuses_embedded_fences() {
+    let cases = [
+        ("```\na", "``\u{200B}`\na"),
+        ("a\n```\nb", "a\n``\u{200B}`\nb"),
+        ("a\n```", "a\n``\u{200B}`"),
+        ("``````", "``\u{200B}``\u{200B}``"),
+        ("a `b` ``c``", "a `b` ``c``"),
+    ];
+
+    for &(input, body) in &cases {
+        let content = MessageBuilder::new().push_codeblock(input, Some("md")).build();
+        assert_eq!(content, format!("```md\n{}\n```", body));
+        assert_eq!(content.matches("```").count(), 2);
+    }
+}
+
+#[test]
+fn quote_line_prefixes_every_line() {
+    let content = MessageBuilder::new().push_quote_line("a\nb\n").build();
+    assert_eq!(content, "> a\n> b\n");
+    assert!(MessageBuilder::new().push_quote_line("").build().is_empty());
+}
+
+#[test]
+fn quote_block_swallows_later_pushes() {
+    let content = MessageBuilder::new()
+        .push_quote_block("a")
+        .push(" b")
+        .build();
+    assert_eq!(content, ">>> a b");
+
+    let content = MessageBuilder::new()
+        .push_quote_line("a")
+        .push_quote_block("b")
+        .build();
+    assert_eq!(content, "> a\n>>> b");
+}
+
+#[test]
+fn headers_start_on_a_new_line() {
+    let content = MessageBuilder::new()
+        .push_h1("a")
+        .push_h2("b")
+        .push("c")
+        .push_h3("d")
+        .push_h3("")
+        .build();
+    assert_eq!(content, "# a\n## b\nc\n### d\n");
+
+    let content = MessageBuilder::new().push("a").push_quote_line("b").build();
+    assert_eq!(content, "a\n> b\n");
+}
+
+#[test]
+fn subtext_prefixes_every_line() {
+    let content = MessageBuilder::new().push_subtext("a\nb").build();
+    assert_eq!(content, "-# a\n-# b\n");
+}
+
+#[test]
+fn line_variants() {
+    let content = MessageBuilder::new()
+        .push_line("a")
+        .push_italic_line("b")
+        .push_underline_line("c")
+        .push_strike_line("d")
+        .push_mono_line("e`")
+        .push_line("")
+        .build();
+    assert_eq!(content, "a\n*b*\n__c__\n~~d~~\n`` e` ``\n\n");
+}
+
+#[test]
+fn named_link_encodes_url() {
+    let content = MessageBuilder::new()
+        .push_named_link("docs", "https://example.com/a b/(c)")
+        .build();
+    assert_eq!(content, "[docs](https://example.com/a%20b/%28c%29)");
+}
+
+#[test]
+fn links_suppress_embeds_unless_asked() {
+    let url = "https://example.com/a?b=c";
+
+    assert_eq!(MessageBuilder::new().push_link(url), "<https://example.com/a?b=c>");
+    assert_eq!(MessageBuilder::new().push_link("<http://example.com>"), "<http://example.com>");
+    assert_eq!(MessageBuilder::new().push_link_embedded(url), url);
+    let content = MessageBuilder::new().push_link_embedded("<http://example.com>").build();
+    assert_eq!(content, "http://example.com");
+
+    let content = MessageBuilder::new().push_link("https://example.com/a>b").build();
+    assert_eq!(content, "<https://example.com/a%3Eb>");
+
+    let garbage = ["", "<>", "example.com", "ftp://example.com", "https://", "https://a b"];
+
+    for url in &garbage {
+        assert_eq!(MessageBuilder::new().push("x").push_link(url), "x");
+        assert_eq!(MessageBuilder::new().push("x").push_link_embedded(url), "x");
+    }
+}
+
+#[test]
+fn named_link_safe_keeps_caller_url() {
+    let content = MessageBuilder::new()
+        .push_named_link_safe("click me](https://evil.example)", "https://example.com")
+        .build();
+    assert!(content.ends_with("](https://example.com)"));
+    assert!(!content.contains("](https://evil"));
+
+    let content = MessageBuilder::new()
+        .push_named_link_safe(r"a\](b)", "https://example.com")
+        .build();
+    assert_eq!(content, r"[a\\\]\(b\)](https://example.com)");
+
+    let content = MessageBuilder::new()
+        .push_named_link_safe("text", "https://example.com\n[x](https://evil.example)")
+        .build();
+    assert_eq!(content, "text");
+}
+
+#[test]
+fn push_safe_escapes_markdown() {
+    let inputs = [
+        "**bold**",
+        "||spoiler||",
+        "> quote",
+        "__a__ ~~b~~ `c`",
+        "***___~~~|||```>>>",
+        r"\*already\*",
+        r"trailing \",
+        r"\\*x\\*",
+        r"\\**bold\\**",
+    ];
+
+    for input in &inputs {
+        let escaped = MessageBuilder::new().push_safe(input).build();
+        let chars = escaped.chars().collect::<Vec<_>>();
+
+        for (idx, c) in chars.iter().enumerate() {
+            if "*_~|`>".contains(*c) {
+                let backslashes = chars[..idx].iter().rev().take_while(|&&c| c == '\\').count();
+                assert_eq!(backslashes % 2, 1, "unescaped {:?} in {:?}", c, escaped);
+            }
+        }
+
+        let twice = MessageBuilder::new().push_safe(&escaped).build();
+        assert_eq!(twice, escaped);
+    }
+
+    let plain = "Hello world 123";
+    assert_eq!(MessageBuilder::new().push_safe(plain).build(), plain);
+    assert_eq!(MessageBuilder::new().push_safe(r"\\*x\\*").build(), r"\\\*x\\\*");
+}
+
+#[test]
+fn kv_lines_escape_key_and_value() {
+    let content = MessageBuilder::new()
+        .push_kv("a**b", "**c** @everyone")
+        .push_kv("Count", 3)
+        .build();
+    assert_eq!(content, "**a\\*\\*b:** \\*\\*c\\*\\* @\u{200B}everyone\n**Count:** 3\n");
+
+    let content = MessageBuilder::new().push_kv_raw("Role", "<@&1> **bold**").build();
+    assert_eq!(content, "**Role:** <@&1> **bold**\n");
+
+    let content = MessageBuilder::new().push_kv_raw("", 1.5).build();
+    assert_eq!(content, "**:** 1.5\n");
+}
+
+#[test]
+fn fields_skip_empty_values() {
+    use std::collections::BTreeMap;
+
+    let none: Vec<(&str, &str)> = vec![];
+    assert_eq!(MessageBuilder::new().push_fields(none.clone(), false), "");
+    assert_eq!(MessageBuilder::new().push_fields(none, true), "");
+
+    let blank = vec![("a", ""), ("b", " ")];
+    assert_eq!(MessageBuilder::new().push_fields(blank, true), "");
+
+    let mut map = BTreeMap::new();
+    map.insert("id", "1".to_string());
+    map.insert("name", "a*b".to_string());
+    map.insert("topic", String::new());
+
+    let content = MessageBuilder::new().push_fields(&map, false).build();
+    assert_eq!(content, "**id:** 1\n**name:** a\\*b\n");
+
+    let content = MessageBuilder::new().push_fields(&map, true).build();
+    assert_eq!(content, "**id:** 1 • **name:** a\\*b\n");
+}
+
+#[test]
+fn fields_truncate_at_the_limit() {
+    use serenity_utils::MESSAGE_CODE_LIMIT;
+
+    let long = "x".repeat(3000);
+    let fields = vec![("a", "1"), ("b", &long[..]), ("c", "3")];
+
+    for &inline in &[false, true] {
+        let content = MessageBuilder::new().push_fields(fields.clone(), inline).build();
+        assert_eq!(content.chars().count(), MESSAGE_CODE_LIMIT);
+        assert!(content.ends_with("x…\n"));
+        assert!(!content.contains("**c:**"));
+    }
+
+    let escapes = "*".repeat(3000);
+    let content = MessageBuilder::new().push_fields(vec![("k", &escapes)], false).build();
+    assert!(content.chars().count() <= MESSAGE_CODE_LIMIT);
+    assert!(content.ends_with("\\*…\n"));
+
+    let full = MessageBuilder::new().push("y".repeat(MESSAGE_CODE_LIMIT - 9));
+    let content = full.push_fields(vec![("b", "2"), ("key", "value")], false).build();
+    assert_eq!(content.chars().count(), MESSAGE_CODE_LIMIT);
+    assert!(content.ends_with("y**b:** 2\n"));
+}
+
+#[test]
+fn mono_safe_never_breaks_out() {
+    assert_eq!(MessageBuilder::new().push_mono_safe("`").build(), "`ˋ`");
+    assert_eq!(MessageBuilder::new().push_mono_safe("ends`").build(), "`endsˋ`");
+    assert_eq!(MessageBuilder::new().push_mono_safe("```").build(), "`ˋˋˋ`");
+    assert!(MessageBuilder::new().push_mono_safe("").build().is_empty());
+}
+
+#[test]
+fn codeblock_safe_neutralizes_fences() {
+    let cases = [
+        ("```rust\nx", "`\u{200B}`\u{200B}`rust\nx\n"),
+        ("x\n```", "x\n`\u{200B}`\u{200B}`\n"),
+        ("a```b```c\n```", "a`\u{200B}`\u{200B}`b`\u{200B}`\u{200B}`c\n`\u{200B}`\u{200B}`\n"),
+    ];
+
+    for &(input, body) in &cases {
+        let content = MessageBuilder::new().push_codeblock_safe(input, None).build();
+        assert_eq!(content, format!("```\n{}```", body));
+        assert_eq!(content.matches("```").count(), 2);
+    }
+}
+
+#[test]
+fn push_safe_escapes_mass_mentions() {
+    let content = MessageBuilder::new().push_safe("@everyone _hi_ @HERE").build();
+    assert_eq!(content, "@\u{200B}everyone \\_hi\\_ @\u{200B}HERE");
+
+    let twice = MessageBuilder::new().push_safe(&content).build();
+    assert_eq!(twice, content);
+}
+
+#[test]
+fn content_modifier_nesting_order() {
+    use serenity_utils::ContentModifier::*;
+
+    let cases = vec![
+        (Bold + Italic + "x", "***x***"),
+        (Italic + Bold + "x", "***x***"),
+        (Spoiler + Underline + "x", "||__x__||"),
+        (Code + Bold + "x", "**`x`**"),
+        (Strikethrough + Italic + "x", "~~*x*~~"),
+        (
+            Code + Italic + Bold + Strikethrough + Underline + Spoiler + "x",
+            "||__~~***`x`***~~__||",
+        ),
+        (Bold + Italic + "", ""),
+    ];
+
+    for (content, expected) in cases {
+        assert_eq!(content.to_string(), expected);
+        assert_eq!(MessageBuilder::new().push_content(content).build(), expected);
+    }
+}
+
+#[test]
+fn push_content_plain_string() {
+    let content = MessageBuilder::new().push_bold("a").push_content("b").build();
+    assert_eq!(content, "**a**b");
+}
+
+#[test]
+fn push_styled_table() {
+    use serenity_utils::Styles;
+
+    let cases = [
+        (Styles::empty(), "x"),
+        (Styles::BOLD, "**x**"),
+        (Styles::ITALIC, "*x*"),
+        (Styles::UNDERLINE, "__x__"),
+        (Styles::STRIKETHROUGH, "~~x~~"),
+        (Styles::SPOILER, "||x||"),
+        (Styles::CODE, "`x`"),
+        (Styles::BOLD | Styles::ITALIC, "***x***"),
+        (Styles::CODE | Styles::BOLD, "**`x`**"),
+        (Styles::SPOILER | Styles::STRIKETHROUGH | Styles::ITALIC, "||~~*x*~~||"),
+    ];
+
+    for &(styles, expected) in &cases {
+        assert_eq!(MessageBuilder::new().push_styled("x", styles).build(), expected);
+    }
+}
+
+#[test]
+fn push_list_items() {
+    let empty: Vec<String> = vec![];
+    assert_eq!(MessageBuilder::new().push("a").push_list(empty).build(), "a");
+
+    let items = vec!["a".to_string(), "b".to_string()];
+    let content = MessageBuilder::new()
+        .push_line("x")
+        .push_list(&items)
+        .push_nested_list(vec!["c"], 2)
+        .build();
+    assert_eq!(content, "x\n- a\n- b\n    - c\n");
+}
+
+#[test]
+fn push_numbered_list_items() {
+    let empty: Vec<&str> = vec![];
+    assert!(MessageBuilder::new().push_numbered_list(empty).build().is_empty());
+
+    let items = (1..12).map(|n| format!("item {}", n)).collect::<Vec<_>>();
+    let content = MessageBuilder::new().push_numbered_list(&items).build();
+    let lines = content.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 11);
+    assert_eq!(lines[8], "9. item 9");
+    assert_eq!(lines[10], "11. item 11");
+
+    let content = MessageBuilder::new()
+        .push("x")
+        .push_numbered_list_from(vec!["a\nb"], 10)
+        .build();
+    assert_eq!(content, "x\n10. a\n    b\n");
+}
+
+#[test]
+fn push_numbered_list_empty_items() {
+    let content = MessageBuilder::new().push_numbered_list(vec!["a", "", "c"]).build();
+    assert_eq!(content, "1. a\n2. \n3. c\n");
+
+    let content = MessageBuilder::new()
+        .push_numbered_list_from(vec!["a", "b"], u64::MAX)
+        .build();
+    assert_eq!(content.lines().count(), 2);
+    assert!(content.starts_with(&format!("{}. a\n", u64::MAX)));
+}
+
+#[test]
+fn push_diff_layout() {
+    let content = MessageBuilder::new()
+        .push_diff(vec!["-1", "a\nb"], vec!["+1"])
+        .build();
+    assert_eq!(content, "```diff\n- +1\n+ -1\n+ a\n+ b\n```");
+
+    assert!(MessageBuilder::new().push_diff(vec![], vec![]).build().is_empty());
+
+    let content = MessageBuilder::new().push_diff(vec!["```"], vec![]).build();
+    assert_eq!(content, "```diff\n+ `\u{200B}`\u{200B}`\n```");
+}
+
+#[test]
+fn mention_command_format() {
+    let content = MessageBuilder::new()
+        .mention_command("ping", 1)
+        .mention_command("  role  add\tcolour ", 2)
+        .build();
+    assert_eq!(content, "</ping:1></role add colour:2>");
+}
+
+#[test]
+fn id_mentions() {
+    let content = MessageBuilder::new()
+        .channel(1)
+        .role(2)
+        .user(3)
+        .push(4u64)
+        .build();
+    assert_eq!(content, "<#1><@&2><@3>4");
+}
+
+#[test]
+fn id_mentions_round_trip() {
+    let content = MessageBuilder::new().channel(12_345).build();
+    assert_eq!(serenity_utils::parse_channel(&content), Some(12_345));
+
+    let content = MessageBuilder::new().role(12_345).build();
+    assert_eq!(serenity_utils::parse_role(&content), Some(12_345));
+
+    let content = MessageBuilder::new().user(12_345).build();
+    assert_eq!(serenity_utils::parse_username(&content), Some(12_345));
+}
+
+#[test]
+fn emoji_forms() {
+    use serenity_utils::ReactionType;
+
+    let custom = |animated| ReactionType::Custom {
+        animated,
+        id: 1,
+        name: "blob".to_string(),
+    };
+
+    let content = MessageBuilder::new()
+        .emoji("✅")
+        .emoji('❌')
+        .emoji(custom(false))
+        .emoji(custom(true))
+        .build();
+    assert_eq!(content, "✅❌<:blob:1><a:blob:1>");
+}
+
+#[test]
+fn animated_custom_emoji() {
+    use serenity_utils::{parse_emoji, Mentionable};
+
+    for &(tag, animated) in &[("<a:blob:12345>", true), ("<:blob:12345>", false)] {
+        let emoji = parse_emoji(tag).unwrap();
+
+        assert_eq!(emoji.animated, animated);
+        assert_eq!(emoji.to_string(), tag);
+        assert_eq!(emoji.mention_display(), tag);
+        assert_eq!(ReactionType::from(emoji.clone()).to_string(), tag);
+        assert_eq!(MessageBuilder::new().emoji(emoji.clone()).build(), tag);
+        assert_eq!(MessageBuilder::new().mention(&emoji).build(), tag);
+        assert_eq!(MessageBuilder::new().custom_emoji(&emoji.name, emoji.id, animated).build(), tag);
+    }
+}
+
+#[test]
+fn mention_by_value_and_reference() {
+    use serenity_utils::{GuildNavigation, Mentionable, ReactionType};
+
+    let emoji = ReactionType::from("👋");
+    let content = MessageBuilder::new()
+        .mention(&emoji)
+        .mention(emoji.clone())
+        .mention(GuildNavigation::Guide)
+        .build();
+    assert_eq!(content, "👋👋<id:guide>");
+    assert_eq!((&&emoji).mention_display(), "👋");
+}
+
+#[test]
+fn every_mentionable_mention() {
+    use serenity_utils::{EmojiIdentifier, Mention, Mentionable, TimestampStyle};
+
+    let emoji = EmojiIdentifier {
+        animated: false,
+        id: 12_345,
+        name: "blob".to_string(),
+    };
+
+    assert_eq!(emoji.mention_display(), "<:blob:12345>");
+    assert_eq!(ReactionType::from(emoji.clone()).mention_display(), "<:blob:12345>");
+    assert_eq!(ReactionType::from("✅").mention_display(), "✅");
+    assert_eq!(GuildNavigation::Customize.mention_display(), "<id:customize>");
+    assert_eq!(GuildNavigation::Browse.mention_display(), "<id:browse>");
+    assert_eq!(GuildNavigation::LinkedRoles.mention_display(), "<id:linked-roles>");
+
+    let mentions = [
+        (Mention::Channel(1), "<#1>"),
+        (Mention::Command { id: 2, name: "tag get".to_string() }, "</tag get:2>"),
+        (Mention::Emoji(emoji), "<:blob:12345>"),
+        (Mention::Navigation(GuildNavigation::Guide), "<id:guide>"),
+        (Mention::Role(3), "<@&3>"),
+        (Mention::Timestamp { style: None, timestamp: 4 }, "<t:4>"),
+        (Mention::Timestamp { style: Some(TimestampStyle::Relative), timestamp: 5 }, "<t:5:R>"),
+        (Mention::User(6), "<@6>"),
+    ];
+
+    for &(ref mention, expected) in &mentions {
+        assert_eq!(mention.mention_display(), expected);
+        assert_eq!((&mention).mention_display(), expected);
+        assert_eq!(MessageBuilder::new().mention(mention).build(), expected);
+    }
+}
+
+#[test]
+fn mention_display() {
+    use serenity_utils::{Mention, Mentionable};
+
+    let user = Mention::User(12_345);
+    let mention = user.mention_display();
+
+    assert_eq!(mention, "<@12345>");
+    assert_eq!(mention, *"<@12345>");
+    assert_eq!(mention, "<@12345>".to_string());
+    assert!(mention != "<@1234");
+    assert!(mention != "<@123456>");
+    assert!(mention != "");
+    assert_eq!(format!("hi {}!", mention), "hi <@12345>!");
+    assert_eq!(format!("{:?}", mention), "\"<@12345>\"");
+    assert_eq!(String::from(mention), "<@12345>");
+    assert_eq!(mention.to_string(), "<@12345>");
+}
+
+#[test]
+#[allow(deprecated)]
+fn deprecated_mention_returns_string() {
+    use serenity_utils::Mentionable;
+    use std::fmt::{Formatter, Result as FmtResult};
+
+    struct Role(u64);
+
+    impl Mentionable for Role {
+        fn fmt_mention(&self, f: &mut Formatter) -> FmtResult { write!(f, "<@&{}>", self.0) }
+    }
+
+    let role = Role(7);
+    let mention: String = role.mention();
+
+    assert_eq!(mention, "<@&7>");
+    assert_eq!(role.mention_display().to_string(), "<@&7>");
+    assert_eq!(MessageBuilder::new().mention(&role).build(), "<@&7>");
+
+    let content = MessageBuilder::new().mention_join(vec![Role(1), Role(2)], ", ").build();
+    assert_eq!(content, "<@&1>, <@&2>");
+}
+
+#[test]
+fn quote_message_defuses_content() {
+    let content = MessageBuilder::new()
+        .push("Quoting:")
+        .quote_message(1, "<@2> <@&3> <#4> @here\n```rust", None)
+        .build();
+    assert_eq!(
+        content,
+        "Quoting:\n<@1>\n> <\u{200B}@2> <\u{200B}@&3> <\u{200B}#4> @\u{200B}here\n> `\u{200B}`\u{200B}`rust\n",
+    );
+}
+
+#[test]
+fn quote_message_truncates_long_content() {
+    use serenity_utils::MESSAGE_CODE_LIMIT;
+
+    let long = "a".repeat(5000);
+    let content = MessageBuilder::new().quote_message(1, &long, None).build();
+    assert_eq!(content.chars().count(), MESSAGE_CODE_LIMIT);
+    assert!(content.ends_with("a…\n"));
+
+    let lines = "line\n".repeat(1000);
+    let content = MessageBuilder::new().quote_message(1, &lines, Some(0)).build();
+    assert!(content.chars().count() <= MESSAGE_CODE_LIMIT);
+    assert!(content.ends_with("…\n"));
+    assert!(content.lines().skip(1).all(|line| line.starts_with("> ")));
+}
+
+#[test]
+fn quote_message_truncates_outside_tags() {
+    use serenity_utils::MESSAGE_CODE_LIMIT;
+
+    let emoji = "<:blob:302516740095606785> ".repeat(100);
+    let content = MessageBuilder::new().quote_message(1, &emoji, None).build();
+    assert!(content.chars().count() <= MESSAGE_CODE_LIMIT);
+    assert!(content.ends_with("> …\n"));
+
+    let quote = content.trim_start_matches("<@1>\n> ").trim_end_matches("…\n");
+    assert_eq!(quote.matches("<:blob:302516740095606785>").count(), quote.matches('<').count());
+
+    // A cut just after a line break leaves the ellipsis within the quote.
+    let lines = format!("{}\nb", "a".repeat(1989));
+    let content = MessageBuilder::new().quote_message(1, &lines, None).build();
+    assert!(content.chars().count() <= MESSAGE_CODE_LIMIT);
+    assert!(content.ends_with("a…\n"));
+}
+
+#[test]
+fn quote_message_stays_within_limit() {
+    use serenity_utils::MESSAGE_CODE_LIMIT;
+
+    for filler in 1990..2001 {
+        let content = MessageBuilder::new()
+            .push("a".repeat(filler))
+            .quote_message(1, "some quoted content", None)
+            .build();
+        let length = content.chars().count();
+
+        assert!(length <= MESSAGE_CODE_LIMIT.max(filler + 6), "{} chars", length);
+
+        if length > filler + 6 {
+            assert!(content.ends_with("…\n"));
+        }
+    }
+
+    let content = MessageBuilder::new()
+        .push("a".repeat(1994))
+        .quote_message(1, "some quoted content", None)
+        .build();
+    assert_eq!(content, format!("{}\n<@1>\n", "a".repeat(1994)));
+}
+
+#[test]
+fn length_counts_code_points() {
+    use serenity_utils::MESSAGE_CODE_LIMIT;
+
+    let builder = MessageBuilder::new().push("👋".repeat(1500));
+    assert_eq!(builder.0.len(), 6000);
+    assert_eq!(builder.len(), 1500);
+    assert_eq!(builder.remaining(), 500);
+
+    let builder = MessageBuilder::new().push("漢".repeat(2500));
+    assert_eq!(builder.len(), 2500);
+    assert_eq!(builder.remaining(), 0);
+
+    let builder = MessageBuilder::new().push("a".repeat(MESSAGE_CODE_LIMIT));
+    assert_eq!(builder.remaining(), 0);
+    assert!(!builder.is_empty());
+}
+
+#[test]
+fn try_build_checks_code_points() {
+    use serenity_utils::{Error, MessageTooLong};
+
+    let content = MessageBuilder::new().push("👋".repeat(2000)).try_build();
+    assert_eq!(content.unwrap().chars().count(), 2000);
+
+    let err = MessageBuilder::new().push("👋".repeat(2001)).try_build().unwrap_err();
+    assert_eq!(err, MessageTooLong { length: 2001, limit: 2000 });
+    assert_eq!(err.to_string(), "Message is 2001 characters long, over the limit of 2000");
+
+    match Error::from(err) {
+        Error::MessageTooLong(inner) => assert_eq!(inner.length, 2001),
+        other => panic!("unexpected error: {:?}", other),
+    }
+}
+
+#[test]
+fn try_from_content_checks_code_points() {
+    use serenity_utils::MessageTooLong;
+
+    let builder = MessageBuilder::try_from_content("👋".repeat(2000)).unwrap();
+    assert_eq!(builder.len(), 2000);
+
+    let err = MessageBuilder::try_from_content("👋".repeat(2001)).unwrap_err();
+    assert_eq!(err, MessageTooLong { length: 2001, limit: 2000 });
+
+    assert!(MessageBuilder::try_from_content(String::new()).unwrap().is_empty());
+}
+
+#[test]
+fn check_markdown_finds_unclosed_markers() {
+    use serenity_utils::{MarkdownIssue, MarkdownKind};
+
+    let issue = |kind, offset| MarkdownIssue { kind, offset };
+    let check = |content: &str| MessageBuilder::new().push(content).check_markdown();
+
+    assert_eq!(check(""), Ok(()));
+    assert_eq!(check("**a** __b__ ~~c~~ ||d|| *e* `f` ``g`` ```h```"), Ok(()));
+    assert_eq!(check("`**` and ``||`` and ```\n*\n```"), Ok(()));
+    assert_eq!(check(r"\*\* snake_case_name"), Ok(()));
+    assert_eq!(check("***x***"), Ok(()));
+    assert_eq!(check("2 * 3 = 6"), Ok(()));
+    assert_eq!(check("a * b"), Ok(()));
+    assert_eq!(check("x_"), Ok(()));
+
+    assert_eq!(check("a **b"), Err(vec![issue(MarkdownKind::Bold, 2)]));
+    assert_eq!(check("a `b"), Err(vec![issue(MarkdownKind::CodeSpan, 2)]));
+    assert_eq!(check("```rust\nfn main() {}"), Err(vec![issue(MarkdownKind::CodeBlock, 0)]));
+    assert_eq!(check("||a|| ||b"), Err(vec![issue(MarkdownKind::Spoiler, 6)]));
+    assert_eq!(check("**a __b"), Err(vec![
+        issue(MarkdownKind::Bold, 0),
+        issue(MarkdownKind::Underline, 4),
+    ]));
+
+    let content = MessageBuilder::new().push_bold("a").push("~~b").build_checked();
+    let issues = content.unwrap_err();
+    assert_eq!(issues, vec![issue(MarkdownKind::Strikethrough, 5)]);
+    assert_eq!(issues[0].to_string(), "Unclosed struck-through text at byte 5");
+
+    let content = MessageBuilder::new().push_bold("a").push_mono("b").build_checked();
+    assert_eq!(content.unwrap(), "**a**`b`");
+
+    use serenity_utils::ContentModifier::{Bold, Italic};
+
+    let builder = MessageBuilder::new().push("This is ").push_content(Bold + Italic + "important");
+    assert_eq!(builder.check_markdown(), Ok(()));
+    assert!(builder.build_checked().is_ok());
+}
+
+#[test]
+fn length_rule_agrees_everywhere() {
+    use serenity_utils::{split_message, MESSAGE_CODE_LIMIT};
+
+    // (content, bytes, code points)
+    let samples = [
+        ("👋", 4, 1),
+        ("👍🏽", 8, 2),
+        ("👨\u{200D}👩\u{200D}👧", 18, 5),
+        ("漢字", 6, 2),
+        ("e\u{0301}", 3, 2),
+    ];
+
+    for &(sample, bytes, chars) in &samples {
+        let builder = MessageBuilder::new().push(sample);
+        assert_eq!(sample.len(), bytes);
+        assert_eq!(sample.chars().count(), chars);
+        assert_eq!(builder.len(), chars);
+        assert_eq!(builder.remaining(), MESSAGE_CODE_LIMIT - chars);
+
+        let fits = sample.repeat(MESSAGE_CODE_LIMIT / chars);
+        let builder = MessageBuilder::new().push(&fits[..]);
+        assert!(builder.len() <= MESSAGE_CODE_LIMIT);
+        assert_eq!(builder.clone().try_build().unwrap(), fits);
+        assert_eq!(split_message(&fits), vec![fits.clone()]);
+        assert_eq!(builder.build_truncated(MESSAGE_CODE_LIMIT), fits);
+
+        let over = sample.repeat(MESSAGE_CODE_LIMIT / chars + 1);
+        let err = MessageBuilder::new().push(&over[..]).try_build().unwrap_err();
+        assert_eq!(err.length, over.chars().count());
+
+        for chunk in split_message(&over) {
+            assert!(MessageBuilder::new().push(chunk).len() <= MESSAGE_CODE_LIMIT);
+        }
+
+        let truncated = MessageBuilder::new().push(&over[..]).build_truncated(MESSAGE_CODE_LIMIT);
+        assert!(truncated.chars().count() <= MESSAGE_CODE_LIMIT);
+    }
+}
+
+#[test]
+fn truncate_to_keeps_mentions_whole() {
+    let content = MessageBuilder::new()
+        .push("hi ")
+        .user(114941315417899012)
+        .push(" there")
+        .build_truncated(12);
+
+    assert_eq!(content, "hi …");
+}
+
+#[test]
+fn truncate_to_closes_code_blocks() {
+    let content = MessageBuilder::new()
+        .push_codeblock("let x = 1;\nlet y = 2;", Some("rust"))
+        .build_truncated(27);
+
+    assert_eq!(content, "```rust\nlet x = 1;\nlet…\n```");
+}
+
+#[test]
+fn truncate_to_keeps_emoji_whole() {
+    // A family emoji, joined with zero width joiners, and a flag.
+    let family = "👨\u{200D}👩\u{200D}👧";
+    let content = MessageBuilder::new().push("ab").push(family).push("cd").build_truncated(5);
+
+    assert_eq!(content, "ab…");
+
+    let content = MessageBuilder::new().push("ab🇬🇧🇫🇷").build_truncated(5);
+
+    assert_eq!(content, "ab🇬🇧…");
+}
+
+#[test]
+fn truncate_to_closes_styles() {
+    let content = MessageBuilder::new()
+        .push_bold("bold")
+        .push(" ")
+        .push_italic("and italic text")
+        .build_truncated(17);
+
+    assert_eq!(content, "**bold** *and i…*");
+
+    let content = MessageBuilder::new().push("short").build_truncated(10);
+
+    assert_eq!(content, "short");
+}
+
+#[test]
+fn truncate_to_adds_no_markup() {
+    let content = MessageBuilder::new().push("2 * 3 is six and more words").truncate_to(10);
+    assert_eq!(content, "2 * 3 is …");
+
+    let content = MessageBuilder::new().push("***x*** then a long tail of words").truncate_to(12);
+    assert_eq!(content, "***x*** the…");
+
+    let content = MessageBuilder::new()
+        .push("Use `\\`` to escape.\nword word word word")
+        .truncate_to(30);
+    assert_eq!(content, "Use `\\`` to escape.\nword word…");
+
+    // A marker which is never closed in the whole content isn't closed either.
+    let content = MessageBuilder::new().push("**not bold at all").truncate_to(10);
+    assert_eq!(content, "**not bol…");
+}
+
+#[test]
+fn truncate_to_never_cuts_markers() {
+    let content = MessageBuilder::new().push("a ***bold italic***").truncate_to(4);
+    assert_eq!(content, "a …");
+
+    let content = MessageBuilder::new().push_bold("ab").push(" and more").truncate_to(6);
+    assert_eq!(content, "**a…**");
+
+    let content = MessageBuilder::new().push("`code` and more").truncate_to(6);
+    assert_eq!(content, "`cod…`");
+}
+
+#[test]
+fn with_capacity_does_not_reallocate() {
+    let builder = MessageBuilder::with_capacity(256);
+    let capacity = builder.capacity();
+    let ptr = builder.0.as_ptr();
+
+    let builder = builder
+        .push("Welcome, ")
+        .push_bold("user")
+        .push("! ")
+        .push_styled("Rules", Styles::BOLD | Styles::UNDERLINE)
+        .push_safe(" apply to *everyone*: ")
+        .user(114941315417899012)
+        .push_mono_safe("a`b")
+        .push_codeblock("let x = 1;", Some("rust"));
+
+    assert_eq!(builder.capacity(), capacity);
+    assert_eq!(builder.0.as_ptr(), ptr);
+}
+
+#[test]
+fn write_into_builder() {
+    use std::fmt::Write;
+
+    fn write_scores<W: Write>(sink: &mut W, scores: &[(&str, u32)]) -> std::fmt::Result {
+        for &(name, points) in scores {
+            writeln!(sink, "{} scored {}", name, points)?;
+        }
+
+        Ok(())
+    }
+
+    let mut builder = MessageBuilder::new().push_bold_line("Results");
+    write_scores(&mut builder, &[("a", 3), ("b", 1)]).unwrap();
+    builder.write_char('✓').unwrap();
+
+    assert_eq!(builder.build(), "**Results**\na scored 3\nb scored 1\n✓");
+}
+
+#[test]
+fn collect_and_extend() {
+    let empty: MessageBuilder = Vec::<&str>::new().into_iter().collect();
+
+    assert!(empty.is_empty());
+
+    let fragments: Vec<String> = (0..1000).map(|n| format!("{},", n)).collect();
+    let expected: String = fragments.concat();
+    let collected: MessageBuilder = fragments.iter().map(String::as_str).collect();
+
+    assert_eq!(collected.0, expected);
+
+    let mut builder = MessageBuilder::new().push_bold("x");
+    builder.extend(fragments);
+
+    assert_eq!(builder.0, format!("**x**{}", expected));
+}
+
+#[test]
+fn push_display_values() {
+    struct Points(u32);
+
+    impl std::fmt::Display for Points {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            write!(f, "{} points", self.0)
+        }
+    }
+
+    let name = String::from("Zeyla");
+    let content = MessageBuilder::new()
+        .push(&name)
+        .push(" has ")
+        .push(Points(12))
+        .push_line(',')
+        .push(-7i64)
+        .push(" and ")
+        .push(0.25f64)
+        .build();
+
+    assert_eq!(content, "Zeyla has 12 points,\n-7 and 0.25");
+}
+
+#[test]
+fn inspect_and_compare() {
+    let builder = MessageBuilder::new().push("Hi ").push_bold("there");
+
+    assert_eq!(builder, "Hi **there**");
+    assert_eq!(builder, *"Hi **there**");
+    assert_eq!(builder, String::from("Hi **there**"));
+    assert_ne!(builder, "Hi there");
+
+    let as_ref: &str = builder.as_ref();
+
+    assert_eq!(as_ref, builder.as_str());
+    assert_eq!(builder.find("**"), Some(3));
+    assert_eq!(builder.to_string(), "Hi **there**");
+}
+
+#[test]
+fn status_lines() {
+    let content = MessageBuilder::new()
+        .push("Report:")
+        .push_success("Built")
+        .push_warning("2 warnings")
+        .push_error("")
+        .push_error("Tests failed")
+        .build();
+
+    assert_eq!(content, "Report:\n✅ Built\n⚠\u{FE0F} 2 warnings\n❌ Tests failed\n");
+}
+
+#[test]
+fn status_lines_with_custom_icons() {
+    let icons = StatusIcons {
+        success: ReactionType::Custom {
+            animated: false,
+            id: 302516740095606785,
+            name: "ok".to_string(),
+        },
+        warning: ReactionType::Custom {
+            animated: true,
+            id: 302516740095606786,
+            name: "hmm".to_string(),
+        },
+        error: ReactionType::from('🔥'),
+    };
+
+    let content = MessageBuilder::new()
+        .push_status(&icons.success, "Built")
+        .push_status(&icons.warning, "Slow")
+        .push_status(&icons.error, "Crashed")
+        .build();
+
+    assert_eq!(
+        content,
+        "<:ok:302516740095606785> Built\n<a:hmm:302516740095606786> Slow\n🔥 Crashed\n",
+    );
+}
+
+#[test]
+fn status_lines_with_icon_set() {
+    let icons = StatusIcons {
+        success: ReactionType::Custom {
+            animated: false,
+            id: 302516740095606785,
+            name: "ok".to_string(),
+        },
+        warning: ReactionType::from("🟡"),
+        error: ReactionType::from('🔥'),
+    };
+
+    let content = MessageBuilder::new()
+        .push("Report:")
+        .push_success_with(&icons, "Built")
+        .push_warning_with(&icons, "Slow")
+        .push_error_with(&icons, "")
+        .push_error_with(&icons, "Crashed")
+        .build();
+
+    assert_eq!(content, "Report:\n<:ok:302516740095606785> Built\n🟡 Slow\n🔥 Crashed\n");
+
+    let defaults = StatusIcons::default();
+    let builder = MessageBuilder::new()
+        .push_success_with(&defaults, "a")
+        .push_warning_with(&defaults, "b")
+        .push_error_with(&defaults, "c");
+
+    let expected = MessageBuilder::new().push_success("a").push_warning("b").push_error("c");
+    assert_eq!(builder, expected.as_str());
+}
+
+#[test]
+fn edit_in_place() {
+    fn section(builder: &mut MessageBuilder, title: &str, items: &[&str]) {
+        builder.edit(|b| b.push_h3(title).push_list(items));
+    }
+
+    let mut builder = MessageBuilder::with_capacity(64);
+    section(&mut builder, "Done", &["a", "b"]);
+    builder.edit(|b| b.push("x")).edit(|b| b.push_italic("y"));
+
+    assert_eq!(builder, "### Done\n- a\n- b\nx*y*");
+    assert!(builder.capacity() >= 64);
+}
+
+#[test]
+fn clear_keeps_capacity() {
+    let builder = MessageBuilder::new().push("a".repeat(500));
+    let capacity = builder.capacity();
+    let builder = builder.clear();
+
+    assert!(builder.is_empty());
+    assert_eq!(builder.capacity(), capacity);
+}
+
+#[test]
+fn truncate_on_multibyte_boundaries() {
+    let builder = MessageBuilder::new().push("é漢👋");
+
+    assert_eq!(builder.clone().truncate(0), "");
+    assert_eq!(builder.clone().truncate(1), "é");
+    assert_eq!(builder.clone().truncate(2), "é漢");
+    assert_eq!(builder.clone().truncate(3), "é漢👋");
+    assert_eq!(builder.truncate(10), "é漢👋");
+}
+
+#[test]
+fn mentions_from_strings_round_trip() {
+    use serenity_utils::{parse_channel, parse_role, parse_username, ParseIdError};
+
+    let id = 381880193700069377;
+
+    for input in &["381880193700069377", " <#381880193700069377> "] {
+        let content = MessageBuilder::new().try_channel(input).unwrap().build();
+        assert_eq!(parse_channel(&content), Some(id));
+        assert_eq!(MessageBuilder::new().try_channel(&content).unwrap(), content);
+    }
+
+    for input in &["381880193700069377", "<@&381880193700069377>"] {
+        let content = MessageBuilder::new().try_role(input).unwrap().build();
+        assert_eq!(parse_role(&content), Some(id));
+        assert_eq!(MessageBuilder::new().try_role(&content).unwrap(), content);
+    }
+
+    for input in &["381880193700069377", "<@381880193700069377>", "<@!381880193700069377>"] {
+        let content = MessageBuilder::new().try_user(input).unwrap().build();
+        assert_eq!(parse_username(&content), Some(id));
+        assert_eq!(MessageBuilder::new().try_user(&content).unwrap(), content);
+    }
+
+    let invalid = ["", "0", "<#0>", "00", "-1", "12a", "<#1", "<@1>", "99999999999999999999"];
+
+    for input in &invalid {
+        let err = MessageBuilder::new().try_channel(input).unwrap_err();
+        assert_eq!(err, ParseIdError { input: input.to_string() });
+    }
+
+    assert!(MessageBuilder::new().try_role("<#1>").is_err());
+    assert!(MessageBuilder::new().try_user("<@&1>").is_err());
+}
+
+#[test]
+fn mass_mentions_need_opting_into() {
+    let content = MessageBuilder::new()
+        .allow_mass_mentions()
+        .mention_everyone()
+        .mention_here()
+        .build();
+    assert_eq!(content, "@everyone@here");
+
+    let content = MessageBuilder::new().mention_everyone().push(" ").mention_here().build();
+    assert_eq!(content, "@\u{200B}everyone @\u{200B}here");
+
+    let content = MessageBuilder::new()
+        .mention_here()
+        .allow_mass_mentions()
+        .mention_here()
+        .into_builder()
+        .mention_everyone()
+        .build();
+    assert_eq!(content, "@\u{200B}here@here@\u{200B}everyone");
+
+    let builder: MessageBuilder = MessageBuilder::new().push("a").allow_mass_mentions().into();
+    assert_eq!(builder, "a");
+}
+
+#[test]
+fn append_builders() {
+    let left = || MessageBuilder::new().push_bold("a");
+    let right = || MessageBuilder::new().push_italic("b");
+
+    assert_eq!(MessageBuilder::new().append(right()), "*b*");
+    assert_eq!(left().append(MessageBuilder::new()), "**a**");
+    assert_eq!(left().append(right()), "**a***b*");
+
+    let mut builder = MessageBuilder::with_capacity(64).push("x");
+    let ptr = builder.0.as_ptr();
+    builder.append_ref(&left()).append_ref(&MessageBuilder::new());
+
+    assert_eq!(builder, "x**a**");
+    assert_eq!(builder.0.as_ptr(), ptr);
+}
+
+#[test]
+fn paragraphs_are_separated_by_one_blank_line() {
+    let content = MessageBuilder::new()
+        .push_paragraph("first")
+        .push_paragraph("second\n")
+        .push_paragraph("\nthird\n\n\n")
+        .build();
+    assert_eq!(content, "first\n\nsecond\n\nthird");
+
+    let content = MessageBuilder::new()
+        .push_line("header")
+        .push_paragraph("a")
+        .push("b\n\n\n\n")
+        .push_paragraph("c")
+        .build();
+    assert_eq!(content, "header\n\nab\n\nc");
+
+    let mut builder = MessageBuilder::new();
+    for _ in 0..5 {
+        builder = builder.push_paragraph("x").push_paragraph("\n\n");
+    }
+    assert_eq!(builder, "x\n\nx\n\nx\n\nx\n\nx");
+
+    assert_eq!(MessageBuilder::new().push_paragraph("\n"), "");
+    assert_eq!(MessageBuilder::new().push_paragraph("solo"), "solo");
+}
+
+#[test]
+fn push_join_items() {
+    let empty: [&str; 0] = [];
+
+    assert_eq!(MessageBuilder::new().push_join(empty, ", "), "");
+    assert_eq!(MessageBuilder::new().push_join(["a"], ", "), "a");
+    assert_eq!(MessageBuilder::new().push_join(1..4, " + "), "1 + 2 + 3");
+    assert_eq!(MessageBuilder::new().push_join_last(["a", "b"], ", ", " and "), "a and b");
+    assert_eq!(MessageBuilder::new().push_join_last(empty, ", ", " and "), "");
+
+    let roles = [GuildNavigation::Browse, GuildNavigation::Guide];
+
+    assert_eq!(MessageBuilder::new().mention_join(roles, ", "), "<id:browse>, <id:guide>");
+}
+
+#[test]
+fn conditional_pushes() {
+    let build = |admin: bool, reason: Option<&str>| {
+        MessageBuilder::new()
+            .push("Banned ")
+            .user(7)
+            .push_if(admin, " (by an admin)")
+            .push_opt(reason.map(|reason| format!(" for {}", reason)))
+            .push_with(|b| b.push_if_else(admin, ".", "!"))
+            .build()
+    };
+
+    assert_eq!(build(false, None), "Banned <@7>!");
+    assert_eq!(build(true, None), "Banned <@7> (by an admin).");
+    assert_eq!(build(false, Some("spam")), "Banned <@7> for spam!");
+    assert_eq!(build(true, Some("spam")), "Banned <@7> (by an admin) for spam.");
+}
+
+#[test]
+fn push_sanitized_breaks_mentions() {
+    let inputs = [
+        "<@114941315417899012>",
+        "<@!114941315417899012>",
+        "<@&136107769680887808>",
+        "<#81384788765712384>",
+        "@everyone",
+        "@here",
+    ];
+
+    for input in &inputs {
+        let content = MessageBuilder::new().push_sanitized(input).build();
+
+        assert!(parse_mention(&content).is_none(), "{:?}", content);
+        assert!(!content.contains("@everyone") && !content.contains("@here"));
+        assert_eq!(content.replace('\u{200B}', ""), *input);
+    }
+
+    let text = "**<#1>** @x `code`";
+    let sanitized = MessageBuilder::new().push_sanitized(text).build();
+
+    assert_eq!(sanitized.replace('\u{200B}', ""), text);
+
+    let both = MessageBuilder::new().push_safe(&sanitized).build();
+
+    assert_eq!(both, "\\*\\*<\u{200B}#1\\>\\*\\* @\u{200B}x \\`code\\`");
+}
+
+#[test]
+fn push_emoji_shortcode() {
+    let content = MessageBuilder::new()
+        .push_emoji_shortcode("white_check_mark")
+        .and_then(|builder| builder.push(" ").push_emoji_shortcode(":+1:"))
+        .and_then(|builder| builder.push_emoji_shortcode("heart"))
+        .and_then(|builder| builder.push_emoji_shortcode("one"))
+        .unwrap()
+        .build();
+
+    assert_eq!(content, "✅ 👍❤\u{FE0F}1\u{FE0F}\u{20E3}");
+    assert_eq!(emoji_shortcode("thumbsup"), emoji_shortcode("+1"));
+    assert_eq!(emoji_shortcode("100"), Some("💯"));
+    assert_eq!(emoji_shortcode("zzz"), Some("💤"));
+}
+
+#[test]
+fn push_emoji_shortcode_rejects_unknown() {
+    let unknown = ["", ":", "::", "white_check_mrak", "WHITE_CHECK_MARK", ":tada", "+1::skin-tone-3"];
+
+    for name in &unknown {
+        let err = MessageBuilder::new().push_emoji_shortcode(name).unwrap_err();
+
+        assert_eq!(err, UnknownEmoji { name: name.to_string() });
+        assert_eq!(emoji_shortcode(name), None, "{:?}", name);
+    }
+
+    let err = MessageBuilder::new().push_emoji_shortcode(":nope:").unwrap_err();
+    assert_eq!(err.to_string(), "Unknown emoji shortcode \":nope:\"");
+}
+
+#[test]
+fn segments_cover_content() {
+    let builder = MessageBuilder::new()
+        .push_line("Hi <@1>, your build <t:1700000000:R>:")
+        .push_codeblock("line one\n\nline two", Some("txt"))
+        .push_spoiler("*secret*")
+        .push_mono("<@2>");
+
+    let segments = builder.segments();
+    let kinds = segments.iter().map(|segment| segment.kind).collect::<Vec<_>>();
+
+    assert_eq!(kinds, [
+        SegmentKind::Text,
+        SegmentKind::Mention,
+        SegmentKind::Text,
+        SegmentKind::Mention,
+        SegmentKind::Text,
+        SegmentKind::LineBreak,
+        SegmentKind::CodeBlock,
+        SegmentKind::Styled,
+        SegmentKind::Styled,
+    ]);
+    assert_eq!(segments[6].content, "```txt\nline one\n\nline two\n```");
+
+    let joined = segments.iter().map(|segment| segment.content).collect::<String>();
+    assert_eq!(joined, builder.as_str());
+
+    for segment in &segments {
+        assert_eq!(&builder.as_str()[segment.range.clone()], segment.content);
+    }
+
+    assert!(MessageBuilder::new().segments().is_empty());
+}
+
+#[test]
+fn drop_largest_segment_to_fit() {
+    let mut builder = MessageBuilder::new()
+        .push_bold_line("Deploy failed")
+        .push_line("Reason: timeout")
+        .push_codeblock(&"stack frame\n".repeat(200), None)
+        .push_line("")
+        .push_italic("Retry with /deploy");
+
+    assert!(builder.len() > MESSAGE_CODE_LIMIT);
+
+    while builder.len() > MESSAGE_CODE_LIMIT {
+        let segments = builder.segments();
+        let largest = (0..segments.len()).max_by_key(|&idx| segments[idx].len()).unwrap();
+
+        builder.remove_segment(largest);
+    }
+
+    assert_eq!(builder, "**Deploy failed**\nReason: timeout\n\n*Retry with /deploy*");
+    assert!(builder.try_build().is_ok());
+}
+
+#[test]
+fn segments_are_parsed_not_pushed() {
+    let builder = MessageBuilder::new()
+        .push_line("Details:")
+        .push_line("- one")
+        .push_line("- two");
+
+    // Each line and line break is its own segment, whichever push wrote it.
+    let kinds = builder.segments().iter().map(|segment| segment.kind).collect::<Vec<_>>();
+    assert_eq!(kinds, [
+        SegmentKind::Text,
+        SegmentKind::LineBreak,
+        SegmentKind::Text,
+        SegmentKind::LineBreak,
+        SegmentKind::Text,
+        SegmentKind::LineBreak,
+    ]);
+
+    // Optional details pushed last can be dropped with a checkpoint instead.
+    let mut builder = MessageBuilder::new().push_line("Build failed.");
+    let checkpoint = builder.checkpoint();
+    builder.append_ref(&MessageBuilder::new().push_line("- one").push_line("- two"));
+    builder.rollback_to(checkpoint).unwrap();
+
+    assert_eq!(builder, "Build failed.\n");
+}
+
+#[test]
+fn replace_segment() {
+    let mut builder = MessageBuilder::new().push("Hi ").user(1).push("!");
+    builder.replace_segment(1, "everyone");
+
+    assert_eq!(builder, "Hi everyone!");
+
+    // The text either side of the replaced mention is now one segment.
+    assert_eq!(builder.segments().len(), 1);
+
+    builder.replace_segment(0, MessageBuilder::new().push_bold("Bye"));
+    assert_eq!(builder, "**Bye**");
+}
+
+#[test]
+#[should_panic(expected = "segment index 2 out of bounds for 2 segments")]
+fn remove_segment_out_of_bounds() {
+    MessageBuilder::new().push("a").push_bold("b").remove_segment(2);
+}
+
+#[test]
+#[should_panic(expected = "segment index 0 out of bounds for 0 segments")]
+fn replace_segment_out_of_bounds() {
+    MessageBuilder::new().replace_segment(0, "a");
+}
+
+#[test]
+fn rollback_restores_content() {
+    let mut builder = MessageBuilder::new().push_bold("héllo");
+    let checkpoint = builder.checkpoint();
+    let (content, len, remaining) = (builder.to_string(), builder.len(), builder.remaining());
+
+    for _ in 0..100 {
+        builder.append_ref(&MessageBuilder::new().push_line("👋 more").push_codeblock("x", None));
+    }
+
+    builder.rollback_to(checkpoint).unwrap();
+
+    assert_eq!(builder, content);
+    assert_eq!(builder.len(), len);
+    assert_eq!(builder.remaining(), remaining);
+
+    // Nothing has changed since, so the checkpoint can be used again.
+    builder.append_ref(&MessageBuilder::new().push("!"));
+    builder.rollback_to(checkpoint).unwrap();
+    assert_eq!(builder, content);
+
+    let mut empty = MessageBuilder::new();
+    let start = empty.checkpoint();
+    empty.append_ref(&MessageBuilder::new().push("a"));
+    empty.rollback_to(start).unwrap();
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn rollback_to_stale_checkpoint() {
+    let mut builder = MessageBuilder::new().push("a ");
+    let first = builder.checkpoint();
+    builder.append_ref(&MessageBuilder::new().push_bold("b"));
+    let second = builder.checkpoint();
+
+    builder.rollback_to(first).unwrap();
+    assert_eq!(builder.rollback_to(second).unwrap_err(), StaleCheckpoint);
+
+    // Content of the same length but different from the second checkpoint's
+    // doesn't make it valid again.
+    builder.append_ref(&MessageBuilder::new().push_bold("c"));
+    assert_eq!(builder.rollback_to(second).unwrap_err(), StaleCheckpoint);
+    assert_eq!(builder, "a **c**");
+
+    // Neither does editing the content before a checkpoint.
+    let third = builder.checkpoint();
+    builder.append_ref(&MessageBuilder::new().push(" d"));
+    builder.remove_segment(0);
+    assert!(builder.rollback_to(third).is_err());
+    assert_eq!(builder, "**c** d");
+    assert_eq!(
+        StaleCheckpoint.to_string(),
+        "Checkpoint is stale, as the content it was taken at has since changed",
+    );
+}
+
+#[test]
+fn push_line_checked_until_full() {
+    let mut builder = MessageBuilder::new();
+    let mut pushed = 0;
+
+    let err = loop {
+        // Multi-byte lines, so that counting bytes rather than characters
+        // would stop far too early.
+        let line = format!("👋 log line {} — ünïcødé", pushed);
+
+        match builder.push_line_checked(&line) {
+            Ok(_) => pushed += 1,
+            Err(err) => break err,
+        }
+    };
+
+    assert!(builder.len() <= MESSAGE_CODE_LIMIT);
+    assert!(err.needed > err.remaining);
+    assert_eq!(err.remaining, MESSAGE_CODE_LIMIT - builder.len());
+    assert_eq!(builder.lines().count(), pushed);
+
+    let before = builder.to_string();
+    assert!(builder.push_checked(&"x".repeat(err.needed)).is_err());
+    let bold = builder.push_checked_with(|builder| builder.push_bold(&"x".repeat(err.remaining)));
+    assert!(bold.is_err());
+    assert_eq!(builder, before);
+
+    assert!(builder.try_build().is_ok());
+}
+
+#[test]
+fn push_checked_fills_exactly() {
+    let mut builder = MessageBuilder::new();
+
+    builder.push_checked(&"漢".repeat(MESSAGE_CODE_LIMIT - 1)).unwrap();
+    let err = builder.push_line_checked("a").unwrap_err();
+    assert_eq!(err, ContentFull { needed: 2, remaining: 1 });
+    builder.push_checked("a").unwrap().push_checked("").unwrap();
+
+    assert_eq!(builder.len(), MESSAGE_CODE_LIMIT);
+    let err = builder.push_checked("b").unwrap_err();
+    assert_eq!(err.to_string(), "Content needs 1 characters, but only 0 remain");
+
+    // Content which is already too long can't be pushed to, even emptily.
+    let mut builder = MessageBuilder::new().push("a".repeat(MESSAGE_CODE_LIMIT + 5));
+    assert_eq!(builder.push_checked("").unwrap_err(), ContentFull { needed: 0, remaining: 0 });
+}
+
+#[test]
+fn push_checked_with_styled() {
+    let mut builder = MessageBuilder::new();
+
+    builder
+        .push_checked_with(|builder| builder.push_underline("a"))
+        .unwrap()
+        .push_checked_with(|builder| builder.push_underline("b"))
+        .unwrap();
+
+    assert_eq!(builder, MessageBuilder::new().push_underline("a").push_underline("b").build());
+
+    let limit = MESSAGE_CODE_LIMIT - builder.len();
+    builder.push_checked_with(|builder| builder.push_mono(&"c".repeat(limit - 2))).unwrap();
+    assert_eq!(builder.len(), MESSAGE_CODE_LIMIT);
+}
@@ -0,0 +1,52 @@
+extern crate serenity_utils;
+
+use serenity_utils::MessageBuilder;
+
+#[test]
+fn numbers_are_grouped_in_threes() {
+    let cases = [
+        (0, "0"),
+        (7, "7"),
+        (42, "42"),
+        (999, "999"),
+        (1_000, "1,000"),
+        (12_345, "12,345"),
+        (999_999, "999,999"),
+        (1_000_000, "1,000,000"),
+        (12_345_678, "12,345,678"),
+        (123_456_789_012, "123,456,789,012"),
+        (999_999_999_999_999, "999,999,999,999,999"),
+        (-1, "-1"),
+        (-999, "-999"),
+        (-1_000, "-1,000"),
+        (-123_456_789_012_345, "-123,456,789,012,345"),
+    ];
+
+    for &(number, expected) in &cases {
+        let number: i64 = number;
+
+        assert_eq!(MessageBuilder::new().push_number(number).build(), expected);
+    }
+}
+
+#[test]
+fn number_types_and_extremes() {
+    assert_eq!(MessageBuilder::new().push_number(255u8).build(), "255");
+    assert_eq!(MessageBuilder::new().push_number(u64::MAX).build(), "18,446,744,073,709,551,615");
+    assert_eq!(MessageBuilder::new().push_number(i64::MIN).build(), "-9,223,372,036,854,775,808");
+
+    let content = MessageBuilder::new().push_number(i128::MIN).build();
+    assert_eq!(content, "-170,141,183,460,469,231,731,687,303,715,884,105,728");
+}
+
+#[test]
+fn number_separators() {
+    let number_with = |number: i64, separator| {
+        MessageBuilder::new().push_number_separated(number, separator).build()
+    };
+
+    assert_eq!(number_with(1_234_567, '.'), "1.234.567");
+    assert_eq!(number_with(1_234_567, '\u{2009}'), "1\u{2009}234\u{2009}567");
+    assert_eq!(number_with(-1_234, '\''), "-1'234");
+    assert_eq!(number_with(123, '.'), "123");
+}
@@ -13,23 +13,128 @@ fn invite_parser() {
 fn username_parser() {
     assert_eq!(parse_username("<@12345>").unwrap(), 12_345);
     assert_eq!(parse_username("<@!12345>").unwrap(), 12_345);
+    assert_eq!(parse_username("\t<@!12345>\n").unwrap(), 12_345);
+    assert_eq!(parse_username("<@18446744073709551615>").unwrap(), u64::MAX);
+}
+
+#[test]
+fn username_parser_rejects_malformed() {
+    let invalid = [
+        "",
+        "<",
+        "<@",
+        "<@>",
+        "<@!>",
+        "<@abc>",
+        "<@12345",
+        "<@12345>>",
+        "<@12345> junk",
+        "junk <@12345>",
+        "<@+12345>",
+        "<@-12345>",
+        "<@ 12345>",
+        "<@!!12345>",
+        "<@&12345>",
+        "<#12345>",
+        "<@18446744073709551616>",
+        "<@١٢٣>",
+        "12345",
+        "@12345",
+    ];
+
+    for mention in &invalid {
+        assert_eq!(parse_username(mention), None, "{:?}", mention);
+    }
 }
 
 #[test]
 fn role_parser() {
     assert_eq!(parse_role("<@&12345>").unwrap(), 12_345);
+    assert_eq!(parse_role("\n<@&12345>").unwrap(), 12_345);
+}
+
+#[test]
+fn role_parser_rejects_malformed() {
+    let invalid = [
+        "<@&>",
+        "<@&12345",
+        "<@&12345 >",
+        "<@&18446744073709551616>",
+        "<@12345>",
+        "<@!12345>",
+        "<@&&12345>",
+        "<#12345>",
+    ];
+
+    for mention in &invalid {
+        assert_eq!(parse_role(mention), None, "{:?}", mention);
+    }
 }
 
 #[test]
 fn channel_parser() {
     assert_eq!(parse_channel("<#12345>").unwrap(), 12_345);
+    assert_eq!(parse_channel(" <#12345> ").unwrap(), 12_345);
+    assert_eq!(parse_channel("<#18446744073709551615>").unwrap(), u64::MAX);
+}
+
+#[test]
+fn channel_parser_rejects_near_misses() {
+    let invalid = [
+        "<#>",
+        "<#12a34>",
+        "<#12345",
+        "#12345>",
+        "<# 12345>",
+        "<#012345>",
+        "<#0>",
+        "<#18446744073709551616>",
+        "<#99999999999999999999999>",
+        "<#12345><#67890>",
+        "see <#12345>",
+        "<#12345> here",
+        "<@12345>",
+    ];
+
+    for mention in &invalid {
+        assert_eq!(parse_channel(mention), None, "{:?}", mention);
+    }
 }
 
 #[test]
 fn emoji_parser() {
     let emoji = parse_emoji("<:name:12345>").unwrap();
-    assert_eq!(emoji.0, "name");
-    assert_eq!(emoji.1, 12_345);
+    assert!(!emoji.animated);
+    assert_eq!(emoji.name, "name");
+    assert_eq!(emoji.id, 12_345);
+
+    let emoji = parse_emoji("<a:ねこ_2:12345>").unwrap();
+    assert!(emoji.animated);
+    assert_eq!(emoji.name, "ねこ_2");
+    assert_eq!(emoji.to_string(), "<a:ねこ_2:12345>");
+}
+
+#[test]
+fn emoji_parser_rejects_malformed() {
+    let long = format!("<:{}:12345>", "a".repeat(33));
+    let invalid = [
+        "👍",
+        ":name:",
+        "<::12345>",
+        "<:name:>",
+        "<:name12345>",
+        "<:name:12345",
+        "<:name:12a45>",
+        "<:na me:12345>",
+        "<:name:12345:6789>",
+        "<b:name:12345>",
+        "<a:name:18446744073709551616>",
+        &long,
+    ];
+
+    for mention in &invalid {
+        assert_eq!(parse_emoji(mention), None, "{:?}", mention);
+    }
 }
 
 #[test]
@@ -37,3 +142,204 @@ fn quote_parser() {
     let parsed = parse_quotes("a \"b c\" d\"e f\"  g");
     assert_eq!(parsed, ["a", "b c", "d", "e f", "g"]);
 }
+
+#[test]
+fn mass_mention_escaping() {
+    let escaped = escape_mass_mentions("@everyone @Here x@everyone @hero @every");
+    assert!(!escaped.contains("@everyone"));
+    assert!(!escaped.to_lowercase().contains("@here"));
+    assert_eq!(escaped, "@\u{200B}everyone @\u{200B}Here x@\u{200B}everyone @hero @every");
+}
+
+#[test]
+fn navigation_parser() {
+    let navigations = [
+        GuildNavigation::Customize,
+        GuildNavigation::Browse,
+        GuildNavigation::Guide,
+        GuildNavigation::LinkedRoles,
+    ];
+
+    for &navigation in &navigations {
+        let mention = MessageBuilder::new().mention_navigation(navigation).build();
+        assert_eq!(parse_navigation(&mention), Some(navigation));
+        assert_eq!(parse_navigation(&format!("\t{}\n", mention)), Some(navigation));
+    }
+
+    assert_eq!(GuildNavigation::LinkedRoles.to_string(), "<id:linked-roles>");
+    assert!(parse_navigation("<id:>").is_none());
+    assert!(parse_navigation("<id:guide").is_none());
+    assert!(parse_navigation("id:guide>").is_none());
+    assert!(parse_navigation("see <id:guide>").is_none());
+}
+
+#[test]
+fn mention_parser() {
+    let emoji = EmojiIdentifier {
+        animated: true,
+        id: 12_345,
+        name: "cat".to_string(),
+    };
+    let cases = vec![
+        ("<@12345>", Mention::User(12_345)),
+        ("<@!12345>", Mention::User(12_345)),
+        ("<#12345>", Mention::Channel(12_345)),
+        ("<@&12345>", Mention::Role(12_345)),
+        ("<a:cat:12345>", Mention::Emoji(emoji)),
+        ("<t:-60>", Mention::Timestamp { style: None, timestamp: -60 }),
+        ("<t:1700000000:F>", Mention::Timestamp {
+            style: Some(TimestampStyle::LongDateTime),
+            timestamp: 1_700_000_000,
+        }),
+        ("</tag get:12345>", Mention::Command { id: 12_345, name: "tag get".to_string() }),
+        ("<id:guide>", Mention::Navigation(GuildNavigation::Guide)),
+    ];
+
+    for (input, expected) in cases {
+        assert_eq!(parse_mention(input).as_ref(), Some(&expected), "{:?}", input);
+
+        if input != "<@!12345>" {
+            assert_eq!(expected.to_string(), input);
+        }
+    }
+}
+
+#[test]
+fn mention_parser_rejects_garbage() {
+    let invalid = [
+        "",
+        "<>",
+        "<@>",
+        "<@&>",
+        "<@&!12345>",
+        "<@!&12345>",
+        "<#@12345>",
+        "<t:>",
+        "<t:12:>",
+        "<t:12:x>",
+        "<t:12:RR>",
+        "<t:1.5>",
+        "</:12345>",
+        "</a  b:12345>",
+        "</a b c d:12345>",
+        "</ping:>",
+        "<id:nowhere>",
+        "<x:12345>",
+        "@everyone",
+        "<@12345> <#12345>",
+    ];
+
+    for mention in &invalid {
+        assert_eq!(parse_mention(mention), None, "{:?}", mention);
+    }
+}
+
+#[test]
+fn message_link_parser() {
+    let hosts = [
+        "discord.com",
+        "ptb.discord.com",
+        "canary.discord.com",
+        "discordapp.com",
+        "ptb.discordapp.com",
+        "canary.discordapp.com",
+    ];
+
+    for host in &hosts {
+        let link = format!("https://{}/channels/1/2/3", host);
+        assert_eq!(parse_message_link(&link), Some((Some(1), 2, 3)), "{}", link);
+    }
+
+    assert_eq!(parse_message_link("https://discord.com/channels/@me/2/3"), Some((None, 2, 3)));
+    assert_eq!(parse_message_link("http://discord.com/channels/1/2/3/"), Some((Some(1), 2, 3)));
+    assert_eq!(parse_message_link("https://discord.com/channels/1/2/3?a=b"), Some((Some(1), 2, 3)));
+    assert_eq!(parse_message_link(" https://discord.com/channels/1/2/3#x "), Some((Some(1), 2, 3)));
+}
+
+#[test]
+fn message_link_parser_rejects_others() {
+    let invalid = [
+        "https://discord.com/channels/1/2",
+        "https://discord.com/channels/@me/2",
+        "https://discord.com/channels/1/2/3/4",
+        "https://discord.com/channels/1/2/x",
+        "https://discord.com/channels/@you/2/3",
+        "https://discord.com/guilds/1/2/3",
+        "https://evil.com/channels/1/2/3",
+        "https://discord.com.evil.com/channels/1/2/3",
+        "https://notdiscord.com/channels/1/2/3",
+        "discord.com/channels/1/2/3",
+        "ftp://discord.com/channels/1/2/3",
+        "",
+    ];
+
+    for link in &invalid {
+        assert_eq!(parse_message_link(link), None, "{:?}", link);
+    }
+}
+
+#[test]
+fn normalize_removes_invisible_characters() {
+    let cases = [
+        ("ad\u{200B}min", "admin"),
+        ("\u{FEFF}hello\u{2060}", "hello"),
+        ("a\u{200C}b\u{200E}c\u{200F}d", "abcd"),
+        ("\u{2066}isolated\u{2069} \u{202A}embedded\u{202C}", "isolated embedded"),
+        ("soft\u{00AD}hyphen", "softhyphen"),
+        ("a\u{200D}b", "ab"),
+        ("👍\u{200D}", "👍"),
+        ("\u{200D}👍", "👍"),
+        ("a\u{200D}👍", "a👍"),
+        ("👍\u{200D}b", "👍b"),
+        ("\u{200B}\u{200D}\u{FEFF}", ""),
+    ];
+
+    for &(input, expected) in &cases {
+        assert_eq!(normalize(input), expected, "{:?}", input);
+    }
+}
+
+#[test]
+fn normalize_keeps_emoji_sequences() {
+    let sequences = [
+        // A family, joined with zero width joiners.
+        "👨\u{200D}👩\u{200D}👧",
+        // A person with a skin tone modifier, joined to a laptop.
+        "👩🏽\u{200D}💻",
+        // A heart with a variation selector, joined to fire.
+        "❤\u{FE0F}\u{200D}🔥",
+        // The rainbow flag.
+        "🏳\u{FE0F}\u{200D}🌈",
+        // A flag made of regional indicators.
+        "🇺🇸",
+        // The flag of England, made with tag characters.
+        "🏴\u{E0067}\u{E0062}\u{E0065}\u{E006E}\u{E0067}\u{E007F}",
+        // A keycap.
+        "1\u{FE0F}\u{20E3}",
+        // Combining characters.
+        "e\u{0301}a\u{0308}",
+    ];
+
+    for sequence in &sequences {
+        assert_eq!(normalize(sequence), *sequence, "{:?}", sequence);
+    }
+
+    let spoofed = format!("{}\u{200B} and {}", sequences[0], sequences[1]);
+    assert_eq!(normalize(&spoofed), format!("{} and {}", sequences[0], sequences[1]));
+}
+
+#[test]
+fn normalize_reveals_spoofed_filenames() {
+    let name = "invoice\u{202E}fdp.exe";
+    let normalized = normalize(name);
+
+    assert_eq!(normalized, "invoicefdp.exe");
+    assert!(normalized.ends_with(".exe"));
+    assert_eq!(normalize("\u{202D}photo\u{202E}gnp.js\u{202C}"), "photognp.js");
+
+    let content = MessageBuilder::new()
+        .push("File: ")
+        .push_normalized(name)
+        .build();
+    assert_eq!(content, "File: invoicefdp.exe");
+}
@@ -0,0 +1,48 @@
+extern crate serenity_utils;
+
+use serenity_utils::{MessageBuilder, ProgressStyle};
+
+fn bar(value: f64, max: f64, width: usize) -> String {
+    MessageBuilder::new().push_progress_bar(value, max, width).build()
+}
+
+#[test]
+fn progress_bar_fills_in_proportion() {
+    assert_eq!(bar(0.0, 10.0, 8), "▱▱▱▱▱▱▱▱ 0%");
+    assert_eq!(bar(5.0, 10.0, 8), "▰▰▰▰▱▱▱▱ 50%");
+    assert_eq!(bar(10.0, 10.0, 8), "▰▰▰▰▰▰▰▰ 100%");
+    assert_eq!(bar(0.29, 1.0, 100).chars().filter(|&c| c == '▰').count(), 29);
+    assert_eq!(bar(0.29, 1.0, 10), "▰▰▱▱▱▱▱▱▱▱ 29%");
+}
+
+#[test]
+fn progress_bar_clamps_and_rounds_down() {
+    assert_eq!(bar(250.0, 100.0, 4), "▰▰▰▰ 100%");
+    assert_eq!(bar(-3.0, 100.0, 4), "▱▱▱▱ 0%");
+    assert_eq!(bar(99.9, 100.0, 4), "▰▰▰▱ 99%");
+    assert_eq!(bar(99.999, 100.0, 1000).matches('▱').count(), 1);
+    assert_eq!(bar(f64::NAN, 100.0, 4), "▱▱▱▱ 0%");
+}
+
+#[test]
+fn progress_bar_with_no_maximum() {
+    assert_eq!(bar(0.0, 0.0, 4), "▱▱▱▱ 0%");
+    assert_eq!(bar(5.0, 0.0, 4), "▱▱▱▱ 0%");
+    assert_eq!(bar(5.0, -1.0, 4), "▱▱▱▱ 0%");
+    assert_eq!(bar(5.0, 10.0, 0), " 50%");
+}
+
+#[test]
+fn progress_bar_styles() {
+    let style = ProgressStyle {
+        filled: '=',
+        empty: ' ',
+        percentage: false,
+        brackets: Some(('[', ']')),
+    };
+
+    let content = MessageBuilder::new().push_progress_bar_styled(1.0, 3.0, 6, &style).build();
+    assert_eq!(content, "[==    ]");
+
+    assert_eq!(ProgressStyle::new(), ProgressStyle::default());
+}
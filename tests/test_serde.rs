@@ -0,0 +1,38 @@
+#![cfg(feature = "serde")]
+
+extern crate serde_json;
+extern crate serenity_utils;
+
+use serenity_utils::MessageBuilder;
+
+#[test]
+fn serializes_as_string() {
+    let builder = MessageBuilder::new().push_bold("hi").push_line("").user(7);
+    let json = serde_json::to_string(&builder).unwrap();
+
+    assert_eq!(json, r#""**hi**\n<@7>""#);
+}
+
+#[test]
+fn round_trips_unicode() {
+    let builder = MessageBuilder::new()
+        .push("👨\u{200D}👩\u{200D}👧 漢字 é ")
+        .push_spoiler("🇬🇧")
+        .push_mono("\"quoted\" \\ backslash");
+    let json = serde_json::to_string(&builder).unwrap();
+    let deserialized: MessageBuilder = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(deserialized, builder.as_str());
+}
+
+#[test]
+fn rejects_overlong_content() {
+    let json = serde_json::to_string(&MessageBuilder::new().push("👋".repeat(2000))).unwrap();
+
+    assert!(serde_json::from_str::<MessageBuilder>(&json).is_ok());
+
+    let json = serde_json::to_string(&MessageBuilder::new().push("👋".repeat(2001))).unwrap();
+    let err = serde_json::from_str::<MessageBuilder>(&json).unwrap_err();
+
+    assert!(err.to_string().contains("2001 characters"));
+}
@@ -0,0 +1,52 @@
+extern crate serenity_utils;
+
+use serenity_utils::{group_by_shard, shard_id};
+
+const GUILDS: [u64; 3] = [81_384_788_765_712_384, 41_771_983_423_143_937, 172_018_499_005_317_120];
+
+#[test]
+fn known_shards() {
+    let expected = [
+        (1, [0, 0, 0]),
+        (5, [3, 4, 2]),
+        (16, [2, 6, 0]),
+        (17, [7, 4, 3]),
+        (100, [98, 34, 12]),
+    ];
+
+    for &(shard_count, shards) in &expected {
+        for (&guild_id, &shard) in GUILDS.iter().zip(&shards) {
+            assert_eq!(shard_id(guild_id, shard_count), shard, "{} {}", guild_id, shard_count);
+        }
+    }
+
+    assert_eq!(shard_id(0, 3), 0);
+    assert_eq!(shard_id(u64::MAX, 1 << 42), (1 << 42) - 1);
+}
+
+#[test]
+fn grouping() {
+    let guilds = GUILDS.iter().cloned().chain(Some(1));
+
+    assert_eq!(group_by_shard(guilds.clone(), 1), [vec![GUILDS[0], GUILDS[1], GUILDS[2], 1]]);
+    assert_eq!(group_by_shard(guilds, 5), [
+        vec![1],
+        vec![],
+        vec![GUILDS[2]],
+        vec![GUILDS[0]],
+        vec![GUILDS[1]],
+    ]);
+    assert_eq!(group_by_shard(Vec::new(), 2), [Vec::<u64>::new(), Vec::new()]);
+}
+
+#[test]
+#[should_panic(expected = "shard_count must be at least 1")]
+fn zero_shards() {
+    shard_id(GUILDS[0], 0);
+}
+
+#[test]
+#[should_panic(expected = "shard_count must be at least 1")]
+fn zero_shards_grouping() {
+    group_by_shard(GUILDS.iter().cloned(), 0);
+}
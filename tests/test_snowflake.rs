@@ -0,0 +1,106 @@
+extern crate serenity_utils;
+
+use serenity_utils::{parse_emoji, SnowflakeExt, DISCORD_EPOCH};
+use std::time::{Duration, UNIX_EPOCH};
+
+#[test]
+fn created_at_known_ids() {
+    // (Id, creation time in milliseconds since the Unix epoch)
+    let cases = [
+        // The example in Discord's API documentation, from 2016-04-30.
+        (175928847299117063, 1_462_015_105_796),
+        // The channel Id used throughout the crate's examples, from 2015-08-13.
+        (81384788765712384, 1_439_474_045_698),
+        // The first possible Id, at the start of 2015.
+        (0, DISCORD_EPOCH),
+    ];
+
+    for &(id, millis) in &cases {
+        let id: u64 = id;
+
+        assert_eq!(id.created_at_millis(), millis);
+        assert_eq!(id.created_at(), UNIX_EPOCH + Duration::from_millis(millis));
+    }
+}
+
+#[test]
+fn created_at_largest_id() {
+    assert_eq!(u64::MAX.created_at_millis(), 5_818_116_911_103);
+    assert_eq!(u64::MAX.created_at().duration_since(UNIX_EPOCH).unwrap().as_secs(), 5_818_116_911);
+}
+
+#[test]
+fn created_at_emoji() {
+    let emoji = parse_emoji("<:ferris:302516740095606785>").unwrap();
+
+    assert_eq!(emoji.created_at_millis(), 302516740095606785u64.created_at_millis());
+}
+
+#[test]
+fn generate_and_decompose_round_trip() {
+    use serenity_utils::{decompose_snowflake, generate_snowflake, SnowflakeParts};
+
+    let cases = [
+        SnowflakeParts { timestamp: DISCORD_EPOCH, worker: 0, process: 0, increment: 0 },
+        SnowflakeParts { timestamp: 1_462_015_105_796, worker: 1, process: 0, increment: 7 },
+        SnowflakeParts { timestamp: 1_600_000_000_000, worker: 31, process: 31, increment: 4095 },
+        SnowflakeParts {
+            timestamp: DISCORD_EPOCH + (1 << 42) - 1,
+            worker: 31,
+            process: 31,
+            increment: 4095,
+        },
+    ];
+
+    for parts in &cases {
+        let id = generate_snowflake(parts.timestamp, parts.worker, parts.process, parts.increment);
+
+        assert_eq!(decompose_snowflake(id), *parts);
+        assert_eq!(id.created_at_millis(), parts.timestamp);
+    }
+
+    assert_eq!(generate_snowflake(1_462_015_105_796, 1, 0, 7), 175928847299117063);
+    assert_eq!(generate_snowflake(DISCORD_EPOCH + (1 << 42) - 1, 31, 31, 4095), u64::MAX);
+
+    for &id in &[0, 1, 175928847299117063, 81384788765712384, u64::MAX] {
+        let parts = decompose_snowflake(id);
+        let generated =
+            generate_snowflake(parts.timestamp, parts.worker, parts.process, parts.increment);
+
+        assert_eq!(generated, id);
+    }
+}
+
+#[test]
+#[should_panic(expected = "worker must be at most 31")]
+fn generate_rejects_large_worker() {
+    serenity_utils::generate_snowflake(DISCORD_EPOCH, 32, 0, 0);
+}
+
+#[test]
+#[should_panic(expected = "timestamp must not be before the Discord epoch")]
+fn generate_rejects_early_timestamp() {
+    serenity_utils::generate_snowflake(DISCORD_EPOCH - 1, 0, 0, 0);
+}
+
+#[test]
+fn generator_is_sequential() {
+    use serenity_utils::{decompose_snowflake, SnowflakeGenerator};
+
+    let mut generator = SnowflakeGenerator::new(1_600_000_000_000).worker(2).process(3);
+    let ids = generator.by_ref().take(5000).collect::<Vec<_>>();
+
+    assert!(ids.windows(2).all(|pair| pair[0] < pair[1]));
+    assert_eq!(decompose_snowflake(ids[4095]).increment, 4095);
+    assert_eq!(decompose_snowflake(ids[4096]).timestamp, 1_600_000_000_001);
+    assert_eq!(decompose_snowflake(ids[4096]).increment, 0);
+    assert_eq!(decompose_snowflake(ids[0]).worker, 2);
+    assert_eq!(decompose_snowflake(ids[0]).process, 3);
+
+    let last = ids[ids.len() - 1];
+    assert!(generator.advance(Duration::from_micros(10)).next_id() > last);
+
+    let later = generator.advance(Duration::from_secs(300)).next_id();
+    assert_eq!(later.created_at_millis() - ids[0].created_at_millis(), 300_001);
+    assert_eq!(decompose_snowflake(later).increment, 0);
+}
@@ -0,0 +1,166 @@
+extern crate serenity_utils;
+
+use serenity_utils::{split_message, MESSAGE_CODE_LIMIT};
+
+fn assert_fits(chunks: &[String]) {
+    for chunk in chunks {
+        assert!(chunk.chars().count() <= MESSAGE_CODE_LIMIT);
+    }
+}
+
+#[test]
+fn single_giant_word() {
+    let content = "a".repeat(6000);
+    let chunks = split_message(&content);
+
+    assert_eq!(chunks.len(), 3);
+    assert_fits(&chunks);
+    assert_eq!(chunks.concat(), content);
+}
+
+#[test]
+fn giant_paragraph_splits_at_words() {
+    let content = "word ".repeat(1000);
+    let chunks = split_message(content.trim_end());
+
+    assert_eq!(chunks.len(), 3);
+    assert_fits(&chunks);
+
+    for chunk in &chunks {
+        assert!(chunk.starts_with("word"));
+        assert!(chunk.ends_with("word"));
+    }
+}
+
+#[test]
+fn prefers_paragraphs_then_lines() {
+    let content = format!("{}\n{}\n\n{}", "a".repeat(500), "b".repeat(500), "c".repeat(1500));
+    let chunks = split_message(&content);
+    assert_eq!(chunks, vec![format!("{}\n{}", "a".repeat(500), "b".repeat(500)), "c".repeat(1500)]);
+
+    let content = format!("{}\n{}", "a".repeat(1500), "b".repeat(1500));
+    let chunks = split_message(&content);
+    assert_eq!(chunks, vec!["a".repeat(1500), "b".repeat(1500)]);
+}
+
+#[test]
+fn never_splits_mentions_or_characters() {
+    let content = format!("{}<@114941315417899012>", "a".repeat(1990));
+    let chunks = split_message(&content);
+    assert_eq!(chunks, vec!["a".repeat(1990), "<@114941315417899012>".to_string()]);
+
+    let content = "👋".repeat(4001);
+    let chunks = split_message(&content);
+    assert_eq!(chunks.len(), 3);
+    assert_eq!(chunks[2], "👋");
+}
+
+#[test]
+fn empty_and_short_content() {
+    assert!(split_message("").is_empty());
+    assert_eq!(split_message("hi"), vec!["hi"]);
+}
+
+#[test]
+fn code_block_spanning_three_chunks() {
+    let code = "println!(\"hello\");\n".repeat(250);
+    let content = format!("Output:\n```rust\n{}```\ndone", code);
+    let chunks = split_message(&content);
+
+    assert_eq!(chunks.len(), 3);
+    assert_fits(&chunks);
+
+    for chunk in &chunks {
+        assert_eq!(chunk.matches("```").count(), 2, "unbalanced chunk: {:?}", chunk);
+    }
+
+    assert!(chunks[0].starts_with("Output:\n```rust\n"));
+    assert!(chunks[1].starts_with("```rust\nprintln!"));
+    assert!(chunks[2].starts_with("```rust\nprintln!"));
+    assert!(chunks[2].ends_with("```\ndone"));
+}
+
+#[test]
+fn boundary_on_closing_fence_line() {
+    // The split lands on the line of the closing fence, which is replaced by
+    // the chunk's own fence rather than re-opening an empty block.
+    let code = "a".repeat(1990);
+    let content = format!("```\n{}\n``` and then some more words", code);
+    let chunks = split_message(&content);
+
+    assert_eq!(chunks, vec![format!("```\n{}\n```", code), "and then some more words".to_string()]);
+}
+
+#[test]
+fn code_block_with_overlong_first_line() {
+    let content = format!("```\n{}\n```", "a".repeat(1993));
+    let chunks = split_message(&content);
+
+    assert_eq!(chunks, vec![
+        format!("```\n{}\n```", "a".repeat(1992)),
+        "```\na\n```".to_string(),
+    ]);
+}
+
+#[test]
+fn code_block_with_one_long_line_of_words() {
+    let content = format!("```\n{}```", "word ".repeat(500));
+    let chunks = split_message(&content);
+
+    assert_eq!(chunks.len(), 2);
+    assert_fits(&chunks);
+    assert!(chunks[0].starts_with("```\nword") && chunks[0].ends_with("word\n```"), "{:?}", chunks);
+    assert!(chunks[1].starts_with("```\nword") && chunks[1].ends_with("word ```"), "{:?}", chunks);
+}
+
+#[test]
+fn code_block_with_overlong_fence_line() {
+    let content = format!("```{}\ncode\n```", "x".repeat(2500));
+    let chunks = split_message(&content);
+
+    assert!(chunks.len() > 1);
+    assert_fits(&chunks);
+    assert!(chunks[0].starts_with("```x"));
+}
+
+#[test]
+fn boundary_on_opening_fence_line() {
+    let content = format!("{}\n```py\n{}\n```", "a".repeat(1995), "b".repeat(100));
+    let chunks = split_message(&content);
+
+    assert_eq!(chunks, vec!["a".repeat(1995), format!("```py\n{}\n```", "b".repeat(100))]);
+}
+
+#[test]
+fn whitespace_before_overlong_fence_line() {
+    let content = format!("\n```{}", "x".repeat(3000));
+    let chunks = split_message(&content);
+
+    assert!(chunks.len() > 1);
+    assert!(chunks.iter().all(|chunk| !chunk.trim().is_empty()), "{:?}", chunks);
+    assert!(chunks[0].starts_with("```"));
+    assert_fits(&chunks);
+}
+
+#[test]
+fn inline_code_is_not_a_fence() {
+    let content = format!("`{}`\n``{}``", "a".repeat(1500), "b".repeat(1500));
+    let chunks = split_message(&content);
+
+    assert_eq!(chunks, vec![
+        format!("`{}`", "a".repeat(1500)),
+        format!("``{}``", "b".repeat(1500)),
+    ]);
+
+    let content = format!("Type `` ``` `` to start a code block.\n{}", "word ".repeat(500));
+    let chunks = split_message(content.trim_end());
+
+    assert_eq!(chunks.len(), 3);
+    assert_fits(&chunks);
+    assert_eq!(chunks[0], "Type `` ``` `` to start a code block.");
+
+    for chunk in &chunks[1..] {
+        assert!(chunk.starts_with("word") && chunk.ends_with("word"), "{:?}", chunk);
+        assert!(!chunk.contains('`'));
+    }
+}
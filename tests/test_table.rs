@@ -0,0 +1,34 @@
+extern crate serenity_utils;
+
+use serenity_utils::{Alignment, TableBuilder};
+
+#[test]
+fn columns_align_with_wide_characters() {
+    let table = TableBuilder::new(["Name", "Score"])
+        .align(1, Alignment::Right)
+        .row(["山田太郎", "5"])
+        .row(["Zoë", "10"])
+        .build();
+
+    assert_eq!(table, "Name     | Score\n---------+------\n山田太郎 |     5\nZoë      |    10");
+}
+
+#[test]
+fn center_alignment_and_missing_cells() {
+    let table = TableBuilder::new(["a", "b", "c"])
+        .align(0, Alignment::Center)
+        .row(["xyz"])
+        .build();
+
+    assert_eq!(table, " a  | b | c\n----+---+--\nxyz |   |");
+}
+
+#[test]
+fn wide_cells_are_truncated() {
+    let table = TableBuilder::new(["Name"])
+        .max_width(5)
+        .row(["漢字漢字"])
+        .build();
+
+    assert_eq!(table, "Name\n-----\n漢字…");
+}
@@ -0,0 +1,133 @@
+extern crate serenity_utils;
+
+use serenity_utils::{MessageBuilder, TimestampStyle};
+
+#[test]
+fn style_letters() {
+    use serenity_utils::TimestampStyle::*;
+
+    let cases = [
+        (ShortTime, "t"),
+        (LongTime, "T"),
+        (ShortDate, "d"),
+        (LongDate, "D"),
+        (ShortDateTime, "f"),
+        (LongDateTime, "F"),
+        (Relative, "R"),
+    ];
+
+    for &(style, letter) in &cases {
+        assert_eq!(style.to_string(), letter);
+    }
+
+    assert_eq!(TimestampStyle::default(), ShortDateTime);
+}
+
+#[test]
+fn out_of_range_timestamps() {
+    let content = MessageBuilder::new()
+        .push_timestamp(-86_400, TimestampStyle::LongDate)
+        .push_timestamp(i64::MAX, TimestampStyle::ShortTime)
+        .build();
+    assert_eq!(content, "<t:-86400:D><t:9223372036854775807:t>");
+}
+
+#[test]
+fn system_time_timestamps() {
+    use serenity_utils::IntoTimestamp;
+    use std::time::{Duration, UNIX_EPOCH};
+
+    assert_eq!(UNIX_EPOCH.into_timestamp(), 0);
+    assert_eq!((UNIX_EPOCH + Duration::from_millis(1_500)).into_timestamp(), 1);
+    assert_eq!((UNIX_EPOCH - Duration::from_millis(1_500)).into_timestamp(), -1);
+    assert_eq!((UNIX_EPOCH - Duration::from_secs(86_400)).into_timestamp(), -86_400);
+    assert_eq!(u64::MAX.into_timestamp(), i64::MAX);
+
+    let content = MessageBuilder::new()
+        .push_timestamp_default(UNIX_EPOCH + Duration::from_secs(60))
+        .build();
+    assert_eq!(content, "<t:60>");
+}
+
+#[test]
+fn style_conveniences_match_push_timestamp() {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    let time = UNIX_EPOCH + Duration::from_secs(1_543_392_000);
+
+    let cases = [
+        (MessageBuilder::new().push_relative(time), TimestampStyle::Relative),
+        (MessageBuilder::new().push_date(time), TimestampStyle::ShortDate),
+        (MessageBuilder::new().push_datetime(time), TimestampStyle::LongDateTime),
+    ];
+
+    for &(ref builder, style) in &cases {
+        assert_eq!(builder.as_str(), MessageBuilder::new().push_timestamp(time, style).as_str());
+    }
+
+    let content = MessageBuilder::new()
+        .push_relative(-1)
+        .push_date(0u64)
+        .push_datetime(i64::MAX)
+        .build();
+    assert_eq!(content, "<t:-1:R><t:0:d><t:9223372036854775807:F>");
+}
+
+#[test]
+fn parse_timestamp_tags() {
+    use serenity_utils::parse_timestamp_tag;
+    use serenity_utils::TimestampStyle::*;
+
+    let styles = [ShortTime, LongTime, ShortDate, LongDate, ShortDateTime, LongDateTime, Relative];
+
+    for &style in &styles {
+        let tag = MessageBuilder::new().push_timestamp(1700000000, style).build();
+        assert_eq!(parse_timestamp_tag(&tag), Some((1700000000, style)));
+    }
+
+    assert_eq!(parse_timestamp_tag("<t:1700000000>"), Some((1700000000, ShortDateTime)));
+    assert_eq!(parse_timestamp_tag(" <t:-86400:D> "), Some((-86400, LongDate)));
+
+    let malformed = [
+        "",
+        "<t:>",
+        "<t:1700000000R>",
+        "<t:1700000000:>",
+        "<t:1700000000:X>",
+        "<t:1700000000:RR>",
+        "<t:1700000000:R:F>",
+        "<t:17000a0000:R>",
+        "<t::R>",
+        "<t:-:R>",
+        "<t:99999999999999999999>",
+        "<t:1700000000:R",
+        "t:1700000000:R>",
+        "<t:1700000000:R>!",
+    ];
+
+    for tag in &malformed {
+        assert_eq!(parse_timestamp_tag(tag), None, "{:?}", tag);
+    }
+}
+
+#[test]
+fn find_timestamp_tags_with_ranges() {
+    use serenity_utils::find_timestamp_tags;
+    use serenity_utils::TimestampStyle::*;
+
+    let content = "<t:1:R><t:2> <t:soon <t:3:d> <t:4:X> <t:";
+    let tags = find_timestamp_tags(content).collect::<Vec<_>>();
+
+    assert_eq!(tags, vec![
+        (0..7, 1, Relative),
+        (7..12, 2, ShortDateTime),
+        (21..28, 3, ShortDate),
+    ]);
+
+    for &(ref range, timestamp, _) in &tags {
+        assert!(content[range.clone()].contains(&timestamp.to_string()));
+    }
+
+    assert_eq!(find_timestamp_tags("").count(), 0);
+    assert_eq!(find_timestamp_tags("no tags <@1>").count(), 0);
+}
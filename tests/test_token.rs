@@ -0,0 +1,80 @@
+extern crate serenity_utils;
+
+use serenity_utils::{validate_token, TokenError, TokenPart};
+
+const TOKEN: &str = "MTIzNDU2Nzg5MDEyMzQ1Njc4.GaBcDe.abcdefghijklmnopqrstuvwxyz0123456789AB";
+
+#[test]
+fn valid_tokens() {
+    assert_eq!(validate_token(TOKEN), Ok(()));
+    assert_eq!(validate_token(&format!("Bot {}", TOKEN)), Ok(()));
+    assert_eq!(validate_token("MjM4NDk0NzU2NTIxMzc3Nzky.CunGFQ.wUILz7z6HoJzVeq6pyHPmVgQgV4"), Ok(()));
+    assert_eq!(validate_token("MTIzNDU2Nzg5MDEyMzQ1Njc4.G-_cDe.abc-ef_hijklmnopqrstuvwxyz"), Ok(()));
+}
+
+#[test]
+fn empty_tokens() {
+    assert_eq!(validate_token(""), Err(TokenError::Empty));
+    assert_eq!(validate_token("Bot "), Err(TokenError::Empty));
+}
+
+#[test]
+fn whitespace() {
+    let tokens = [
+        format!("{}\n", TOKEN),
+        format!(" {}", TOKEN),
+        format!("Bot  {}", TOKEN),
+        TOKEN.replace(".G", ". G"),
+        "   ".to_string(),
+    ];
+
+    for token in &tokens {
+        assert_eq!(validate_token(token), Err(TokenError::Whitespace), "{:?}", token);
+    }
+}
+
+#[test]
+fn malformed_structure() {
+    assert_eq!(validate_token("abc"), Err(TokenError::PartCount(1)));
+    assert_eq!(validate_token(&format!("{}.x", TOKEN)), Err(TokenError::PartCount(4)));
+    assert_eq!(validate_token("Bearer abc"), Err(TokenError::Whitespace));
+    assert_eq!(
+        validate_token(&TOKEN.replace("GaBcDe", "GaB+De")),
+        Err(TokenError::InvalidCharacter(TokenPart::Timestamp)),
+    );
+    assert_eq!(
+        validate_token(&TOKEN.replace("MTIz", "MTI=")),
+        Err(TokenError::InvalidCharacter(TokenPart::UserId)),
+    );
+    assert_eq!(
+        validate_token(&TOKEN.replace("MTIzNDU2Nzg5", "")),
+        Err(TokenError::InvalidLength(TokenPart::UserId)),
+    );
+    assert_eq!(
+        validate_token(&TOKEN.replace(".GaBcDe.", "..")),
+        Err(TokenError::InvalidLength(TokenPart::Timestamp)),
+    );
+    assert_eq!(
+        validate_token(&format!("{}{}", TOKEN, "A".repeat(30))),
+        Err(TokenError::InvalidLength(TokenPart::Hmac)),
+    );
+}
+
+#[test]
+fn errors_omit_the_token() {
+    let tokens = [
+        TOKEN.replace("GaBcDe", "GaB+De"),
+        TOKEN.replace("MTIzNDU2Nzg5", ""),
+        format!("{}.x", TOKEN),
+        format!("{}\n", TOKEN),
+    ];
+
+    for token in &tokens {
+        let err = validate_token(token).unwrap_err();
+        let rendered = format!("{} {:?}", err, err);
+
+        for part in token.split('.') {
+            assert!(!rendered.contains(part.trim()), "{:?}", rendered);
+        }
+    }
+}